@@ -0,0 +1,166 @@
+use crate::integer::{IntegerImpl, Sign};
+use crate::{Integer, Plain};
+use core::fmt;
+
+/// Formats an integer in [balanced ternary], a base three numeral system
+/// whose digits (trits) are −1, 0 and +1.
+///
+/// Every integer, including negative ones, has a unique representation
+/// without a separate sign: negating a number negates each of its trits.
+///
+/// The trits are computed from the least significant trit upwards: The magnitude
+/// is divided by three and a remainder of two is written as −1 with a carry of
+/// one into the next trit. For negative numbers each trit of the magnitude is negated.
+///
+/// | Trit | Glyph | Alternate `#` |
+/// |------|-------|---------------|
+/// | −1   | `T`   | `⁻`           |
+/// | 0    | `0`   | `⁰`           |
+/// | +1   | `1`   | `⁺`           |
+///
+/// ## Formatting Flags
+/// ### Alternate `#`
+/// The alternate flag `#` can be used to write the trits using superscript signs and zero.
+///
+/// ## Examples
+/// ```
+/// # use fmtastic::BalancedTernary;
+/// assert_eq!("1TT", BalancedTernary(5).to_string()); // 9 - 3 - 1
+/// assert_eq!("T11", BalancedTernary(-5).to_string());
+/// assert_eq!("⁺⁰", format!("{:#}", BalancedTernary(3)));
+/// assert_eq!("0", BalancedTernary(0).to_string());
+/// ```
+///
+/// [balanced ternary]: https://en.wikipedia.org/wiki/Balanced_ternary
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct BalancedTernary<T>(pub T);
+
+impl<T> BalancedTernary<T> {
+    /// Returns a reference to the wrapped value.
+    #[must_use]
+    pub fn as_inner(&self) -> &T {
+        &self.0
+    }
+
+    /// Applies a function to the wrapped value.
+    #[must_use]
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> BalancedTernary<U> {
+        BalancedTernary(f(self.0))
+    }
+
+    /// Formats the wrapped value using its standard formatting,
+    /// ignoring the glyphs of this format. See [`Plain`].
+    #[must_use]
+    pub fn plain(self) -> Plain<T> {
+        Plain(self.0)
+    }
+}
+
+impl<T> From<T> for BalancedTernary<T>
+where
+    T: Integer,
+{
+    fn from(value: T) -> Self {
+        BalancedTernary(value)
+    }
+}
+
+impl<T> fmt::Display for BalancedTernary<T>
+where
+    T: Integer,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let glyphs = if f.alternate() {
+            &SUPERSCRIPT_TRITS
+        } else {
+            &TRITS
+        };
+        fmt_balanced_ternary(self.0.into_impl(), glyphs, f)
+    }
+}
+
+fn fmt_balanced_ternary<T: IntegerImpl>(
+    n: T,
+    glyphs: &[&str; 3],
+    f: &mut fmt::Formatter<'_>,
+) -> fmt::Result {
+    if n == T::ZERO {
+        f.write_str(glyphs[1])
+    } else {
+        let negative = matches!(n.sign(), Sign::Negative);
+        fmt_trits(n.unsigned_abs(), negative, glyphs, f)
+    }
+}
+
+/// Writes the trits of `n` most significant first, recursing
+/// until all higher trits have been written.
+fn fmt_trits<T: IntegerImpl>(
+    n: T,
+    negative: bool,
+    glyphs: &[&str; 3],
+    f: &mut fmt::Formatter<'_>,
+) -> fmt::Result {
+    if n == T::ZERO {
+        return Ok(());
+    }
+    let Ok(three) = T::try_from(3) else {
+        unreachable!("all integer types can represent three")
+    };
+    let (trit, rest) = match (n % three).as_usize() {
+        0 => (0, n / three),
+        1 => (1, n / three),
+        _ => (-1, n / three + T::ONE),
+    };
+    fmt_trits(rest, negative, glyphs, f)?;
+    let trit = if negative { -trit } else { trit };
+    f.write_str(glyphs[(trit + 1) as usize])
+}
+
+const TRITS: [&str; 3] = ["T", "0", "1"];
+
+const SUPERSCRIPT_TRITS: [&str; 3] = ["\u{207B}", "\u{2070}", "\u{207A}"];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn formats_balanced_ternary() {
+        for (expected, input) in [
+            ("0", 0),
+            ("1", 1),
+            ("1T", 2),
+            ("10", 3),
+            ("11", 4),
+            ("1TT", 5),
+            ("111", 13),
+            ("T", -1),
+            ("T11", -5),
+            ("TTT", -13),
+        ] {
+            assert_eq!(expected, BalancedTernary(input).to_string());
+        }
+    }
+
+    #[test]
+    fn formats_superscript_trits() {
+        assert_eq!("⁺⁻⁻", format!("{:#}", BalancedTernary(5)));
+        assert_eq!("⁻⁺⁺", format!("{:#}", BalancedTernary(-5)));
+        assert_eq!("⁰", format!("{:#}", BalancedTernary(0_u8)));
+    }
+
+    #[test]
+    fn formats_extreme_values() {
+        assert_eq!("1TTT01", BalancedTernary(i8::MAX).to_string());
+        assert_eq!("T111T1", BalancedTernary(i8::MIN).to_string());
+        assert_eq!(
+            "1T10T01110TT101T1T11TT001T111TT01110T0T11000TT1T111100110T111T111T00101T1101TT1010",
+            BalancedTernary(u128::MAX).to_string()
+        );
+        assert_eq!(
+            "T0TT0T10TT0100T0TT11T000TT10T011T101TTT10001T0TT1T100T1001T101T1011100TT100T010T1",
+            BalancedTernary(i128::MIN).to_string()
+        );
+    }
+}
@@ -1,4 +1,6 @@
+use crate::float::fmt_non_finite;
 use core::fmt;
+use core::str::FromStr;
 
 /// Formats a boolean as either a checked or unchecked ballot box.
 /// ```
@@ -12,9 +14,128 @@ use core::fmt;
 /// ### Alternate `#`
 /// By default a ballot box with a check (`☑`) is used.
 /// The alternate flag `#` can be used to use a ballot box with an x instead (`☒`).
+///
+/// ## Layout
+/// `BallotBox` is `#[repr(transparent)]`, so it has the same size, alignment and ABI as `bool`.
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+#[repr(transparent)]
 pub struct BallotBox(pub bool);
 
+impl BallotBox {
+    /// Formats the ballot box followed by a label.
+    /// The ballot box and the label are separated by a single space by default,
+    /// use [`LabeledBallotBox::separator`] to change it.
+    ///
+    /// ```
+    /// # use fmtastic::BallotBox;
+    /// assert_eq!("☑ Buy bread", BallotBox(true).labeled("Buy bread").to_string());
+    /// assert_eq!("☒ Laundry", format!("{:#}", BallotBox(true).labeled("Laundry")));
+    /// assert_eq!("☐: Do the dishes", BallotBox(false).labeled("Do the dishes").separator(": ").to_string());
+    /// ```
+    #[must_use]
+    pub fn labeled<L>(self, label: L) -> LabeledBallotBox<L> {
+        LabeledBallotBox {
+            ballot_box: self,
+            separator: " ",
+            label,
+        }
+    }
+
+    /// Formats a progress fraction from 0 to 1 as a coarse progress indicator,
+    /// picking a ballot box that is empty, partially filled or checked.
+    /// See [`PartialBallotBox`] for the glyphs and thresholds.
+    ///
+    /// ```
+    /// # use fmtastic::BallotBox;
+    /// assert_eq!("☐", BallotBox::partial(0.0).to_string());
+    /// assert_eq!("◪", BallotBox::partial(0.5).to_string());
+    /// assert_eq!("☑", BallotBox::partial(1.0).to_string());
+    /// ```
+    #[must_use]
+    pub fn partial(fraction: f64) -> PartialBallotBox {
+        PartialBallotBox(fraction)
+    }
+}
+
+/// A ballot box showing the progress of a fraction from 0 to 1,
+/// created by [`BallotBox::partial`].
+///
+/// | Fraction             | Glyph                  |
+/// |----------------------|------------------------|
+/// | 0 or less            | `☐` (U+2610)           |
+/// | more than 0, < 0.75  | `◪` (U+25EA)           |
+/// | 0.75, less than 1    | `▣` (U+25A3)           |
+/// | 1 or more            | `☑` (U+2611) or `☒`    |
+///
+/// Fractions outside of the range from 0 to 1 are clamped.
+/// Fractions that are not finite are written as `NaN`, `∞` (U+221E) or `-∞`
+/// instead of a ballot box, like in all formats accepting floating point values.
+///
+/// ## Formatting Flags
+/// ### Alternate `#`
+/// The alternate flag `#` can be used to use a ballot box with an x (`☒`)
+/// for a complete fraction, like for [`BallotBox`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PartialBallotBox(f64);
+
+impl PartialBallotBox {
+    /// Returns the progress fraction.
+    #[must_use]
+    pub fn fraction(&self) -> f64 {
+        self.0
+    }
+}
+
+impl fmt::Display for PartialBallotBox {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let fraction = self.0;
+        if let Some(result) = fmt_non_finite(fraction, f) {
+            result
+        } else if fraction >= 1.0 {
+            fmt::Display::fmt(&BallotBox(true), f)
+        } else if fraction >= 0.75 {
+            f.write_str("\u{25A3}")
+        } else if fraction > 0.0 {
+            f.write_str("\u{25EA}")
+        } else {
+            fmt::Display::fmt(&BallotBox(false), f)
+        }
+    }
+}
+
+/// A [`BallotBox`] followed by a label, created by [`BallotBox::labeled`].
+///
+/// ## Formatting Flags
+/// ### Alternate `#`
+/// The alternate flag is applied to the ballot box, see [`BallotBox`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct LabeledBallotBox<L> {
+    ballot_box: BallotBox,
+    separator: &'static str,
+    label: L,
+}
+
+impl<L> LabeledBallotBox<L> {
+    /// Sets the separator written between the ballot box and the label.
+    #[must_use]
+    pub fn separator(mut self, separator: &'static str) -> Self {
+        self.separator = separator;
+        self
+    }
+}
+
+impl<L> fmt::Display for LabeledBallotBox<L>
+where
+    L: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.ballot_box, f)?;
+        write!(f, "{}{}", self.separator, self.label)
+    }
+}
+
 impl fmt::Display for BallotBox {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         if self.0 && f.alternate() {
@@ -27,8 +148,189 @@ impl fmt::Display for BallotBox {
     }
 }
 
+/// Parses a ballot box from its glyph:
+/// `☑` and `☒` are parsed as `true`, `☐` as `false`.
+///
+/// Whether a checked box uses a check or an x is chosen by the alternate
+/// flag `#` when formatting, so it is not stored in the [`BallotBox`].
+/// Format a parsed `☒` using `{:#}` to get the same glyph back.
+///
+/// ```
+/// # use fmtastic::BallotBox;
+/// assert_eq!(Ok(BallotBox(true)), "☑".parse());
+/// assert_eq!(Ok(BallotBox(true)), "☒".parse());
+/// assert_eq!(Ok(BallotBox(false)), "☐".parse());
+/// assert!("x".parse::<BallotBox>().is_err());
+/// ```
+impl FromStr for BallotBox {
+    type Err = ParseBallotBoxError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "☑" | "☒" => Ok(BallotBox(true)),
+            "☐" => Ok(BallotBox(false)),
+            _ => Err(ParseBallotBoxError(())),
+        }
+    }
+}
+
+/// An error which can be returned when parsing a [`BallotBox`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct ParseBallotBoxError(());
+
+impl fmt::Display for ParseBallotBoxError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("expected one of ☑, ☒ or ☐")
+    }
+}
+
 impl From<bool> for BallotBox {
     fn from(value: bool) -> Self {
         BallotBox(value)
     }
 }
+
+/// A sequence of [`BallotBox`]es, e.g. `☑☐☑`, collected from booleans.
+///
+/// The ballot boxes are written without a separator by default,
+/// use [`Checklist::separator`] to change it.
+///
+/// ```
+/// # use fmtastic::Checklist;
+/// let checklist: Checklist = [1, 2, 3, 4].iter().map(|n| n % 2 == 1).collect();
+/// assert_eq!("☑☐☑☐", checklist.to_string());
+/// assert_eq!("☒ ☐ ☒ ☐", format!("{:#}", checklist.separator(" ")));
+/// ```
+///
+/// ## Formatting Flags
+/// ### Alternate `#`
+/// The alternate flag is applied to every ballot box, see [`BallotBox`].
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct Checklist {
+    items: alloc::vec::Vec<bool>,
+    separator: &'static str,
+}
+
+#[cfg(feature = "alloc")]
+impl Checklist {
+    /// Creates an empty checklist.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the separator written between two ballot boxes.
+    #[must_use]
+    pub fn separator(mut self, separator: &'static str) -> Self {
+        self.separator = separator;
+        self
+    }
+
+    /// Returns the collected booleans.
+    #[must_use]
+    pub fn as_slice(&self) -> &[bool] {
+        &self.items
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl FromIterator<bool> for Checklist {
+    fn from_iter<I: IntoIterator<Item = bool>>(iter: I) -> Self {
+        let mut checklist = Checklist::new();
+        checklist.extend(iter);
+        checklist
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Extend<bool> for Checklist {
+    fn extend<I: IntoIterator<Item = bool>>(&mut self, iter: I) {
+        self.items.extend(iter);
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl fmt::Display for Checklist {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, &checked) in self.items.iter().enumerate() {
+            if index > 0 {
+                f.write_str(self.separator)?;
+            }
+            fmt::Display::fmt(&BallotBox(checked), f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn collects_checklist() {
+        let mut checklist: Checklist = [true, false].into_iter().collect();
+        assert_eq!("☑☐", checklist.to_string());
+        checklist.extend([true]);
+        assert_eq!(&[true, false, true], checklist.as_slice());
+        assert_eq!("☒, ☐, ☒", format!("{:#}", checklist.separator(", ")));
+        assert_eq!("", Checklist::new().separator(" ").to_string());
+        assert_eq!(
+            "☐",
+            Checklist::from_iter([false]).separator(" ").to_string()
+        );
+    }
+
+    #[test]
+    fn round_trips_parsed_ballot_boxes() {
+        assert_eq!("☑", "☑".parse::<BallotBox>().unwrap().to_string());
+        assert_eq!("☒", format!("{:#}", "☒".parse::<BallotBox>().unwrap()));
+        assert_eq!("☐", "☐".parse::<BallotBox>().unwrap().to_string());
+        assert_eq!("☐", format!("{:#}", "☐".parse::<BallotBox>().unwrap()));
+    }
+
+    #[test]
+    fn rejects_other_input() {
+        for input in ["", " ☑", "☑☑", "[x]", "true"] {
+            assert_eq!(Err(ParseBallotBoxError(())), input.parse::<BallotBox>());
+        }
+    }
+
+    #[test]
+    fn formats_progress_fractions() {
+        for (expected, fraction) in [
+            ("☐", 0.0),
+            ("☐", -0.5),
+            ("◪", f64::MIN_POSITIVE),
+            ("◪", 0.5),
+            ("▣", 0.75),
+            ("▣", 0.99),
+            ("☑", 1.0),
+            ("☑", 2.0),
+        ] {
+            assert_eq!(expected, BallotBox::partial(fraction).to_string());
+        }
+        assert_eq!("☒", format!("{:#}", BallotBox::partial(1.0)));
+        assert_eq!("◪", format!("{:#}", BallotBox::partial(0.5)));
+    }
+
+    #[test]
+    fn formats_non_finite_progress_fractions() {
+        assert_eq!("NaN", BallotBox::partial(f64::NAN).to_string());
+        assert_eq!("∞", BallotBox::partial(f64::INFINITY).to_string());
+        assert_eq!("-∞", BallotBox::partial(f64::NEG_INFINITY).to_string());
+        assert_eq!("∞", format!("{:#}", BallotBox::partial(f64::INFINITY)));
+    }
+
+    #[test]
+    fn formats_label_with_custom_separator() {
+        let item = BallotBox(true).labeled("Water plants").separator(" – ");
+        assert_eq!("☑ – Water plants", item.to_string());
+        assert_eq!("☒ – Water plants", format!("{item:#}"));
+        assert_eq!(
+            "☐\t42",
+            BallotBox(false).labeled(42).separator("\t").to_string()
+        );
+    }
+}
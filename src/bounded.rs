@@ -0,0 +1,199 @@
+use crate::dingbat::ten;
+use crate::integer::{IntegerImpl, Sign};
+use crate::roman::RomanInteger;
+use crate::{Dingbat, Integer, OutOfRange, Roman, ToIntegerImpl, UnsignedInteger};
+
+/// A format that can only represent the values from [`BoundedNumeral::min_value`]
+/// to [`BoundedNumeral::max_value`] (inclusive), such as [`Roman`] and [`Dingbat`].
+///
+/// This allows generic code to construct and validate any of these formats.
+///
+/// The bounds are functions rather than associated constants because they are
+/// converted from the underlying integer implementation with `T::from_impl`,
+/// which is not a `const fn`, so generic associated constants are not possible.
+///
+/// ## Contract
+/// [`BoundedNumeral::try_new`] must succeed for every value from the minimum to the maximum
+/// and fail with an [`OutOfRange`] error containing these bounds for all other values.
+/// The minimum must not be greater than the maximum.
+/// [`BoundedNumeral::saturating`] and [`BoundedNumeral::wrapping`] rely on this
+/// to map any value into the range.
+///
+/// ```
+/// # use fmtastic::{BoundedNumeral, Dingbat, Roman};
+/// fn clamped<N: BoundedNumeral>(value: N::Value) -> N {
+///     N::saturating(value)
+/// }
+///
+/// assert_eq!("ⅯⅯⅯⅭⅯⅩⅭⅠⅩ", clamped::<Roman<u16>>(5000).to_string());
+/// assert_eq!("⓿", clamped::<Dingbat<i32>>(-5).to_string());
+/// assert_eq!("❶", Dingbat::wrapping(12_u8).to_string());
+/// ```
+pub trait BoundedNumeral: Sized {
+    /// The type of the values of the format.
+    type Value: Integer;
+
+    /// Creates the format for a value, returning an [`OutOfRange`] error
+    /// if the value is not between [`BoundedNumeral::min_value`] and [`BoundedNumeral::max_value`].
+    fn try_new(value: Self::Value) -> Result<Self, OutOfRange<Self::Value>>;
+
+    /// Returns the smallest value that the format can represent.
+    fn min_value() -> Self::Value;
+
+    /// Returns the largest value that the format can represent.
+    fn max_value() -> Self::Value;
+
+    /// Creates the format for a value, clamping values outside of
+    /// the range to [`BoundedNumeral::min_value`] or [`BoundedNumeral::max_value`].
+    #[must_use]
+    fn saturating(value: Self::Value) -> Self {
+        let (min, max) = (Self::min_value(), Self::max_value());
+        let clamped = if value.into_impl() < min.into_impl() {
+            min
+        } else if value.into_impl() > max.into_impl() {
+            max
+        } else {
+            value
+        };
+        Self::try_new(clamped).unwrap_or_else(|_| unreachable_out_of_range())
+    }
+
+    /// Creates the format for a value, wrapping values outside of the range
+    /// around to the other end, e.g. one above the maximum becomes the minimum.
+    #[must_use]
+    fn wrapping(value: Self::Value) -> Self {
+        let (min, max) = (Self::min_value().into_impl(), Self::max_value().into_impl());
+        let value = value.into_impl();
+        let wrapped = match max.abs_diff(min).checked_add(IntegerImpl::ONE) {
+            // The range covers all values of the type.
+            None => value,
+            Some(_) if min <= value && value <= max => value,
+            Some(len) if value > max => {
+                let offset = (value.abs_diff(max) - IntegerImpl::ONE) % len;
+                offset_from(min, offset, false)
+            }
+            Some(len) => {
+                let offset = (value.abs_diff(min) - IntegerImpl::ONE) % len;
+                offset_from(max, offset, true)
+            }
+        };
+        Self::try_new(Self::Value::from_impl(wrapped))
+            .unwrap_or_else(|_| unreachable_out_of_range())
+    }
+}
+
+/// Moves `offset` away from `start`, downwards if `down` is set.
+/// The result must be representable, which holds for offsets within the range.
+fn offset_from<T: IntegerImpl>(start: T, offset: T::Magnitude, down: bool) -> T {
+    let negative = matches!(start.sign(), Sign::Negative);
+    let magnitude = start.unsigned_abs();
+    // Moving towards zero shrinks the magnitude, moving away from zero grows it.
+    let (magnitude, negative) = match (negative, down) {
+        (false, false) | (true, true) => (magnitude + offset, negative),
+        (false, true) | (true, false) if offset <= magnitude => (magnitude - offset, negative),
+        _ => (offset - magnitude, !negative),
+    };
+    T::from_magnitude(magnitude, negative).unwrap_or_else(|| unreachable_out_of_range())
+}
+
+fn unreachable_out_of_range() -> ! {
+    unreachable!("the value has been mapped into the range of the bounded numeral")
+}
+
+impl<T> BoundedNumeral for Roman<T>
+where
+    T: UnsignedInteger,
+{
+    type Value = T;
+
+    fn try_new(value: T) -> Result<Self, OutOfRange<T>> {
+        Roman::new(value)
+    }
+
+    fn min_value() -> T {
+        T::from_impl(T::Impl::ONE)
+    }
+
+    fn max_value() -> T {
+        T::from_impl(T::UnsignedImpl::ROMAN_MAX)
+    }
+}
+
+impl<T> BoundedNumeral for Dingbat<T>
+where
+    T: Integer,
+{
+    type Value = T;
+
+    fn try_new(value: T) -> Result<Self, OutOfRange<T>> {
+        Dingbat::new(value)
+    }
+
+    fn min_value() -> T {
+        T::from_impl(T::Impl::ZERO)
+    }
+
+    fn max_value() -> T {
+        T::from_impl(ten())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::fmt::Debug;
+
+    fn check_max<N>()
+    where
+        N: BoundedNumeral + PartialEq + Debug,
+        N::Value: PartialEq + Debug,
+    {
+        let (min, max) = (N::min_value(), N::max_value());
+        assert!(N::try_new(min).is_ok());
+        assert!(N::try_new(max).is_ok());
+        if let Some(above) = max.into_impl().checked_add(IntegerImpl::ONE) {
+            let above = N::Value::from_impl(above);
+            assert_eq!(
+                Err(OutOfRange {
+                    value: above,
+                    min,
+                    max
+                }),
+                N::try_new(above)
+            );
+            assert_eq!(N::try_new(max), Ok(N::saturating(above)));
+            assert_eq!(N::try_new(min), Ok(N::wrapping(above)));
+        }
+    }
+
+    #[test]
+    fn accepts_max_and_rejects_max_plus_one() {
+        check_max::<Roman<u8>>();
+        check_max::<Roman<u16>>();
+        check_max::<Roman<u128>>();
+        check_max::<Dingbat<u8>>();
+        check_max::<Dingbat<i8>>();
+        check_max::<Dingbat<i128>>();
+    }
+
+    #[test]
+    fn rejects_min_minus_one() {
+        assert!(Roman::try_new(0_u16).is_err());
+        assert!(Dingbat::try_new(-1_i8).is_err());
+        assert_eq!(Roman::new(1_u16), Ok(Roman::saturating(0)));
+        assert_eq!(Dingbat::new(0_i8), Ok(Dingbat::saturating(i8::MIN)));
+    }
+
+    #[test]
+    fn wraps_around_range() {
+        for (expected, value) in [(0, 11), (10, -1), (0, 0), (10, 10), (1, 12), (5, -6)] {
+            assert_eq!(Dingbat::new(expected), Ok(Dingbat::wrapping(value)));
+        }
+        assert_eq!(Dingbat::new(6_i8), Ok(Dingbat::wrapping(i8::MAX)));
+        assert_eq!(Dingbat::new(4_i8), Ok(Dingbat::wrapping(i8::MIN)));
+        assert_eq!(Dingbat::new(2_u8), Ok(Dingbat::wrapping(u8::MAX)));
+        assert_eq!(Roman::new(1_u16), Ok(Roman::wrapping(4000)));
+        assert_eq!(Roman::new(3999_u16), Ok(Roman::wrapping(0)));
+        assert_eq!(Roman::new(2_u16), Ok(Roman::wrapping(8000)));
+    }
+}
@@ -0,0 +1,96 @@
+use crate::{Subscript, Superscript};
+use core::fmt::{self, Write};
+
+/// Formats a chemical formula with the count of each element as [`Subscript`],
+/// e.g. `C₆H₁₂O₆` or `SO₄²⁻`.
+///
+/// Each element symbol is followed by its count. A count of one is omitted,
+/// so `("O", 1)` is formatted as `O`.
+///
+/// The charge set by [`ChemicalFormula::charge`] is appended as [`Superscript`]:
+/// The magnitude comes first, followed by the sign (`⁺` or `⁻`).
+/// A magnitude of one is omitted, so a charge of `-1` is formatted as `⁻`.
+/// Neutral formulas (a charge of zero) have no charge.
+///
+/// ```
+/// # use fmtastic::ChemicalFormula;
+/// assert_eq!("C₆H₁₂O₆", ChemicalFormula::new(&[("C", 6), ("H", 12), ("O", 6)]).to_string());
+/// assert_eq!("SO₄²⁻", ChemicalFormula::new(&[("S", 1), ("O", 4)]).charge(-2).to_string());
+/// assert_eq!("Na⁺", ChemicalFormula::new(&[("Na", 1)]).charge(1).to_string());
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct ChemicalFormula<'a> {
+    elements: &'a [(&'a str, u32)],
+    charge: i32,
+}
+
+impl<'a> ChemicalFormula<'a> {
+    /// Creates a neutral formula from pairs of element symbols and counts.
+    #[must_use]
+    pub fn new(elements: &'a [(&'a str, u32)]) -> Self {
+        Self {
+            elements,
+            charge: 0,
+        }
+    }
+
+    /// Sets the charge of the formula.
+    #[must_use]
+    pub fn charge(mut self, charge: i32) -> Self {
+        self.charge = charge;
+        self
+    }
+}
+
+impl fmt::Display for ChemicalFormula<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (element, count) in self.elements {
+            f.write_str(element)?;
+            if *count != 1 {
+                write!(f, "{}", Subscript(*count))?;
+            }
+        }
+        let magnitude = self.charge.unsigned_abs();
+        if magnitude != 0 {
+            if magnitude != 1 {
+                write!(f, "{}", Superscript(magnitude))?;
+            }
+            f.write_char(if self.charge > 0 { '⁺' } else { '⁻' })?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn formats_glucose() {
+        assert_eq!(
+            "C₆H₁₂O₆",
+            ChemicalFormula::new(&[("C", 6), ("H", 12), ("O", 6)]).to_string()
+        );
+    }
+
+    #[test]
+    fn formats_sulfate() {
+        assert_eq!(
+            "SO₄²⁻",
+            ChemicalFormula::new(&[("S", 1), ("O", 4)])
+                .charge(-2)
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn formats_charges() {
+        let hydroxide = ChemicalFormula::new(&[("O", 1), ("H", 1)]);
+        assert_eq!("OH", hydroxide.to_string());
+        assert_eq!("OH⁻", hydroxide.charge(-1).to_string());
+        assert_eq!("OH¹²⁺", hydroxide.charge(12).to_string());
+        assert_eq!("OH²¹⁴⁷⁴⁸³⁶⁴⁸⁻", hydroxide.charge(i32::MIN).to_string());
+        assert_eq!("", ChemicalFormula::new(&[]).to_string());
+        assert_eq!("H₀", ChemicalFormula::new(&[("H", 0)]).to_string());
+    }
+}
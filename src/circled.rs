@@ -0,0 +1,346 @@
+use crate::digits::{iter_digits, iter_digits_rev, max_digits};
+use crate::integer::IntegerImpl;
+use crate::{Plain, UnsignedInteger};
+use core::fmt::{self, Write};
+
+/// Formats an unsigned integer as a circled number, e.g. `⑳`,
+/// using the circled numbers from the [Enclosed Alphanumerics]
+/// and [Enclosed CJK Letters and Months] blocks.
+///
+/// | Values  | Glyphs                      | Alternate `#`               |
+/// |---------|-----------------------------|-----------------------------|
+/// | 0       | `⓪` (U+24EA)                | `⓿` (U+24FF)                |
+/// | 1 – 10  | `①` – `⑩` (U+2460 – U+2469) | `❶` – `❿` (U+2776 – U+277F) |
+/// | 11 – 20 | `⑪` – `⑳` (U+246A – U+2473) | `⓫` – `⓴` (U+24EB – U+24F4) |
+/// | 21 – 35 | `㉑` – `㉟` (U+3251 – U+325F) | —                           |
+/// | 36 – 50 | `㊱` – `㊿` (U+32B1 – U+32BF) | —                           |
+///
+/// Values without a single circled glyph, i.e. above 50 (or above 20 with the alternate flag),
+/// are written digit by digit using the circled digits `⓪` – `⑨` (or `⓿` – `❾`), e.g. `⑤①` for 51.
+/// This way every value can be formatted, at the cost of ambiguity:
+/// `⑤①` could also be read as the two numbers 5 and 1.
+///
+/// ```
+/// # use fmtastic::Circled;
+/// assert_eq!("⓪", Circled(0_u8).to_string());
+/// assert_eq!("①", Circled(1_u8).to_string());
+/// assert_eq!("⑳", Circled(20_u8).to_string());
+/// assert_eq!("㊿", Circled(50_u8).to_string());
+/// assert_eq!("①⓪⓪", Circled(100_u8).to_string()); // digit by digit
+/// ```
+///
+/// ## Formatting Flags
+/// ### Alternate `#`
+/// The alternate flag `#` switches to the negative (filled) circled numbers,
+/// which only exist from 0 to 20:
+///
+/// ```
+/// # use fmtastic::Circled;
+/// assert_eq!("❸", format!("{:#}", Circled(3_u8)));
+/// assert_eq!("⓴", format!("{:#}", Circled(20_u8)));
+/// assert_eq!("❷❶", format!("{:#}", Circled(21_u8))); // digit by digit
+/// ```
+///
+/// [Enclosed Alphanumerics]: https://www.unicode.org/charts/PDF/U2460.pdf
+/// [Enclosed CJK Letters and Months]: https://www.unicode.org/charts/PDF/U3200.pdf
+///
+/// ## Layout
+/// `Circled<T>` is `#[repr(transparent)]`, so it has the same size, alignment and ABI as `T`.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct Circled<T>(pub T);
+
+impl<T> From<T> for Circled<T>
+where
+    T: UnsignedInteger,
+{
+    fn from(value: T) -> Self {
+        Circled(value)
+    }
+}
+
+impl<T> Circled<T>
+where
+    T: UnsignedInteger,
+{
+    /// The largest number of bytes written when formatting a value of type `T`,
+    /// for sizing buffers.
+    ///
+    /// The longest output is the circled digits of the largest value of `T`,
+    /// each of which is encoded in three bytes.
+    ///
+    /// ```
+    /// # use fmtastic::Circled;
+    /// assert_eq!(3 * 3, Circled::<u8>::MAX_LEN);
+    /// ```
+    pub const MAX_LEN: usize = max_digits(<T::Impl as IntegerImpl>::BITS, 10) * 3;
+}
+
+impl<T> Circled<T> {
+    /// Returns a reference to the wrapped value.
+    #[must_use]
+    pub fn as_inner(&self) -> &T {
+        &self.0
+    }
+
+    /// Applies a function to the wrapped value.
+    ///
+    /// ```
+    /// # use fmtastic::Circled;
+    /// assert_eq!(Circled(4), Circled(3_u8).map(|n| n + 1));
+    /// ```
+    #[must_use]
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> Circled<U> {
+        Circled(f(self.0))
+    }
+
+    /// Formats the wrapped value using its standard formatting,
+    /// ignoring the glyphs of this format. See [`Plain`].
+    #[must_use]
+    pub fn plain(self) -> Plain<T> {
+        Plain(self.0)
+    }
+
+    /// Formats the number digit by digit followed by a circled check digit,
+    /// computed using [`CheckDigitScheme::Mod10`] unless set otherwise with [`CircledWithCheckDigit::scheme`].
+    ///
+    /// The digits are always written one by one, e.g. `①②` rather than `⑫` for 12,
+    /// so that the check digit can be told apart from the number.
+    /// The alternate flag `#` switches to the negative (filled) circled digits, as for [`Circled`].
+    ///
+    /// ```
+    /// # use fmtastic::{CheckDigitScheme, Circled};
+    /// assert_eq!("①②③④⑤⑤", Circled(12345_u32).with_check_digit().to_string());
+    /// assert_eq!(
+    ///     "⑦⑨⑨②⑦③⑨⑧⑦①③",
+    ///     Circled(7992739871_u64).with_check_digit().scheme(CheckDigitScheme::Luhn).to_string()
+    /// );
+    /// assert_eq!("❹❷❹", format!("{:#}", Circled(42_u8).with_check_digit()));
+    /// ```
+    #[must_use]
+    pub fn with_check_digit(self) -> CircledWithCheckDigit<T> {
+        CircledWithCheckDigit(self.0, CheckDigitScheme::Mod10)
+    }
+}
+
+impl<T> fmt::Display for Circled<T>
+where
+    T: UnsignedInteger,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let n = self.0.into_impl();
+        let negative = f.alternate();
+        let max = if negative { 20 } else { 50 };
+        if T::Impl::try_from(max).map_or(true, |max| n <= max) {
+            return f.write_char(circled_number(n.as_usize(), negative));
+        }
+        iter_digits::<_, <T::Impl as IntegerImpl>::BaseTen>(n)
+            .try_for_each(|digit| f.write_char(circled_number(digit, negative)))
+    }
+}
+
+/// A [`Circled`] number followed by a check digit, created by [`Circled::with_check_digit`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct CircledWithCheckDigit<T>(T, CheckDigitScheme);
+
+/// The algorithm used to compute the check digit of a [`CircledWithCheckDigit`].
+///
+/// Both schemes pick the check digit that makes the checksum of all digits,
+/// including the check digit, a multiple of ten.
+/// They detect any single mistyped digit.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum CheckDigitScheme {
+    /// The checksum is the sum of all digits, e.g. `5` for 12345 as 1 + 2 + 3 + 4 + 5 + 5 = 20.
+    /// This does not detect two swapped digits.
+    Mod10,
+    /// The [Luhn algorithm], as used for credit card numbers:
+    /// Starting from the rightmost digit of the number, every second digit is doubled,
+    /// subtracting nine if the result is greater than nine, before summing the digits.
+    /// This also detects most swaps of two adjacent digits.
+    ///
+    /// [Luhn algorithm]: https://en.wikipedia.org/wiki/Luhn_algorithm
+    Luhn,
+}
+
+impl<T> CircledWithCheckDigit<T> {
+    /// Sets the algorithm used to compute the check digit.
+    #[must_use]
+    pub fn scheme(mut self, scheme: CheckDigitScheme) -> Self {
+        self.1 = scheme;
+        self
+    }
+}
+
+impl<T> CircledWithCheckDigit<T>
+where
+    T: UnsignedInteger,
+{
+    /// Returns the check digit, from 0 to 9.
+    ///
+    /// ```
+    /// # use fmtastic::{CheckDigitScheme, Circled};
+    /// assert_eq!(5, Circled(12345_u32).with_check_digit().check_digit());
+    /// assert_eq!(3, Circled(7992739871_u64).with_check_digit().scheme(CheckDigitScheme::Luhn).check_digit());
+    /// ```
+    #[must_use]
+    pub fn check_digit(&self) -> u8 {
+        let digits = iter_digits_rev::<_, <T::Impl as IntegerImpl>::BaseTen>(self.0.into_impl());
+        let sum = digits.enumerate().fold(0, |sum, (index, digit)| {
+            let digit = match self.1 {
+                CheckDigitScheme::Luhn if index % 2 == 0 => {
+                    digit * 2 - if digit > 4 { 9 } else { 0 }
+                }
+                _ => digit,
+            };
+            (sum + digit) % 10
+        });
+        // The sum is less than ten, so the check digit always fits.
+        ((10 - sum) % 10) as u8
+    }
+}
+
+impl<T> fmt::Display for CircledWithCheckDigit<T>
+where
+    T: UnsignedInteger,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let negative = f.alternate();
+        iter_digits::<_, <T::Impl as IntegerImpl>::BaseTen>(self.0.into_impl())
+            .chain([usize::from(self.check_digit())])
+            .try_for_each(|digit| f.write_char(circled_number(digit, negative)))
+    }
+}
+
+/// Returns the circled glyph of a number from 0 to 50, or 0 to 20 if `negative` is set.
+fn circled_number(n: usize, negative: bool) -> char {
+    let (base, offset) = match (n, negative) {
+        (0, false) => (0x24EA, 0),
+        (1..=20, false) => (0x2460, n - 1),
+        (21..=35, false) => (0x3251, n - 21),
+        (36..=50, false) => (0x32B1, n - 36),
+        (0, true) => (0x24FF, 0),
+        (1..=10, true) => (0x2776, n - 1),
+        (11..=20, true) => (0x24EB, n - 11),
+        _ => unreachable!("there is no circled glyph for {n}"),
+    };
+    // The offset is at most 19, so the result is always a valid char.
+    char::from_u32(base + offset as u32).unwrap_or(char::REPLACEMENT_CHARACTER)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn formats_single_glyphs() {
+        for (expected, input) in [
+            ('⓪', 0_u8),
+            ('①', 1),
+            ('⑨', 9),
+            ('⑩', 10),
+            ('⑪', 11),
+            ('⑳', 20),
+            ('㉑', 21),
+            ('㉟', 35),
+            ('㊱', 36),
+            ('㊿', 50),
+        ] {
+            assert_eq!(expected.to_string(), Circled(input).to_string());
+        }
+    }
+
+    #[test]
+    fn formats_negative_single_glyphs() {
+        for (expected, input) in [('⓿', 0_u8), ('❶', 1), ('❿', 10), ('⓫', 11), ('⓴', 20)]
+        {
+            assert_eq!(expected.to_string(), format!("{:#}", Circled(input)));
+        }
+    }
+
+    #[test]
+    fn falls_back_to_circled_digits() {
+        assert_eq!("⑤①", Circled(51_u8).to_string());
+        assert_eq!("②⑤⑤", Circled(u8::MAX).to_string());
+        assert_eq!("①⓪⓪", Circled(100_u32).to_string());
+        assert_eq!("❷❶", format!("{:#}", Circled(21_u8)));
+        assert_eq!("❺⓿", format!("{:#}", Circled(50_u16)));
+        assert_eq!(
+            u128::MAX.to_string().len(),
+            Circled(u128::MAX).to_string().chars().count()
+        );
+    }
+
+    #[test]
+    fn computes_mod_10_check_digit() {
+        for (expected, input) in [(0, 0_u64), (9, 1), (5, 12345), (0, 55), (1, 9_999_999_998)] {
+            assert_eq!(expected, Circled(input).with_check_digit().check_digit());
+        }
+    }
+
+    #[test]
+    fn computes_luhn_check_digit() {
+        for (expected, input) in [
+            (0, 0_u64),
+            (3, 7_992_739_871),
+            (4, 1_789_372_997),
+            (6, 453_201_511_283_036),
+            (8, 1),
+        ] {
+            let circled = Circled(input)
+                .with_check_digit()
+                .scheme(CheckDigitScheme::Luhn);
+            assert_eq!(expected, circled.check_digit());
+        }
+    }
+
+    #[test]
+    fn luhn_detects_swapped_adjacent_digits() {
+        let luhn = |n: u32| {
+            Circled(n)
+                .with_check_digit()
+                .scheme(CheckDigitScheme::Luhn)
+                .check_digit()
+        };
+        assert_ne!(luhn(1234), luhn(1324));
+        let mod_10 = |n: u32| Circled(n).with_check_digit().check_digit();
+        assert_eq!(mod_10(1234), mod_10(1324));
+    }
+
+    #[test]
+    fn formats_digits_followed_by_check_digit() {
+        assert_eq!("⓪⓪", Circled(0_u8).with_check_digit().to_string());
+        assert_eq!("①②⑦", Circled(12_u8).with_check_digit().to_string());
+        assert_eq!("❶❷❼", format!("{:#}", Circled(12_u8).with_check_digit()));
+        assert_eq!(
+            u128::MAX.to_string().len() + 1,
+            Circled(u128::MAX)
+                .with_check_digit()
+                .to_string()
+                .chars()
+                .count()
+        );
+    }
+
+    #[test]
+    fn output_never_exceeds_max_len() {
+        assert!((0..=u8::MAX).all(|n| {
+            [Circled(n).to_string(), format!("{:#}", Circled(n))]
+                .iter()
+                .all(|s| s.len() <= Circled::<u8>::MAX_LEN)
+        }));
+        assert_eq!(
+            Circled::<u128>::MAX_LEN,
+            Circled(u128::MAX).to_string().len()
+        );
+    }
+
+    #[test]
+    fn formats_all_single_glyphs_as_distinct_chars() {
+        let glyphs: Vec<_> = (0..=50_u8).map(|n| Circled(n).to_string()).collect();
+        assert!(glyphs.iter().all(|glyph| glyph.chars().count() == 1));
+        for (index, glyph) in glyphs.iter().enumerate() {
+            assert!(!glyphs[..index].contains(glyph));
+        }
+    }
+}
@@ -0,0 +1,108 @@
+use core::fmt::{self, Write};
+
+/// Encloses a number in a pair of CJK brackets, as used for numbered items
+/// in Chinese, Japanese and Korean documents, e.g. `【1】` or `（一）`.
+///
+/// The enclosed value can be anything that implements [`Display`](fmt::Display):
+/// A plain integer for decimal numbering, one of the formats of this crate,
+/// or a CJK numeral such as `"一"`. The formatting flags are passed on to the enclosed value.
+///
+/// ```
+/// use fmtastic::{CjkBracket, CjkEnclosed};
+///
+/// assert_eq!("【1】", CjkEnclosed(1, CjkBracket::Lenticular).to_string());
+/// assert_eq!("〔2〕", CjkEnclosed(2, CjkBracket::TortoiseShell).to_string());
+/// assert_eq!("（一）", CjkEnclosed("一", CjkBracket::FullwidthParentheses).to_string());
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct CjkEnclosed<T>(pub T, pub CjkBracket);
+
+/// A pair of CJK brackets enclosing a [`CjkEnclosed`] number.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum CjkBracket {
+    /// Black lenticular brackets `【` (U+3010) and `】` (U+3011), e.g. `【1】`.
+    Lenticular,
+    /// Tortoise shell brackets `〔` (U+3014) and `〕` (U+3015), e.g. `〔1〕`.
+    TortoiseShell,
+    /// Fullwidth parentheses `（` (U+FF08) and `）` (U+FF09), e.g. `（1）`.
+    FullwidthParentheses,
+}
+
+impl CjkBracket {
+    fn pair(self) -> (char, char) {
+        match self {
+            CjkBracket::Lenticular => ('\u{3010}', '\u{3011}'),
+            CjkBracket::TortoiseShell => ('\u{3014}', '\u{3015}'),
+            CjkBracket::FullwidthParentheses => ('\u{FF08}', '\u{FF09}'),
+        }
+    }
+}
+
+impl<T> CjkEnclosed<T> {
+    /// Returns a reference to the enclosed value.
+    #[must_use]
+    pub fn as_inner(&self) -> &T {
+        &self.0
+    }
+
+    /// Applies a function to the enclosed value, keeping the brackets.
+    #[must_use]
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> CjkEnclosed<U> {
+        CjkEnclosed(f(self.0), self.1)
+    }
+}
+
+impl<T> fmt::Display for CjkEnclosed<T>
+where
+    T: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (open, close) = self.1.pair();
+        f.write_char(open)?;
+        fmt::Display::fmt(&self.0, f)?;
+        f.write_char(close)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Roman, Segmented};
+
+    #[test]
+    fn encloses_in_each_bracket_style() {
+        for (expected, bracket) in [
+            ("【3】", CjkBracket::Lenticular),
+            ("〔3〕", CjkBracket::TortoiseShell),
+            ("（3）", CjkBracket::FullwidthParentheses),
+        ] {
+            assert_eq!(expected, CjkEnclosed(3, bracket).to_string());
+        }
+    }
+
+    #[test]
+    fn encloses_other_formats() {
+        assert_eq!(
+            "【🯱🯲】",
+            CjkEnclosed(Segmented(12_u8), CjkBracket::Lenticular).to_string()
+        );
+        assert_eq!(
+            "（ⅰⅴ）",
+            format!(
+                "{:#}",
+                CjkEnclosed(Roman::from(4), CjkBracket::FullwidthParentheses)
+            )
+        );
+        assert_eq!(
+            "〔十一〕",
+            CjkEnclosed("十一", CjkBracket::TortoiseShell).to_string()
+        );
+        assert_eq!(
+            "【2】",
+            CjkEnclosed(1, CjkBracket::Lenticular)
+                .map(|n| n + 1)
+                .to_string()
+        );
+    }
+}
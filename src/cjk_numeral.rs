@@ -0,0 +1,162 @@
+use crate::daiji::{fmt_daiji, Numerals, SIMPLIFIED_CHINESE, TRADITIONAL_CHINESE};
+use crate::digits::iter_digits;
+use crate::integer::IntegerImpl;
+use crate::{Plain, UnsignedInteger};
+use core::fmt;
+
+/// Formats an unsigned integer using Chinese numerals, e.g. `二千零二十四` for 2024.
+///
+/// The numerals are written using place values: Each digit is followed by the place value
+/// `十` (10), `百` (100) or `千` (1000) of its position, and the upper four digits are
+/// followed by `万` (10⁴), e.g. `二万` for 20000.
+/// A single `零` is written for each run of zeros between two digits, e.g. `一百零五` for 105,
+/// except for zeros at the end of the lower four digits, which are covered by `万`.
+/// A leading one is omitted before `十`, e.g. `十二` for 12 and `十万` for 100000,
+/// but written anywhere else, e.g. `一百一十` for 110.
+///
+/// The supported range is deliberately capped at the values from 0 to 99,999,999,
+/// which need no myriad beyond `万`. Larger values are written as plain decimal digits
+/// instead, e.g. `100000000`.
+/// For numerals covering all values, see [`Daiji`](crate::Daiji).
+///
+/// ```
+/// # use fmtastic::CjkNumeral;
+/// assert_eq!("十", CjkNumeral(10_u32).to_string());
+/// assert_eq!("一百零五", CjkNumeral(105_u32).to_string());
+/// assert_eq!("二千零二十四", CjkNumeral(2024_u32).to_string());
+/// assert_eq!("二万", CjkNumeral(20000_u32).to_string());
+/// assert_eq!("100000000", CjkNumeral(100_000_000_u32).to_string()); // out of range
+/// ```
+///
+/// ## Formatting Flags
+/// ### Alternate `#`
+/// The alternate flag `#` switches to the traditional Chinese financial numerals,
+/// which are used on cheques and contracts as they cannot be changed by adding strokes.
+/// These always write a leading one, e.g. `壹拾` for 10.
+/// See [`DaijiVariant::TraditionalChinese`](crate::DaijiVariant::TraditionalChinese).
+///
+/// ```
+/// # use fmtastic::CjkNumeral;
+/// assert_eq!("壹拾", format!("{:#}", CjkNumeral(10_u32)));
+/// assert_eq!("貳仟零貳拾肆", format!("{:#}", CjkNumeral(2024_u32)));
+/// ```
+///
+/// ## Layout
+/// `CjkNumeral<T>` is `#[repr(transparent)]`, so it has the same size, alignment and ABI as `T`.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct CjkNumeral<T>(pub T);
+
+impl<T> CjkNumeral<T> {
+    /// Returns a reference to the wrapped value.
+    #[must_use]
+    pub fn as_inner(&self) -> &T {
+        &self.0
+    }
+
+    /// Applies a function to the wrapped value.
+    ///
+    /// ```
+    /// # use fmtastic::CjkNumeral;
+    /// assert_eq!(CjkNumeral(4), CjkNumeral(3_u8).map(|n| n + 1));
+    /// ```
+    #[must_use]
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> CjkNumeral<U> {
+        CjkNumeral(f(self.0))
+    }
+
+    /// Formats the wrapped value using its standard formatting,
+    /// ignoring the glyphs of this format. See [`Plain`].
+    #[must_use]
+    pub fn plain(self) -> Plain<T> {
+        Plain(self.0)
+    }
+}
+
+impl<T> From<T> for CjkNumeral<T>
+where
+    T: UnsignedInteger,
+{
+    fn from(value: T) -> Self {
+        CjkNumeral(value)
+    }
+}
+
+impl<T> fmt::Display for CjkNumeral<T>
+where
+    T: UnsignedInteger,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let n = self.0.into_impl();
+        // Values with more than eight digits are outside of the supported range.
+        if iter_digits::<_, <T::Impl as IntegerImpl>::BaseTen>(n)
+            .nth(8)
+            .is_some()
+        {
+            return write!(f, "{n}");
+        }
+        let numerals = if f.alternate() {
+            &TRADITIONAL_CHINESE
+        } else {
+            &COMMON
+        };
+        fmt_daiji(n, numerals, f)
+    }
+}
+
+const COMMON: Numerals = Numerals {
+    digits: ['一', '二', '三', '四', '五', '六', '七', '八', '九'],
+    places: ['十', '百', '千'],
+    myriads: SIMPLIFIED_CHINESE.myriads,
+    write_zeros: true,
+    omit_leading_one: true,
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn formats_common_numerals() {
+        for (expected, input) in [
+            ("零", 0_u32),
+            ("一", 1),
+            ("十", 10),
+            ("十二", 12),
+            ("二十", 20),
+            ("一百零五", 105),
+            ("一百一十", 110),
+            ("一千零一", 1001),
+            ("二千零二十四", 2024),
+            ("二万", 20_000),
+            ("一万零一十", 10_010),
+            ("十万", 100_000),
+            ("十二万三千", 123_000),
+            ("九千九百九十九万九千九百九十九", 99_999_999),
+        ] {
+            assert_eq!(expected, CjkNumeral(input).to_string());
+        }
+    }
+
+    #[test]
+    fn formats_financial_numerals_with_alternate_flag() {
+        for (expected, input) in [
+            ("零", 0_u32),
+            ("壹拾", 10),
+            ("壹佰零伍", 105),
+            ("貳仟零貳拾肆", 2024),
+            ("貳萬", 20_000),
+        ] {
+            assert_eq!(expected, format!("{:#}", CjkNumeral(input)));
+        }
+    }
+
+    #[test]
+    fn falls_back_to_digits_out_of_range() {
+        assert_eq!("100000000", CjkNumeral(100_000_000_u64).to_string());
+        assert_eq!("100000000", format!("{:#}", CjkNumeral(100_000_000_u32)));
+        assert_eq!(u128::MAX.to_string(), CjkNumeral(u128::MAX).to_string());
+        assert_eq!("二百五十五", CjkNumeral(u8::MAX).to_string());
+        assert_eq!("六万五千五百三十五", CjkNumeral(u16::MAX).to_string());
+    }
+}
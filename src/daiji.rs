@@ -0,0 +1,243 @@
+use crate::digits::iter_digits;
+use crate::integer::IntegerImpl;
+use crate::{Plain, UnsignedInteger};
+use core::fmt::{self, Write};
+
+/// Formats an unsigned integer using the financial numerals of Japanese ([daiji])
+/// or Chinese, e.g. `壱千弐百参拾四` for 1234.
+///
+/// Financial numerals are used on cheques and contracts to prevent tampering,
+/// as unlike `一`, `二` and `三` they cannot be changed into another numeral by adding strokes.
+///
+/// The numerals are written using place values: Each digit is followed by the place value
+/// `拾` (10), `百`/`佰` (100) or `千`/`仟` (1000) of its position within a group of four digits,
+/// and each group of four digits is followed by its myriad, e.g. `万` (10⁴) or `億` (10⁸).
+/// A digit of one is always written, e.g. `壱拾` for 10, so that no digit can be inserted.
+/// Zero is written as `零`.
+///
+/// All values of all unsigned integer types are supported: The myriads up to 10³⁶
+/// (`澗` or `涧`) cover all values up to [`u128::MAX`].
+///
+/// | Variant                                      | 1 – 9                   | 10, 100, 1000 | 10⁴, 10⁸, 10¹² …         | Zeros within a number  |
+/// |----------------------------------------------|-------------------------|---------------|--------------------------|------------------------|
+/// | [`DaijiVariant::Japanese`] (default)         | `壱弐参四五六七八九`      | `拾百千`       | `万億兆京垓𥝱穣溝澗`       | omitted                |
+/// | [`DaijiVariant::SimplifiedChinese`]          | `壹贰叁肆伍陆柒捌玖`      | `拾佰仟`       | `万亿兆京垓秭穰沟涧`       | one `零` for each run  |
+/// | [`DaijiVariant::TraditionalChinese`]         | `壹貳參肆伍陸柒捌玖`      | `拾佰仟`       | `萬億兆京垓秭穰溝澗`       | one `零` for each run  |
+///
+/// Japanese only has financial forms for one, two, three and ten that are in use today,
+/// the remaining digits and place values use the common numerals.
+/// Chinese writes a single `零` for a run of zeros between two digits, e.g. `壹仟零伍` for 1005,
+/// except for zeros at the end of a group, which are covered by the myriad, e.g. `壹万贰仟` for 12000.
+///
+/// ```
+/// # use fmtastic::{Daiji, DaijiVariant};
+/// assert_eq!("壱千弐百参拾四", Daiji(1234_u32, DaijiVariant::Japanese).to_string());
+/// assert_eq!("壹仟贰佰叁拾肆", Daiji(1234_u32, DaijiVariant::SimplifiedChinese).to_string());
+/// assert_eq!("壹萬零伍", Daiji(10005_u32, DaijiVariant::TraditionalChinese).to_string());
+/// assert_eq!("壱万五", Daiji::from(10005_u32).to_string());
+/// ```
+///
+/// [daiji]: https://en.wikipedia.org/wiki/Japanese_numerals#Formal_numbers
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct Daiji<T>(pub T, pub DaijiVariant);
+
+/// The variant of the financial numerals of a [`Daiji`] numeral.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+#[non_exhaustive]
+pub enum DaijiVariant {
+    /// Japanese daiji (大字), e.g. `壱千弐百参拾四`.
+    #[default]
+    Japanese,
+    /// Simplified Chinese financial numerals (大写数字), e.g. `壹仟贰佰叁拾肆`.
+    SimplifiedChinese,
+    /// Traditional Chinese financial numerals (大寫數字), e.g. `壹仟貳佰參拾肆`.
+    TraditionalChinese,
+}
+
+impl<T> Daiji<T> {
+    /// Returns a reference to the wrapped value.
+    #[must_use]
+    pub fn as_inner(&self) -> &T {
+        &self.0
+    }
+
+    /// Applies a function to the wrapped value, keeping the variant.
+    #[must_use]
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> Daiji<U> {
+        Daiji(f(self.0), self.1)
+    }
+
+    /// Formats the wrapped value using its standard formatting,
+    /// ignoring the glyphs of this format. See [`Plain`].
+    #[must_use]
+    pub fn plain(self) -> Plain<T> {
+        Plain(self.0)
+    }
+}
+
+impl<T> From<T> for Daiji<T>
+where
+    T: UnsignedInteger,
+{
+    fn from(value: T) -> Self {
+        Daiji(value, DaijiVariant::default())
+    }
+}
+
+impl<T> fmt::Display for Daiji<T>
+where
+    T: UnsignedInteger,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let numerals = match self.1 {
+            DaijiVariant::Japanese => &JAPANESE,
+            DaijiVariant::SimplifiedChinese => &SIMPLIFIED_CHINESE,
+            DaijiVariant::TraditionalChinese => &TRADITIONAL_CHINESE,
+        };
+        fmt_daiji(self.0.into_impl(), numerals, f)
+    }
+}
+
+pub(crate) fn fmt_daiji<T: IntegerImpl>(
+    n: T,
+    numerals: &Numerals,
+    f: &mut fmt::Formatter<'_>,
+) -> fmt::Result {
+    if n == T::ZERO {
+        return f.write_char(ZERO);
+    }
+    let len = iter_digits::<_, T::BaseTen>(n).count();
+    let mut pending_zero = false;
+    let mut group_is_zero = true;
+    for (digit, position) in iter_digits::<_, T::BaseTen>(n).zip((0..len).rev()) {
+        let place = position % 4;
+        if digit == 0 {
+            pending_zero = true;
+        } else {
+            if pending_zero && numerals.write_zeros {
+                f.write_char(ZERO)?;
+            }
+            pending_zero = false;
+            group_is_zero = false;
+            let is_leading_ten = digit == 1 && place == 1 && position == len - 1;
+            if !(is_leading_ten && numerals.omit_leading_one) {
+                f.write_char(numerals.digits[digit - 1])?;
+            }
+            if place > 0 {
+                f.write_char(numerals.places[place - 1])?;
+            }
+        }
+        // Zeros at the end of a group are covered by its myriad,
+        // only entire groups of zeros need a `零` before the next digit.
+        if place == 0 {
+            if !group_is_zero && position > 0 {
+                f.write_str(numerals.myriads[position / 4 - 1])?;
+            }
+            pending_zero &= group_is_zero;
+            group_is_zero = true;
+        }
+    }
+    Ok(())
+}
+
+pub(crate) struct Numerals {
+    pub(crate) digits: [char; 9],
+    pub(crate) places: [char; 3],
+    pub(crate) myriads: [&'static str; 9],
+    pub(crate) write_zeros: bool,
+    /// Whether a leading one is omitted before `十`, e.g. `十二` instead of `一十二`.
+    pub(crate) omit_leading_one: bool,
+}
+
+const ZERO: char = '零';
+
+const JAPANESE: Numerals = Numerals {
+    digits: ['壱', '弐', '参', '四', '五', '六', '七', '八', '九'],
+    places: ['拾', '百', '千'],
+    myriads: ["万", "億", "兆", "京", "垓", "\u{25771}", "穣", "溝", "澗"],
+    write_zeros: false,
+    omit_leading_one: false,
+};
+
+pub(crate) const SIMPLIFIED_CHINESE: Numerals = Numerals {
+    digits: ['壹', '贰', '叁', '肆', '伍', '陆', '柒', '捌', '玖'],
+    places: ['拾', '佰', '仟'],
+    myriads: ["万", "亿", "兆", "京", "垓", "秭", "穰", "沟", "涧"],
+    write_zeros: true,
+    omit_leading_one: false,
+};
+
+pub(crate) const TRADITIONAL_CHINESE: Numerals = Numerals {
+    digits: ['壹', '貳', '參', '肆', '伍', '陸', '柒', '捌', '玖'],
+    places: ['拾', '佰', '仟'],
+    myriads: ["萬", "億", "兆", "京", "垓", "秭", "穰", "溝", "澗"],
+    write_zeros: true,
+    omit_leading_one: false,
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn formats_japanese_daiji() {
+        for (expected, input) in [
+            ("零", 0_u64),
+            ("壱", 1),
+            ("壱拾", 10),
+            ("壱拾壱", 11),
+            ("弐拾", 20),
+            ("壱百", 100),
+            ("壱千弐百参拾四", 1234),
+            ("壱万", 10_000),
+            ("壱万五", 10_005),
+            ("壱拾万", 100_000),
+            ("九千九百九拾九万九千九百九拾九", 99_999_999),
+            ("壱億壱", 100_000_001),
+            ("壱兆", 1_000_000_000_000),
+        ] {
+            assert_eq!(expected, Daiji(input, DaijiVariant::Japanese).to_string());
+        }
+    }
+
+    #[test]
+    fn formats_chinese_financial_numerals() {
+        for (expected, input) in [
+            ("零", 0_u64),
+            ("壹拾", 10),
+            ("壹仟贰佰叁拾肆", 1234),
+            ("壹仟零伍", 1005),
+            ("壹仟零伍拾", 1050),
+            ("壹万零伍", 10_005),
+            ("壹万零壹拾", 10_010),
+            ("壹拾万零伍佰", 100_500),
+            ("壹亿零壹", 100_000_001),
+            ("壹亿零壹万", 100_010_000),
+            ("壹万贰仟", 12_000),
+            ("叁佰肆拾万贰仟捌佰贰拾叁", 3_402_823),
+            ("壹兆零伍佰", 1_000_000_000_500),
+        ] {
+            assert_eq!(
+                expected,
+                Daiji(input, DaijiVariant::SimplifiedChinese).to_string()
+            );
+        }
+        assert_eq!(
+            "壹仟貳佰參拾肆萬伍仟陸佰柒拾捌",
+            Daiji(12_345_678_u32, DaijiVariant::TraditionalChinese).to_string()
+        );
+    }
+
+    #[test]
+    fn formats_extreme_values() {
+        assert_eq!("弐百五拾五", Daiji::from(u8::MAX).to_string());
+        assert_eq!(
+            "参百四拾澗弐千八百弐拾参溝六千六百九拾弐穣九百参拾八\u{25771}四千六百参拾四垓六千参百参拾七京四千六百七兆四千参百壱拾七億六千八百弐拾壱万壱千四百五拾五",
+            Daiji::from(u128::MAX).to_string()
+        );
+        assert_eq!(
+            "叁佰肆拾涧贰仟捌佰贰拾叁沟陆仟陆佰玖拾贰穰零玖佰叁拾捌秭肆仟陆佰叁拾肆垓陆仟叁佰叁拾柒京肆仟陆佰零柒兆肆仟叁佰壹拾柒亿陆仟捌佰贰拾壹万壹仟肆佰伍拾伍",
+            Daiji(u128::MAX, DaijiVariant::SimplifiedChinese).to_string()
+        );
+    }
+}
@@ -0,0 +1,150 @@
+use crate::digits::max_digits;
+use crate::integer::IntegerImpl;
+use crate::{Plain, UnsignedInteger};
+use core::fmt::{self, Write};
+
+/// Formats an unsigned integer from 1 to 6 as a die face, e.g. `⚂` for 3,
+/// using the die faces `⚀` – `⚅` (U+2680 – U+2685) from the [Miscellaneous Symbols] block.
+///
+/// There is no die face for 0 and values above 6, these are written as plain decimal digits
+/// instead, e.g. `7`. This way every value can be formatted,
+/// use [`Dice::face`] to check whether a value has a die face.
+///
+/// [Miscellaneous Symbols]: https://www.unicode.org/charts/PDF/U2600.pdf
+///
+/// ```
+/// # use fmtastic::Dice;
+/// assert_eq!("⚀", Dice(1_u8).to_string());
+/// assert_eq!("⚅", Dice(6_u8).to_string());
+/// assert_eq!("⚃ ⚁", format!("{} {}", Dice(4_u8), Dice(2_u8)));
+/// assert_eq!("7", Dice(7_u8).to_string()); // no die face
+/// ```
+///
+/// ## Layout
+/// `Dice<T>` is `#[repr(transparent)]`, so it has the same size, alignment and ABI as `T`.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct Dice<T>(pub T);
+
+impl<T> Dice<T> {
+    /// Returns a reference to the wrapped value.
+    #[must_use]
+    pub fn as_inner(&self) -> &T {
+        &self.0
+    }
+
+    /// Applies a function to the wrapped value.
+    ///
+    /// ```
+    /// # use fmtastic::Dice;
+    /// assert_eq!(Dice(4), Dice(3_u8).map(|n| n + 1));
+    /// ```
+    #[must_use]
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> Dice<U> {
+        Dice(f(self.0))
+    }
+
+    /// Formats the wrapped value using its standard formatting,
+    /// ignoring the glyphs of this format. See [`Plain`].
+    #[must_use]
+    pub fn plain(self) -> Plain<T> {
+        Plain(self.0)
+    }
+}
+
+impl<T> From<T> for Dice<T>
+where
+    T: UnsignedInteger,
+{
+    fn from(value: T) -> Self {
+        Dice(value)
+    }
+}
+
+impl<T> Dice<T>
+where
+    T: UnsignedInteger,
+{
+    /// The largest number of bytes written when formatting a value of type `T`,
+    /// for sizing buffers.
+    ///
+    /// This is the number of decimal digits of the largest value of `T`,
+    /// but at least the three bytes of a die face.
+    ///
+    /// ```
+    /// # use fmtastic::Dice;
+    /// assert_eq!(3, Dice::<u8>::MAX_LEN);
+    /// assert_eq!(10, Dice::<u32>::MAX_LEN);
+    /// ```
+    pub const MAX_LEN: usize = {
+        let digits = max_digits(<T::Impl as IntegerImpl>::BITS, 10);
+        if digits > 3 {
+            digits
+        } else {
+            3
+        }
+    };
+
+    /// Returns the die face of the value, or [`None`] if the value is not from 1 to 6.
+    ///
+    /// ```
+    /// # use fmtastic::Dice;
+    /// assert_eq!(Some('⚄'), Dice(5_u8).face());
+    /// assert_eq!(None, Dice(0_u8).face());
+    /// assert_eq!(None, Dice(7_u8).face());
+    /// ```
+    #[must_use]
+    pub fn face(&self) -> Option<char> {
+        const DIE_FACE_ONE: u32 = 0x2680;
+        let n = self.0.into_impl();
+        let is_face = n != T::Impl::ZERO && T::Impl::try_from(6).map_or(true, |max| n <= max);
+        is_face
+            .then(|| char::from_u32(DIE_FACE_ONE + n.as_usize() as u32 - 1))
+            .flatten()
+    }
+}
+
+impl<T> fmt::Display for Dice<T>
+where
+    T: UnsignedInteger,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.face() {
+            Some(face) => f.write_char(face),
+            None => write!(f, "{}", self.0.into_impl()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn formats_all_faces() {
+        for (expected, input) in [
+            ("⚀", 1_u8),
+            ("⚁", 2),
+            ("⚂", 3),
+            ("⚃", 4),
+            ("⚄", 5),
+            ("⚅", 6),
+        ] {
+            assert_eq!(expected, Dice(input).to_string());
+        }
+    }
+
+    #[test]
+    fn falls_back_to_digits_out_of_range() {
+        assert_eq!("0", Dice(0_u8).to_string());
+        assert_eq!("7", Dice(7_u16).to_string());
+        assert_eq!(u128::MAX.to_string(), Dice(u128::MAX).to_string());
+        assert_eq!(None, Dice(u128::MAX).face());
+    }
+
+    #[test]
+    fn output_never_exceeds_max_len() {
+        assert!((0..=u8::MAX).all(|n| Dice(n).to_string().len() <= Dice::<u8>::MAX_LEN));
+        assert_eq!(Dice::<u64>::MAX_LEN, Dice(u64::MAX).to_string().len());
+    }
+}
@@ -1,8 +1,225 @@
 use crate::integer::{Base, IntegerImpl};
+use crate::{Integer, SignGlyphs, WithSign};
+use core::fmt;
+use core::iter::FusedIterator;
+
+type Magnitude<T> = <<T as crate::ToIntegerImpl>::Impl as IntegerImpl>::Magnitude;
+
+/// An iterator over the digits of an integer, starting with the most significant digit.
+///
+/// The sign of the integer is ignored. Zero has one digit.
+/// The number of digits is known up front, so this iterator implements [`ExactSizeIterator`].
+///
+/// ```
+/// use fmtastic::Digits;
+///
+/// let digits = Digits::new(-628);
+/// assert_eq!(3, digits.len());
+/// assert_eq!(vec![6, 2, 8], digits.collect::<Vec<_>>());
+///
+/// let digits = Digits::with_radix(0xBEEF_u16, 16);
+/// assert_eq!("BEEF", digits.map(|d| char::from_digit(d, 16).unwrap().to_ascii_uppercase()).collect::<String>());
+/// ```
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Digits<T: Integer> {
+    remainder: Magnitude<T>,
+    power: Magnitude<T>,
+    radix: Magnitude<T>,
+    len: usize,
+}
+
+impl<T: Integer> Digits<T> {
+    /// Iterates the decimal digits of `n`.
+    pub fn new(n: T) -> Self {
+        Self::with_radix(n, 10)
+    }
+
+    /// Iterates the digits of `n` in the given radix.
+    ///
+    /// ## Panics
+    /// Panics if `radix` is not in the range from 2 to 36.
+    pub fn with_radix(n: T, radix: u32) -> Self {
+        assert!(
+            (2..=36).contains(&radix),
+            "radix must be between 2 and 36, got {radix}"
+        );
+        let Ok(radix) = Magnitude::<T>::try_from(radix as u16) else {
+            unreachable!("all integer types can represent numbers up to 36")
+        };
+        let remainder = n.into_impl().unsigned_abs();
+        let mut power = Magnitude::<T>::ONE;
+        let mut len = 1;
+        while remainder / power >= radix {
+            power = power * radix;
+            len += 1;
+        }
+        Digits {
+            remainder,
+            power,
+            radix,
+            len,
+        }
+    }
+}
+
+impl<T: Integer> Iterator for Digits<T> {
+    type Item = u32;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        let digit = self.remainder / self.power;
+        self.remainder = self.remainder % self.power;
+        self.power = self.power / self.radix;
+        self.len -= 1;
+        Some(digit.as_usize() as u32)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<T: Integer> ExactSizeIterator for Digits<T> {}
+
+impl<T: Integer> FusedIterator for Digits<T> {}
+
+/// Formats the digits of `value` in the given base using a custom table of digit glyphs,
+/// starting with the most significant digit.
+///
+/// Each digit `d` is written as `digits[d]`. The sign of the value is ignored.
+/// This is the building block of the digit-glyph formats of this crate
+/// such as [`Segmented`](crate::Segmented), [`Outlined`](crate::Outlined)
+/// and [`Superscript`](crate::Superscript), and can be used to create your own.
+///
+/// ## Panics
+/// Panics if `base` is not in the range from 2 to 36
+/// or if the table contains fewer than `base` digits.
+///
+/// ```
+/// use fmtastic::fmt_with;
+///
+/// let mut hex = String::new();
+/// fmt_with(0xC0DE_u16, 16, &["0", "1", "2", "3", "4", "5", "6", "7", "8", "9", "🅰", "🅱", "🅲", "🅳", "🅴", "🅵"], &mut hex).unwrap();
+/// assert_eq!("🅲0🅳🅴", hex);
+/// ```
+pub fn fmt_with<T, D>(value: T, base: u32, digits: &[D], w: &mut impl fmt::Write) -> fmt::Result
+where
+    T: Integer,
+    D: fmt::Display,
+{
+    assert!(
+        digits.len() >= base as usize,
+        "expected at least {base} digits, got {}",
+        digits.len()
+    );
+    fmt_digits(
+        Digits::with_radix(value, base).map(|digit| digit as u8),
+        digits,
+        w,
+    )
+}
+
+/// Formats digits that have already been computed using a custom table of digit glyphs,
+/// e.g. the digits of a number that does not fit into a primitive integer.
+///
+/// Each digit `d` is written as `digits[d]`, in the order of the iterator.
+/// See [`fmt_with`] for formatting an integer.
+///
+/// ## Panics
+/// Panics if a digit is not a valid index into the table.
+///
+/// ```
+/// use fmtastic::fmt_digits;
+///
+/// let mut output = String::new();
+/// fmt_digits([4, 2], &['𝟘', '𝟙', '𝟚', '𝟛', '𝟜', '𝟝', '𝟞', '𝟟', '𝟠', '𝟡'], &mut output).unwrap();
+/// assert_eq!("𝟜𝟚", output);
+/// ```
+pub fn fmt_digits<D>(
+    digits: impl IntoIterator<Item = u8>,
+    table: &[D],
+    w: &mut impl fmt::Write,
+) -> fmt::Result
+where
+    D: fmt::Display,
+{
+    digits.into_iter().try_for_each(|digit| {
+        let glyph = table.get(usize::from(digit)).unwrap_or_else(|| {
+            panic!(
+                "digit {digit} is out of range for a table of {} digits",
+                table.len()
+            )
+        });
+        write!(w, "{glyph}")
+    })
+}
+
+/// Returns the number of digits of the largest unsigned integer
+/// with the given number of bits in the given radix.
+pub(crate) const fn max_digits(bits: u32, radix: u128) -> usize {
+    let mut n = u128::MAX >> (u128::BITS - bits);
+    let mut len = 1;
+    while n >= radix {
+        n /= radix;
+        len += 1;
+    }
+    len
+}
+
+/// Formats the digits of an integer in the given radix using a table of digit glyphs,
+/// preceded by a sign using the given glyphs, see [`WithSign`](crate::WithSign).
+pub(crate) fn fmt_with_digit_table<T: IntegerImpl, D: fmt::Display>(
+    n: T,
+    radix: u32,
+    table: &[D],
+    glyphs: SignGlyphs,
+    f: &mut fmt::Formatter<'_>,
+) -> fmt::Result {
+    let digits = DigitTable { n, radix, table };
+    fmt::Display::fmt(&WithSign::from_impl(n, digits).glyphs(glyphs), f)
+}
+
+/// The digits of the magnitude of `n`, without a sign.
+struct DigitTable<'a, T, D> {
+    n: T,
+    radix: u32,
+    table: &'a [D],
+}
+
+impl<T: IntegerImpl, D: fmt::Display> fmt::Display for DigitTable<'_, T, D> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_with(self.n.into_public(), self.radix, self.table, f)
+    }
+}
+
+/// Converts an ASCII digit (`'0'` to `'9'`) to its value.
+pub(crate) fn digit_from_char<T: Integer>(c: char) -> Result<T, ParseDigitError> {
+    let digit = c.to_digit(10).ok_or(ParseDigitError(()))?;
+    let Ok(digit) = T::Impl::try_from(digit as u16) else {
+        unreachable!("all integer types can represent a single digit")
+    };
+    Ok(T::from_impl(digit))
+}
+
+/// An error which can be returned when converting a [`char`] that is not
+/// an ASCII digit (`'0'` to `'9'`) to a digit format such as [`Segmented`](crate::Segmented).
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct ParseDigitError(());
+
+impl fmt::Display for ParseDigitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("expected an ASCII digit")
+    }
+}
 
 /// Iterates the digits of the given integer. Zero has one digit.
-pub(crate) fn iter_digits<T: IntegerImpl, B: Base<T>>(n: T) -> impl Iterator<Item = usize> {
-    let n = n.abs();
+pub(crate) fn iter_digits<T: IntegerImpl, B: Base<T::Magnitude>>(
+    n: T,
+) -> impl Iterator<Item = usize> {
+    let n = n.unsigned_abs();
     B::powers(n).scan(n, move |remainder, power| {
         let digit = *remainder / power;
         *remainder = n % power;
@@ -10,6 +227,21 @@ pub(crate) fn iter_digits<T: IntegerImpl, B: Base<T>>(n: T) -> impl Iterator<Ite
     })
 }
 
+/// Iterates the digits of the given integer, starting with the least significant digit.
+/// Zero has one digit.
+pub(crate) fn iter_digits_rev<T: IntegerImpl, B: Base<T::Magnitude>>(
+    n: T,
+) -> impl Iterator<Item = usize> {
+    let mut n = Some(n.unsigned_abs());
+    core::iter::from_fn(move || {
+        let remainder = n?;
+        let digit = remainder % B::VALUE;
+        let quotient = remainder / B::VALUE;
+        n = (quotient != T::Magnitude::ZERO).then_some(quotient);
+        Some(digit.as_usize())
+    })
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -39,4 +271,117 @@ mod test {
         let digits: Vec<_> = iter_digits::<_, <u32 as IntegerImpl>::BaseTen>(-1234).collect();
         assert_eq!(vec![1, 2, 3, 4], digits);
     }
+
+    #[test]
+    fn public_digits_have_exact_length() {
+        assert_eq!(1, Digits::new(0_u8).len());
+        assert_eq!(3, Digits::new(u8::MAX).len());
+        assert_eq!(39, Digits::new(u128::MAX).len());
+        assert_eq!(128, Digits::with_radix(i128::MIN, 2).len());
+        let mut digits = Digits::new(1234_u32);
+        digits.next();
+        assert_eq!(3, digits.len());
+    }
+
+    #[test]
+    fn public_digits_can_be_cloned_to_restart() {
+        let mut digits = Digits::new(907_i16);
+        assert_eq!(Some(9), digits.next());
+        let copy = digits.clone();
+        assert_eq!(vec![0, 7], digits.collect::<Vec<_>>());
+        assert_eq!(vec![0, 7], copy.collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn public_digits_match_internal_digits() {
+        for n in [0_u64, 1, 9, 10, 99, 100, 12345, u64::MAX] {
+            let expected: Vec<_> = iter_digits::<_, <u64 as IntegerImpl>::BaseTen>(n)
+                .map(|d| d as u32)
+                .collect();
+            assert_eq!(expected, Digits::new(n).collect::<Vec<_>>());
+        }
+        assert_eq!(
+            vec![3, 5],
+            Digits::with_radix(3 * 36 + 5, 36).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn public_digits_panic_on_invalid_radix() {
+        let _ = Digits::with_radix(1, 37);
+    }
+
+    // `u16` and `i16` stand in for `usize` and `isize` on 16-bit targets.
+    #[test]
+    fn iterates_digits_of_16_bit_extremes() {
+        let digits: Vec<_> = iter_digits::<_, <u16 as IntegerImpl>::BaseTen>(u16::MAX).collect();
+        assert_eq!(vec![6, 5, 5, 3, 5], digits);
+        let digits: Vec<_> = iter_digits::<_, <i16 as IntegerImpl>::BaseTen>(i16::MIN).collect();
+        assert_eq!(vec![3, 2, 7, 6, 8], digits);
+        assert_eq!(
+            16,
+            iter_digits::<_, <u16 as IntegerImpl>::BaseTwo>(u16::MAX).count()
+        );
+        assert_eq!(
+            4,
+            iter_digits::<_, <u16 as IntegerImpl>::BaseSixteen>(u16::MAX).count()
+        );
+        assert_eq!(
+            vec![1, 14, 20, 15],
+            Digits::with_radix(u16::MAX, 36).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    #[cfg(target_pointer_width = "16")]
+    fn iterates_digits_of_usize_on_16_bit_targets() {
+        assert_eq!(
+            vec![6, 5, 5, 3, 5],
+            Digits::new(usize::MAX).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![3, 2, 7, 6, 8],
+            Digits::new(isize::MIN).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn formats_with_custom_digits() {
+        let format = |value: u64, base: u32, digits: &[char]| {
+            let mut output = String::new();
+            fmt_with(value, base, digits, &mut output).unwrap();
+            output
+        };
+        let ascii: Vec<_> = ('0'..='9').collect();
+        assert_eq!("1234567890", format(1234567890, 10, &ascii));
+        assert_eq!("0", format(0, 10, &ascii));
+        assert_eq!("101010", format(42, 2, &ascii));
+        let emoji = [
+            '0', '1', '2', '3', '4', '5', '6', '7', '8', '9', '🍎', '🍌', '🍒', '🍇', '🥝', '🍋',
+        ];
+        assert_eq!("🍇🥝🍎🍇🍌🥝🥝🍋", format(0xDEAD_BEEF, 16, &emoji));
+        assert_eq!("🍋🍋", format(u8::MAX.into(), 16, &emoji));
+    }
+
+    #[test]
+    #[should_panic]
+    fn formatting_digit_out_of_range_panics() {
+        let _ = fmt_digits([1, 10], &['0', '1'], &mut String::new());
+    }
+
+    #[test]
+    #[should_panic]
+    fn formatting_with_too_few_digits_panics() {
+        let _ = fmt_with(10_u8, 16, &['0', '1'], &mut String::new());
+    }
+
+    #[test]
+    fn iterates_digits_in_reverse() {
+        let digits: Vec<_> =
+            iter_digits_rev::<_, <u32 as IntegerImpl>::BaseTen>(1234567890_u32).collect();
+        assert_eq!(vec![0, 9, 8, 7, 6, 5, 4, 3, 2, 1], digits);
+        let digits: Vec<_> = iter_digits_rev::<_, <u32 as IntegerImpl>::BaseTen>(0_u32).collect();
+        assert_eq!(vec![0], digits);
+    }
 }
@@ -0,0 +1,200 @@
+use crate::integer::IntegerImpl;
+use crate::{Integer, OutOfRange, Plain};
+use core::fmt;
+
+/// Formats an integer from 0 to 10 as a circled digit
+/// in one of the styles of the [Dingbats] block, e.g. `❶`, `➀` or `➊`.
+///
+/// The numbers 1 to 10 are taken from the Dingbats block.
+/// Zero is not part of the Dingbats block and uses the matching glyph
+/// from the [Enclosed Alphanumerics] (`⓿`) or the
+/// [Enclosed Alphanumeric Supplement] block (`🄋`, `🄌`) instead.
+///
+/// Values outside of the range from 0 to 10 cannot be formatted
+/// and are rejected by [`Dingbat::new`] with an [`OutOfRange`] error.
+///
+/// | Style                                       | 0    | 1    | 10   |
+/// |---------------------------------------------|------|------|------|
+/// | [`DingbatStyle::NegativeCircled`] (default) | `⓿`  | `❶`  | `❿`  |
+/// | [`DingbatStyle::CircledSansSerif`]          | `🄋`  | `➀`  | `➉`  |
+/// | [`DingbatStyle::NegativeCircledSansSerif`]  | `🄌`  | `➊`  | `➓`  |
+///
+/// ```
+/// # use fmtastic::{Dingbat, DingbatStyle};
+/// assert_eq!("❸", Dingbat::new(3_u8).unwrap().to_string());
+/// assert_eq!("➉", Dingbat::new(10_u8).unwrap().style(DingbatStyle::CircledSansSerif).to_string());
+/// assert!(Dingbat::new(11_u8).is_err());
+/// ```
+///
+/// [Dingbats]: https://www.unicode.org/charts/PDF/U2700.pdf
+/// [Enclosed Alphanumerics]: https://www.unicode.org/charts/PDF/U2460.pdf
+/// [Enclosed Alphanumeric Supplement]: https://www.unicode.org/charts/PDF/U1F100.pdf
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct Dingbat<T>(T, DingbatStyle);
+
+/// The style of a [`Dingbat`] digit.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+#[non_exhaustive]
+pub enum DingbatStyle {
+    /// Negative (white on black) circled digits, e.g. `❶` (U+2776).
+    #[default]
+    NegativeCircled,
+    /// Circled sans-serif digits, e.g. `➀` (U+2780).
+    CircledSansSerif,
+    /// Negative (white on black) circled sans-serif digits, e.g. `➊` (U+278A).
+    NegativeCircledSansSerif,
+}
+
+impl<T> Dingbat<T>
+where
+    T: Integer,
+{
+    /// Creates a new [`Dingbat`] digit using the [`DingbatStyle::NegativeCircled`] style.
+    /// Returns an [`OutOfRange`] error if the value is not between 0 and 10.
+    pub fn new(value: T) -> Result<Dingbat<T>, OutOfRange<T>> {
+        let max = ten::<T::Impl>();
+        if T::Impl::ZERO <= value.into_impl() && value.into_impl() <= max {
+            Ok(Dingbat(value, DingbatStyle::default()))
+        } else {
+            Err(OutOfRange {
+                value,
+                min: T::from_impl(T::Impl::ZERO),
+                max: T::from_impl(max),
+            })
+        }
+    }
+}
+
+impl<T> Dingbat<T> {
+    /// Sets the style of the digit.
+    #[must_use]
+    pub fn style(mut self, style: DingbatStyle) -> Self {
+        self.1 = style;
+        self
+    }
+
+    /// Returns a reference to the wrapped value.
+    #[must_use]
+    pub fn as_inner(&self) -> &T {
+        &self.0
+    }
+
+    /// Formats the wrapped value using its standard formatting,
+    /// ignoring the glyphs of this format. See [`Plain`].
+    #[must_use]
+    pub fn plain(self) -> Plain<T> {
+        Plain(self.0)
+    }
+
+    /// Applies a function to the wrapped value, keeping the style.
+    /// Returns an [`OutOfRange`] error if the new value is not between 0 and 10.
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> Result<Dingbat<U>, OutOfRange<U>>
+    where
+        U: Integer,
+    {
+        Dingbat::new(f(self.0)).map(|dingbat| dingbat.style(self.1))
+    }
+}
+
+impl<T> fmt::Display for Dingbat<T>
+where
+    T: Integer,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let digits = match self.1 {
+            DingbatStyle::NegativeCircled => &NEGATIVE_CIRCLED,
+            DingbatStyle::CircledSansSerif => &CIRCLED_SANS_SERIF,
+            DingbatStyle::NegativeCircledSansSerif => &NEGATIVE_CIRCLED_SANS_SERIF,
+        };
+        f.write_str(digits[self.0.into_impl().as_usize()])
+    }
+}
+
+pub(crate) fn ten<T: IntegerImpl>() -> T {
+    let Ok(ten) = T::try_from(10) else {
+        unreachable!("all integer types can represent ten")
+    };
+    ten
+}
+
+const NEGATIVE_CIRCLED: [&str; 11] = [
+    "\u{24FF}", "\u{2776}", "\u{2777}", "\u{2778}", "\u{2779}", "\u{277A}", "\u{277B}", "\u{277C}",
+    "\u{277D}", "\u{277E}", "\u{277F}",
+];
+
+const CIRCLED_SANS_SERIF: [&str; 11] = [
+    "\u{1F10B}",
+    "\u{2780}",
+    "\u{2781}",
+    "\u{2782}",
+    "\u{2783}",
+    "\u{2784}",
+    "\u{2785}",
+    "\u{2786}",
+    "\u{2787}",
+    "\u{2788}",
+    "\u{2789}",
+];
+
+const NEGATIVE_CIRCLED_SANS_SERIF: [&str; 11] = [
+    "\u{1F10C}",
+    "\u{278A}",
+    "\u{278B}",
+    "\u{278C}",
+    "\u{278D}",
+    "\u{278E}",
+    "\u{278F}",
+    "\u{2790}",
+    "\u{2791}",
+    "\u{2792}",
+    "\u{2793}",
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn formats_each_style() {
+        for (style, expected) in [
+            (DingbatStyle::NegativeCircled, ["⓿", "❶", "❿"]),
+            (DingbatStyle::CircledSansSerif, ["🄋", "➀", "➉"]),
+            (DingbatStyle::NegativeCircledSansSerif, ["🄌", "➊", "➓"]),
+        ] {
+            for (expected, value) in expected.into_iter().zip([0_u8, 1, 10]) {
+                let dingbat = Dingbat::new(value).unwrap().style(style);
+                assert_eq!(expected, dingbat.to_string());
+            }
+        }
+    }
+
+    #[test]
+    fn rejects_values_out_of_range() {
+        assert_eq!(
+            Err(OutOfRange {
+                value: 11_u32,
+                min: 0,
+                max: 10
+            }),
+            Dingbat::new(11_u32)
+        );
+        assert_eq!(
+            Err(OutOfRange {
+                value: -1_i8,
+                min: 0,
+                max: 10
+            }),
+            Dingbat::new(-1_i8)
+        );
+        assert!(Dingbat::new(u128::MAX).is_err());
+        assert!(Dingbat::new(10).unwrap().map(|n| n + 1).is_err());
+    }
+
+    #[test]
+    fn keeps_style_when_mapping() {
+        let dingbat = Dingbat::new(1_u8)
+            .unwrap()
+            .style(DingbatStyle::CircledSansSerif);
+        assert_eq!("➁", dingbat.map(|n| n + 1).unwrap().to_string());
+    }
+}
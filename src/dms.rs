@@ -0,0 +1,226 @@
+use crate::float::fmt_non_finite;
+use core::fmt::{self, Write};
+
+/// Formats an angle given in decimal degrees as degrees, minutes and seconds,
+/// using the degree sign (`°`), prime (`′`) and double prime (`″`), e.g. `12°34′56″`.
+///
+/// Minutes and seconds are always written using two digits.
+/// The seconds are rounded to the nearest unit of the [precision](Dms::precision)
+/// (halfway cases are rounded away from zero), which may carry over into the minutes
+/// and degrees: `0.99999999°` is formatted as `1°00′00″` rather than `0°59′60″`.
+///
+/// Negative angles are prefixed with a minus (`-`) by default.
+/// Use [`Dms::latitude`] or [`Dms::longitude`] to write the hemisphere instead.
+/// Angles that round to zero are never negative.
+///
+/// Values that are not finite are written as `NaN`, `∞` (U+221E) or `-∞`
+/// without a unit or hemisphere, like in all formats accepting floating point values.
+/// The angle is counted in units of the precision using 128-bit integers, which supports
+/// angles below 9.4 × 10³⁴ degrees with whole seconds, and ten times less for each
+/// fractional digit. Larger angles are written using their standard formatting, e.g. for `1e40`.
+///
+/// ```
+/// # use fmtastic::Dms;
+/// assert_eq!("12°34′56″", Dms::new(12.582222).to_string());
+/// assert_eq!("-12°34′56.00″", Dms::new(-12.582222).precision(2).to_string());
+/// assert_eq!("33°52′07.7″S", Dms::new(-33.8688).precision(1).latitude().to_string());
+/// assert_eq!("151°12′33″E", Dms::new(151.2093_f32).longitude().to_string());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Dms<F> {
+    value: F,
+    precision: u8,
+    sign: DmsSign,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum DmsSign {
+    Minus,
+    Latitude,
+    Longitude,
+}
+
+impl<F> Dms<F>
+where
+    F: Copy + Into<f64>,
+{
+    /// Creates a new angle from decimal degrees, formatted with whole seconds
+    /// and a leading minus for negative angles.
+    #[must_use]
+    pub fn new(value: F) -> Self {
+        Dms {
+            value,
+            precision: 0,
+            sign: DmsSign::Minus,
+        }
+    }
+}
+
+impl<F> Dms<F> {
+    /// The largest supported number of fractional digits of the seconds.
+    pub const MAX_PRECISION: u8 = 9;
+
+    /// Sets the number of fractional digits of the seconds, zero by default.
+    ///
+    /// ## Panics
+    /// Panics if `digits` is greater than [`Dms::MAX_PRECISION`].
+    #[must_use]
+    pub fn precision(mut self, digits: u8) -> Self {
+        assert!(
+            digits <= Self::MAX_PRECISION,
+            "precision must be at most {}, got {digits}",
+            Self::MAX_PRECISION
+        );
+        self.precision = digits;
+        self
+    }
+
+    /// Writes the hemisphere of a latitude (`N` or `S`) after the angle instead of a sign.
+    #[must_use]
+    pub fn latitude(mut self) -> Self {
+        self.sign = DmsSign::Latitude;
+        self
+    }
+
+    /// Writes the hemisphere of a longitude (`E` or `W`) after the angle instead of a sign.
+    #[must_use]
+    pub fn longitude(mut self) -> Self {
+        self.sign = DmsSign::Longitude;
+        self
+    }
+
+    /// Returns a reference to the angle in decimal degrees.
+    #[must_use]
+    pub fn as_inner(&self) -> &F {
+        &self.value
+    }
+}
+
+impl<F> fmt::Display for Dms<F>
+where
+    F: Copy + Into<f64>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let value: f64 = self.value.into();
+        if let Some(result) = fmt_non_finite(value, f) {
+            return result;
+        }
+
+        let scale = 10_u128.pow(u32::from(self.precision));
+        let magnitude = if value < 0.0 { -value } else { value };
+        let units = magnitude * 3600.0 * scale as f64 + 0.5;
+        // The cast would saturate, so the seconds cannot be represented.
+        if units >= u128::MAX as f64 {
+            return write!(f, "{value}");
+        }
+        // Rounds to the nearest unit, the cast truncates.
+        let units = units as u128;
+        let negative = value < 0.0 && units != 0;
+
+        let (seconds, fraction) = (units / scale, units % scale);
+        if negative && self.sign == DmsSign::Minus {
+            f.write_char('-')?;
+        }
+        write!(
+            f,
+            "{}\u{00B0}{:02}\u{2032}{:02}",
+            seconds / 3600,
+            seconds / 60 % 60,
+            seconds % 60
+        )?;
+        if self.precision > 0 {
+            write!(
+                f,
+                ".{fraction:0width$}",
+                width = usize::from(self.precision)
+            )?;
+        }
+        f.write_char('\u{2033}')?;
+        match (self.sign, negative) {
+            (DmsSign::Minus, _) => Ok(()),
+            (DmsSign::Latitude, false) => f.write_char('N'),
+            (DmsSign::Latitude, true) => f.write_char('S'),
+            (DmsSign::Longitude, false) => f.write_char('E'),
+            (DmsSign::Longitude, true) => f.write_char('W'),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn formats_exact_angles() {
+        for (expected, degrees) in [
+            ("0°00′00″", 0.0),
+            ("12°34′56″", 12.0 + 34.0 / 60.0 + 56.0 / 3600.0),
+            ("90°00′00″", 90.0),
+            ("1°01′01″", 1.0 + 1.0 / 60.0 + 1.0 / 3600.0),
+            ("-45°30′00″", -45.5),
+            ("400°00′00″", 400.0),
+        ] {
+            assert_eq!(expected, Dms::new(degrees).to_string());
+        }
+        assert_eq!(
+            "0°00′00.25″",
+            Dms::new(0.25 / 3600.0).precision(2).to_string()
+        );
+    }
+
+    #[test]
+    fn rounds_over_into_minutes_and_degrees() {
+        assert_eq!("1°00′00″", Dms::new(0.999_999_9).to_string());
+        assert_eq!("0°01′00″", Dms::new(59.6 / 3600.0).to_string());
+        assert_eq!("0°00′59″", Dms::new(59.4 / 3600.0).to_string());
+        assert_eq!(
+            "0°01′00.0″",
+            Dms::new(59.96 / 3600.0).precision(1).to_string()
+        );
+        assert_eq!(
+            "0°00′59.9″",
+            Dms::new(59.94 / 3600.0).precision(1).to_string()
+        );
+        assert_eq!("13°00′00″", Dms::new(12.999_99).to_string());
+    }
+
+    #[test]
+    fn formats_sign_or_hemisphere() {
+        let angle = -(33.0 + 52.0 / 60.0 + 8.0 / 3600.0);
+        assert_eq!("-33°52′08″", Dms::new(angle).to_string());
+        assert_eq!("33°52′08″S", Dms::new(angle).latitude().to_string());
+        assert_eq!("33°52′08″N", Dms::new(-angle).latitude().to_string());
+        assert_eq!("33°52′08″W", Dms::new(angle).longitude().to_string());
+        assert_eq!("33°52′08″E", Dms::new(-angle).longitude().to_string());
+        assert_eq!("0°00′00″", Dms::new(-0.0).to_string());
+        assert_eq!("0°00′00″N", Dms::new(-0.000_000_1).latitude().to_string());
+    }
+
+    #[test]
+    fn formats_non_finite_values() {
+        assert_eq!("NaN", Dms::new(f64::NAN).to_string());
+        assert_eq!("∞", Dms::new(f64::INFINITY).to_string());
+        assert_eq!("-∞", Dms::new(f64::NEG_INFINITY).to_string());
+        assert_eq!("∞", Dms::new(f32::INFINITY).precision(2).to_string());
+        assert_eq!("-∞", Dms::new(f64::NEG_INFINITY).latitude().to_string());
+        assert_eq!("NaN", Dms::new(f32::NAN).longitude().to_string());
+    }
+
+    #[test]
+    fn formats_too_large_angles_using_standard_formatting() {
+        assert_eq!(1e40.to_string(), Dms::new(1e40).to_string());
+        assert_eq!((-1e40).to_string(), Dms::new(-1e40).latitude().to_string());
+        assert_eq!(f64::MAX.to_string(), Dms::new(f64::MAX).to_string());
+        assert_eq!(1e30.to_string(), Dms::new(1e30).precision(9).to_string());
+        assert_eq!(
+            "1237940039285380274899124224°00′00″",
+            Dms::new((1_u128 << 90) as f64).to_string()
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "precision must be at most 9")]
+    fn rejects_too_many_digits() {
+        let _ = Dms::new(1.0).precision(10);
+    }
+}
@@ -0,0 +1,191 @@
+use crate::digits::max_digits;
+use crate::integer::IntegerImpl;
+use crate::outlined::fmt_outlined;
+use crate::{Plain, UnsignedInteger};
+use core::fmt;
+
+/// Formats an unsigned integer using the double-struck digits `𝟘` – `𝟡` (U+1D7D8 – U+1D7E1)
+/// from the [Mathematical Alphanumeric Symbols] block, e.g. for math expressions.
+///
+/// Hexadecimal digits use the double-struck letters `𝔸` – `𝔽`
+/// (or `𝕒` – `𝕗` for [`LowerHex`](fmt::LowerHex)).
+/// The double-struck capital C is encoded as `ℂ` (U+2102) in the [Letterlike Symbols] block,
+/// as it existed before the other letters were added.
+///
+/// [Mathematical Alphanumeric Symbols]: https://www.unicode.org/charts/PDF/U1D400.pdf
+/// [Letterlike Symbols]: https://www.unicode.org/charts/PDF/U2100.pdf
+///
+/// ```
+/// use fmtastic::DoubleStruck;
+///
+/// assert_eq!("𝟞𝟚𝟠", DoubleStruck(628_u32).to_string());
+///
+/// // Binary
+/// assert_eq!("𝟙𝟘𝟙", format!("{:b}", DoubleStruck(5_u8)));
+///
+/// // Hexadecimal
+/// assert_eq!("ℂ𝟘𝔻𝔼", format!("{:X}", DoubleStruck(0xC0DE_u16)));
+/// assert_eq!("𝕔𝟘𝕕𝕖", format!("{:x}", DoubleStruck(0xC0DE_u16)));
+/// ```
+///
+/// ## Layout
+/// `DoubleStruck<T>` is `#[repr(transparent)]`, so it has the same size, alignment and ABI as `T`.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct DoubleStruck<T>(pub T);
+
+impl<T> DoubleStruck<T> {
+    /// Returns a reference to the wrapped value.
+    #[must_use]
+    pub fn as_inner(&self) -> &T {
+        &self.0
+    }
+
+    /// Applies a function to the wrapped value.
+    ///
+    /// ```
+    /// # use fmtastic::DoubleStruck;
+    /// assert_eq!(DoubleStruck(4), DoubleStruck(3_u8).map(|n| n + 1));
+    /// ```
+    #[must_use]
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> DoubleStruck<U> {
+        DoubleStruck(f(self.0))
+    }
+
+    /// Formats the wrapped value using its standard formatting,
+    /// ignoring the glyphs of this format. See [`Plain`].
+    #[must_use]
+    pub fn plain(self) -> Plain<T> {
+        Plain(self.0)
+    }
+}
+
+impl<T> From<T> for DoubleStruck<T>
+where
+    T: UnsignedInteger,
+{
+    fn from(value: T) -> Self {
+        DoubleStruck(value)
+    }
+}
+
+impl<T> DoubleStruck<T>
+where
+    T: UnsignedInteger,
+{
+    /// The largest number of bytes written when formatting a value of type `T`
+    /// using any of the formatting traits, for sizing buffers.
+    ///
+    /// The longest output is the binary representation of the largest value,
+    /// with one digit per bit of `T`. Each digit is encoded in four bytes.
+    ///
+    /// ```
+    /// # use fmtastic::DoubleStruck;
+    /// assert_eq!(16 * 4, DoubleStruck::<u16>::MAX_LEN);
+    /// ```
+    pub const MAX_LEN: usize = max_digits(<T::Impl as IntegerImpl>::BITS, 2) * 4;
+}
+
+impl<T> fmt::Binary for DoubleStruck<T>
+where
+    T: UnsignedInteger,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_outlined::<_, <T::Impl as IntegerImpl>::BaseTwo>(self.0.into_impl(), &DIGITS, f)
+    }
+}
+
+impl<T> fmt::Display for DoubleStruck<T>
+where
+    T: UnsignedInteger,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_outlined::<_, <T::Impl as IntegerImpl>::BaseTen>(self.0.into_impl(), &DIGITS, f)
+    }
+}
+
+impl<T> fmt::UpperHex for DoubleStruck<T>
+where
+    T: UnsignedInteger,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_outlined::<_, <T::Impl as IntegerImpl>::BaseSixteen>(self.0.into_impl(), &DIGITS, f)
+    }
+}
+
+impl<T> fmt::LowerHex for DoubleStruck<T>
+where
+    T: UnsignedInteger,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_outlined::<_, <T::Impl as IntegerImpl>::BaseSixteen>(
+            self.0.into_impl(),
+            &LOWERCASE_DIGITS,
+            f,
+        )
+    }
+}
+
+const DIGITS: [&str; 16] = [
+    "𝟘", "𝟙", "𝟚", "𝟛", "𝟜", "𝟝", "𝟞", "𝟟", "𝟠", "𝟡", "𝔸", "𝔹", "ℂ", "𝔻", "𝔼", "𝔽",
+];
+
+const LOWERCASE_DIGITS: [&str; 16] = [
+    "𝟘", "𝟙", "𝟚", "𝟛", "𝟜", "𝟝", "𝟞", "𝟟", "𝟠", "𝟡", "𝕒", "𝕓", "𝕔", "𝕕", "𝕖", "𝕗",
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn maps_digits_to_code_points() {
+        for (index, digit) in DIGITS[..10].iter().enumerate() {
+            assert_eq!(
+                Some(0x1D7D8 + index as u32),
+                digit.chars().next().map(u32::from)
+            );
+        }
+        let upper: Vec<_> = DIGITS[10..]
+            .iter()
+            .flat_map(|d| d.chars())
+            .map(u32::from)
+            .collect();
+        assert_eq!(
+            vec![0x1D538, 0x1D539, 0x2102, 0x1D53B, 0x1D53C, 0x1D53D],
+            upper
+        );
+        let lower: Vec<_> = LOWERCASE_DIGITS[10..]
+            .iter()
+            .flat_map(|d| d.chars())
+            .map(u32::from)
+            .collect();
+        assert_eq!((0x1D552..=0x1D557).collect::<Vec<_>>(), lower);
+    }
+
+    #[test]
+    fn formats_decimal_and_binary() {
+        assert_eq!("𝟘", DoubleStruck(0_u8).to_string());
+        assert_eq!("𝟘", format!("{:b}", DoubleStruck(0_u8)));
+        assert_eq!("𝟙𝟚𝟛𝟜𝟝𝟞𝟟𝟠𝟡𝟘", DoubleStruck(1_234_567_890_u32).to_string());
+        assert_eq!("𝟙𝟘𝟙𝟘𝟙𝟘", format!("{:b}", DoubleStruck(0b101010_u8)));
+        assert_eq!("𝔽𝔽", format!("{:X}", DoubleStruck(u8::MAX)));
+    }
+
+    #[test]
+    fn output_never_exceeds_max_len() {
+        assert_eq!(
+            DoubleStruck::<u128>::MAX_LEN,
+            format!("{:b}", DoubleStruck(u128::MAX)).len()
+        );
+        assert!((0..=u8::MAX).all(|n| {
+            [
+                DoubleStruck(n).to_string(),
+                format!("{:b}", DoubleStruck(n)),
+                format!("{:X}", DoubleStruck(n)),
+            ]
+            .iter()
+            .all(|s| s.len() <= DoubleStruck::<u8>::MAX_LEN)
+        }));
+    }
+}
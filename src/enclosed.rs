@@ -0,0 +1,99 @@
+use core::fmt::{self, Write};
+
+/// Formats each digit of a digit-glyph format such as [`Segmented`](crate::Segmented)
+/// or [`Outlined`](crate::Outlined) followed by a combining enclosing mark,
+/// created by `enclosed` on these formats.
+///
+/// The enclosing mark is appended after every character written by the inner format.
+/// Whether the enclosure is drawn around the digit depends on font support
+/// and text shaping, so this is best-effort.
+///
+/// ```
+/// use fmtastic::{Enclosure, Outlined, Segmented};
+///
+/// assert_eq!("🯱\u{20DE}🯲\u{20DE}", Segmented(12_u8).enclosed(Enclosure::Square).to_string());
+/// assert_eq!("𜳱\u{20E3}𜳰\u{20E3}", format!("{:b}", Outlined(2_u8).enclosed(Enclosure::Keycap)));
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct Enclosed<F>(pub F, pub Enclosure);
+
+/// A combining mark that encloses the preceding character.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum Enclosure {
+    /// Combining enclosing square (U+20DE).
+    Square,
+    /// Combining enclosing keycap (U+20E3).
+    Keycap,
+}
+
+impl Enclosure {
+    fn mark(self) -> char {
+        match self {
+            Enclosure::Square => '\u{20DE}',
+            Enclosure::Keycap => '\u{20E3}',
+        }
+    }
+}
+
+impl<F> fmt::Display for Enclosed<F>
+where
+    F: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(EnclosingWriter(f, self.1), "{}", self.0)
+    }
+}
+
+impl<F> fmt::Binary for Enclosed<F>
+where
+    F: fmt::Binary,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(EnclosingWriter(f, self.1), "{:b}", self.0)
+    }
+}
+
+impl<F> fmt::UpperHex for Enclosed<F>
+where
+    F: fmt::UpperHex,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(EnclosingWriter(f, self.1), "{:X}", self.0)
+    }
+}
+
+struct EnclosingWriter<'a, 'b>(&'a mut fmt::Formatter<'b>, Enclosure);
+
+impl Write for EnclosingWriter<'_, '_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        s.chars().try_for_each(|c| self.write_char(c))
+    }
+
+    fn write_char(&mut self, c: char) -> fmt::Result {
+        self.0.write_char(c)?;
+        self.0.write_char(self.1.mark())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Outlined, Segmented};
+
+    #[test]
+    fn appends_enclosure_after_each_digit() {
+        assert_eq!(
+            "\u{1FBF6}\u{20DE}\u{1FBF2}\u{20DE}\u{1FBF8}\u{20DE}",
+            Segmented(628_u32).enclosed(Enclosure::Square).to_string()
+        );
+        assert_eq!(
+            "\u{1CCF0}\u{20E3}",
+            Outlined(0_u8).enclosed(Enclosure::Keycap).to_string()
+        );
+        assert_eq!(
+            "\u{1CCD9}\u{20DE}\u{1CCDB}\u{20DE}",
+            format!("{:X}", Outlined(0xDF_u8).enclosed(Enclosure::Square))
+        );
+    }
+}
@@ -0,0 +1,17 @@
+use core::fmt;
+
+/// Writes `NaN`, `∞` or `-∞` if `value` is not finite.
+///
+/// All formats accepting floating point values write non-finite values this way,
+/// regardless of their other options. Returns `None` for finite values.
+pub(crate) fn fmt_non_finite(value: f64, f: &mut fmt::Formatter<'_>) -> Option<fmt::Result> {
+    if value.is_nan() {
+        Some(f.write_str("NaN"))
+    } else if value == f64::INFINITY {
+        Some(f.write_str("\u{221E}"))
+    } else if value == f64::NEG_INFINITY {
+        Some(f.write_str("-\u{221E}"))
+    } else {
+        None
+    }
+}
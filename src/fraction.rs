@@ -1,7 +1,11 @@
-use crate::integer::{IntegerImpl, Sign};
+use crate::digits::iter_digits;
+use crate::histogram::bar_cells;
+use crate::integer::{gcd, scale_ratio, IntegerImpl, Sign};
 use crate::Integer;
-use crate::{Subscript, Superscript};
+use crate::{Recurring, RecurringMark, SignZero, Subscript, Superscript, WithSign};
+use core::cmp::Ordering;
 use core::fmt::{self, Write};
+use core::iter::{Product, Sum};
 
 /// A [Vulgar Fraction] that can be formatted as a unicode fraction using the [`Display`][`core::fmt::Display`] trait.
 ///
@@ -36,7 +40,8 @@ use core::fmt::{self, Write};
 ///
 /// [Vulgar Fraction]: https://en.wikipedia.org/wiki/Fraction_(mathematics)#Simple,_common,_or_vulgar_fractions
 /// [single character fractions]: http://unicodefractions.com
-#[derive(Debug, Clone, Eq, PartialEq)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct VulgarFraction<T> {
     /// The number displayed above the fraction line.
     pub numerator: T,
@@ -46,12 +51,1077 @@ pub struct VulgarFraction<T> {
 
 impl<T> VulgarFraction<T> {
     /// Creates a new fraction from a numerator and denominator.
+    #[must_use]
     pub const fn new(numerator: T, denominator: T) -> Self {
         Self {
             numerator,
             denominator,
         }
     }
+
+    /// Returns a value that formats a borrowed fraction using [`Display`](fmt::Display).
+    ///
+    /// As `VulgarFraction` is `Copy`, the fraction itself can be passed wherever
+    /// a [`Display`](fmt::Display) value is expected. This is a shorthand for when only
+    /// a reference is at hand, e.g. when iterating over a slice of fractions.
+    ///
+    /// ```
+    /// # use fmtastic::VulgarFraction;
+    /// let fractions = [VulgarFraction::new(1, 2), VulgarFraction::new(3, 4)];
+    /// let formatted: Vec<_> = fractions.iter().map(VulgarFraction::display).map(|f| f.to_string()).collect();
+    /// assert_eq!(["½", "¾"], formatted.as_slice());
+    /// ```
+    #[must_use]
+    pub fn display(&self) -> impl fmt::Display + '_
+    where
+        T: Integer,
+    {
+        self
+    }
+
+    /// Sets whether a zero fraction gets a plus sign when formatted with the `+` flag.
+    /// Zero is signed by default. See [`SignZero`].
+    #[must_use]
+    pub fn sign_zero(self, sign_zero: bool) -> SignZero<Self> {
+        SignZero(self, sign_zero)
+    }
+
+    /// Applies a function to both the numerator and the denominator.
+    ///
+    /// ```
+    /// # use fmtastic::VulgarFraction;
+    /// assert_eq!(VulgarFraction::new(2, 8), VulgarFraction::new(1, 4).map_both(|n| n * 2));
+    /// ```
+    #[must_use]
+    pub fn map_both<U>(self, mut f: impl FnMut(T) -> U) -> VulgarFraction<U> {
+        VulgarFraction {
+            numerator: f(self.numerator),
+            denominator: f(self.denominator),
+        }
+    }
+}
+
+impl<T> VulgarFraction<T>
+where
+    T: Integer,
+{
+    /// Creates a new fraction, rejecting a zero denominator
+    /// and normalizing the sign so that the denominator is positive.
+    ///
+    /// A fraction with a negative denominator is normalized by negating both the numerator
+    /// and the denominator, e.g. ¹⁄₋₂ becomes ⁻¹⁄₂. Use [`VulgarFraction::new`]
+    /// to create a fraction from any numerator and denominator as is.
+    ///
+    /// ## Errors
+    /// Returns [`FractionError::ZeroDenominator`] if the denominator is zero and
+    /// [`FractionError::NegationOverflow`] if the denominator is negative and either
+    /// numerator or denominator is the smallest value of `T`, which cannot be negated.
+    ///
+    /// ```
+    /// # use fmtastic::{FractionError, VulgarFraction};
+    /// assert_eq!(Ok(VulgarFraction::new(-1, 2)), VulgarFraction::try_new(1, -2));
+    /// assert_eq!(Err(FractionError::ZeroDenominator), VulgarFraction::try_new(1, 0));
+    /// ```
+    pub fn try_new(numerator: T, denominator: T) -> Result<Self, FractionError> {
+        let (n, d) = (numerator.into_impl(), denominator.into_impl());
+        match d.sign() {
+            _ if d == T::Impl::ZERO => Err(FractionError::ZeroDenominator),
+            Sign::PositiveOrZero => Ok(VulgarFraction::new(numerator, denominator)),
+            Sign::Negative => {
+                let negate = |x: T::Impl| {
+                    let positive = matches!(x.sign(), Sign::PositiveOrZero);
+                    T::Impl::from_magnitude(x.unsigned_abs(), positive)
+                };
+                match (negate(n), negate(d)) {
+                    (Some(n), Some(d)) => Ok(VulgarFraction::new(T::from_impl(n), T::from_impl(d))),
+                    _ => Err(FractionError::NegationOverflow),
+                }
+            }
+        }
+    }
+
+    /// Returns the numerator and denominator divided by their greatest common divisor,
+    /// e.g. `(1, 2)` for ²⁄₄.
+    ///
+    /// The sign is normalized so that the denominator is positive:
+    /// A negative fraction has a negative numerator and a fraction where
+    /// both numerator and denominator are negative becomes positive.
+    /// A zero numerator reduces to `(0, 1)`.
+    /// A zero denominator cannot be reduced, so the fraction is returned as is.
+    ///
+    /// ```
+    /// # use fmtastic::VulgarFraction;
+    /// assert_eq!((1, 2), VulgarFraction::new(2, 4).reduced_parts());
+    /// assert_eq!((1, 2), VulgarFraction::new(-2, -4).reduced_parts());
+    /// assert_eq!((-1, 2), VulgarFraction::new(2, -4).reduced_parts());
+    /// ```
+    ///
+    /// ## Panics
+    /// Panics if the reduced numerator or denominator cannot be represented by `T`,
+    /// which can only happen if one of them is the smallest value of a signed type,
+    /// e.g. for `i8::MIN / -1` as `128` does not fit into an `i8`.
+    #[must_use]
+    pub fn reduced_parts(&self) -> (T, T) {
+        let numerator = self.numerator.into_impl();
+        let denominator = self.denominator.into_impl();
+        if denominator == T::Impl::ZERO {
+            return (self.numerator, self.denominator);
+        }
+        let (n, d) = (numerator.unsigned_abs(), denominator.unsigned_abs());
+        let divisor = gcd(n, d);
+        let negative = numerator != T::Impl::ZERO
+            && matches!(numerator.sign() * denominator.sign(), Sign::Negative);
+        let (Some(n), Some(d)) = (
+            T::Impl::from_magnitude(n / divisor, negative),
+            T::Impl::from_magnitude(d / divisor, false),
+        ) else {
+            panic!("the reduced fraction {numerator}/{denominator} is out of range")
+        };
+        (T::from_impl(n), T::from_impl(d))
+    }
+
+    /// Returns the fraction reduced to lowest terms, e.g. ⁵⁄₂ for ¹⁰⁄₄.
+    ///
+    /// The sign is normalized onto the numerator and a zero numerator reduces to ⁰⁄₁,
+    /// see [`VulgarFraction::reduced_parts`].
+    ///
+    /// ```
+    /// # use fmtastic::VulgarFraction;
+    /// assert_eq!("⁵⁄₂", VulgarFraction::new(10, 4).reduced().to_string());
+    /// assert_eq!(VulgarFraction::new(-5, 2), VulgarFraction::new(10, -4).reduced());
+    /// assert_eq!(VulgarFraction::new(0, 1), VulgarFraction::new(0, 7).reduced());
+    /// ```
+    ///
+    /// ## Panics
+    /// Panics if the reduced numerator or denominator cannot be represented by `T`,
+    /// see [`VulgarFraction::reduced_parts`].
+    #[must_use]
+    pub fn reduced(self) -> Self {
+        let (numerator, denominator) = self.reduced_parts();
+        VulgarFraction::new(numerator, denominator)
+    }
+
+    /// Formats the fraction over three lines using a horizontal
+    /// box-drawing line (`─`, U+2500) as the fraction bar.
+    ///
+    /// The bar spans the width of the wider operand. The narrower operand is centered
+    /// using the same rules as the `^` alignment of the standard library,
+    /// i.e. any odd padding goes to the right. All lines are padded to the same width.
+    /// Numerator and denominator are written using ASCII digits and keep their own sign.
+    ///
+    /// ```
+    /// # use fmtastic::VulgarFraction;
+    /// assert_eq!("12\n──\n3 ", VulgarFraction::new(12, 3).boxed().to_string());
+    /// assert_eq!(" 1 \n───\n-10", VulgarFraction::new(1, -10).boxed().to_string());
+    /// ```
+    #[must_use]
+    pub fn boxed(self) -> BoxedFraction<T> {
+        BoxedFraction(self)
+    }
+
+    /// Formats an improper fraction as a mixed number, i.e. a whole number followed
+    /// by the remaining proper fraction, e.g. `3⅓` for ¹⁰⁄₃.
+    ///
+    /// The remaining fraction is formatted like the [`VulgarFraction`] itself,
+    /// i.e. as a single character where possible unless the alternate flag `#` is used.
+    /// It keeps the denominator and is not reduced, use [`VulgarFraction::reduced`] for that.
+    /// A negative fraction puts the sign before the whole number, e.g. `-3⅓` for ⁻¹⁰⁄₃.
+    /// Fractions that divide exactly are written as a whole number, e.g. `2` for ⁶⁄₃.
+    /// Proper fractions and fractions with a zero denominator are formatted unchanged.
+    ///
+    /// ```
+    /// # use fmtastic::VulgarFraction;
+    /// assert_eq!("3⅓", VulgarFraction::new(10, 3).mixed().to_string());
+    /// assert_eq!("3¹⁄₃", format!("{:#}", VulgarFraction::new(10, 3).mixed()));
+    /// assert_eq!("-2⁵⁄₉", VulgarFraction::new(23, -9).mixed().to_string());
+    /// assert_eq!("2", VulgarFraction::new(6, 3).mixed().to_string());
+    /// assert_eq!("¾", VulgarFraction::new(3, 4).mixed().to_string());
+    /// ```
+    #[must_use]
+    pub fn mixed(self) -> MixedFraction<T> {
+        MixedFraction(self)
+    }
+
+    /// Formats the fraction as plain text using ASCII digits,
+    /// e.g. `10 over 3`, as a fallback where fraction glyphs are not available.
+    ///
+    /// By default the word `over` is used, see [`SpelledFraction::separator`]
+    /// for writing a slash (`10/3`) instead.
+    /// Numerator and denominator keep their own sign.
+    /// The `+` flag moves the sign to the outside of the fraction
+    /// and always shows it, as for [`VulgarFraction`].
+    ///
+    /// ```
+    /// # use fmtastic::{FractionSeparator, VulgarFraction};
+    /// assert_eq!("10 over 3", VulgarFraction::new(10, 3).spelled().to_string());
+    /// assert_eq!("-1/4", VulgarFraction::new(-1, 4).spelled().separator(FractionSeparator::Slash).to_string());
+    /// assert_eq!("-1 over 4", format!("{:+}", VulgarFraction::new(1, -4).spelled()));
+    /// ```
+    #[must_use]
+    pub fn spelled(self) -> SpelledFraction<T> {
+        SpelledFraction(self, FractionSeparator::Over)
+    }
+
+    /// Formats the fraction as a decimal with the repeating digits marked, e.g. `0.3̅` for ¹⁄₃.
+    /// See [`Recurring`] for the marking conventions.
+    ///
+    /// ## Errors
+    /// Returns [`FractionError::ZeroDenominator`] if the denominator is zero.
+    ///
+    /// ```
+    /// # use fmtastic::VulgarFraction;
+    /// assert_eq!("0.8\u{305}3\u{305}", VulgarFraction::new(83, 99).recurring().unwrap().to_string());
+    /// assert!(VulgarFraction::new(1, 0).recurring().is_err());
+    /// ```
+    pub fn recurring(self) -> Result<Recurring<T>, FractionError> {
+        if self.denominator.into_impl() == T::Impl::ZERO {
+            Err(FractionError::ZeroDenominator)
+        } else {
+            Ok(Recurring(self, RecurringMark::default()))
+        }
+    }
+}
+
+/// A fraction formatted as plain text, created by [`VulgarFraction::spelled`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct SpelledFraction<T>(VulgarFraction<T>, FractionSeparator);
+
+/// The separator written between numerator and denominator of a [`SpelledFraction`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum FractionSeparator {
+    /// The word `over` surrounded by spaces, e.g. `10 over 3`.
+    Over,
+    /// A slash (`/`) without spaces, e.g. `10/3`.
+    Slash,
+}
+
+impl<T> SpelledFraction<T> {
+    /// Sets the separator written between numerator and denominator.
+    #[must_use]
+    pub fn separator(mut self, separator: FractionSeparator) -> Self {
+        self.1 = separator;
+        self
+    }
+}
+
+impl<T> fmt::Display for SpelledFraction<T>
+where
+    T: Integer,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let numerator = self.0.numerator.into_impl();
+        let denominator = self.0.denominator.into_impl();
+        let separator = match self.1 {
+            FractionSeparator::Over => " over ",
+            FractionSeparator::Slash => "/",
+        };
+        if f.sign_plus() {
+            match numerator.sign() * denominator.sign() {
+                Sign::PositiveOrZero => f.write_char('+')?,
+                Sign::Negative => f.write_char('-')?,
+            }
+            let (numerator, denominator) = (numerator.unsigned_abs(), denominator.unsigned_abs());
+            write!(f, "{numerator}{separator}{denominator}")
+        } else {
+            write!(f, "{numerator}{separator}{denominator}")
+        }
+    }
+}
+
+/// A fraction laid out over three lines, created by [`VulgarFraction::boxed`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct BoxedFraction<T>(VulgarFraction<T>);
+
+impl<T> fmt::Display for BoxedFraction<T>
+where
+    T: Integer,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        const BOX_DRAWINGS_LIGHT_HORIZONTAL: char = '\u{2500}';
+        let numerator = self.0.numerator.into_impl();
+        let denominator = self.0.denominator.into_impl();
+        let width = ascii_width(numerator).max(ascii_width(denominator));
+        write_centered(f, numerator, width)?;
+        f.write_char('\n')?;
+        (0..width).try_for_each(|_| f.write_char(BOX_DRAWINGS_LIGHT_HORIZONTAL))?;
+        f.write_char('\n')?;
+        write_centered(f, denominator, width)
+    }
+}
+
+/// A fraction formatted as a mixed number, created by [`VulgarFraction::mixed`].
+///
+/// ## Formatting Flags
+/// ### Alternate `#`
+/// The alternate flag `#` disables single character fractions for the fractional part.
+///
+/// ### Sign: `+`
+/// Use the `+` flag to always show the sign, even for positive numbers.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct MixedFraction<T>(VulgarFraction<T>);
+
+impl<T> MixedFraction<T> {
+    /// Returns a reference to the wrapped fraction.
+    #[must_use]
+    pub fn as_inner(&self) -> &VulgarFraction<T> {
+        &self.0
+    }
+}
+
+impl<T> fmt::Display for MixedFraction<T>
+where
+    T: Integer,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let style = if f.alternate() {
+            FractionStyle::Slashed
+        } else {
+            FractionStyle::SingleChar
+        };
+        let numerator = self.0.numerator.into_impl();
+        let denominator = self.0.denominator.into_impl();
+        let (n, d) = (numerator.unsigned_abs(), denominator.unsigned_abs());
+        if d == IntegerImpl::ZERO || n < d {
+            return fmt_signed_fraction(&self.0, style, true, f);
+        }
+        let negative = matches!(numerator.sign() * denominator.sign(), Sign::Negative);
+        let magnitude = MixedMagnitude {
+            whole: n / d,
+            remainder: n % d,
+            denominator: d,
+            style,
+        };
+        fmt::Display::fmt(&WithSign::from_parts(negative, false, magnitude), f)
+    }
+}
+
+/// The magnitude of a mixed number with a non-zero whole part, without a sign.
+struct MixedMagnitude<T> {
+    whole: T,
+    remainder: T,
+    denominator: T,
+    style: FractionStyle,
+}
+
+impl<T: IntegerImpl> fmt::Display for MixedMagnitude<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.whole)?;
+        if self.remainder == T::ZERO {
+            return Ok(());
+        }
+        fmt_fraction(self.remainder, self.denominator, self.style, f)
+    }
+}
+
+fn ascii_width<T: IntegerImpl>(n: T) -> usize {
+    let sign = matches!(n.sign(), Sign::Negative) as usize;
+    sign + iter_digits::<_, T::BaseTen>(n).count()
+}
+
+fn write_centered<T: IntegerImpl>(f: &mut fmt::Formatter<'_>, n: T, width: usize) -> fmt::Result {
+    let padding = width - ascii_width(n);
+    (0..padding / 2).try_for_each(|_| f.write_char(' '))?;
+    if let Sign::Negative = n.sign() {
+        f.write_char('-')?;
+    }
+    iter_digits::<_, T::BaseTen>(n).try_for_each(|digit| write!(f, "{digit}"))?;
+    (0..padding - padding / 2).try_for_each(|_| f.write_char(' '))
+}
+
+impl<T> VulgarFraction<T>
+where
+    T: Integer,
+{
+    /// Returns the value of the fraction in per mille (‰), rounded to the nearest integer.
+    /// Halfway cases are rounded away from zero.
+    ///
+    /// Returns `None` if the denominator is zero or if the computation overflows.
+    ///
+    /// ```
+    /// # use fmtastic::VulgarFraction;
+    /// assert_eq!(Some(125), VulgarFraction::new(1, 8).as_permille());
+    /// assert_eq!(Some(-333), VulgarFraction::new(-1, 3).as_permille());
+    /// assert_eq!(None, VulgarFraction::new(1_u8, 8).as_permille()); // 1000 does not fit into an u8
+    /// ```
+    #[must_use]
+    pub fn as_permille(&self) -> Option<T> {
+        self.scaled(1000)
+    }
+
+    /// Returns the value of the fraction in basis points (‱), rounded to the nearest integer.
+    /// Halfway cases are rounded away from zero.
+    ///
+    /// Returns `None` if the denominator is zero or if the computation overflows.
+    ///
+    /// ```
+    /// # use fmtastic::VulgarFraction;
+    /// assert_eq!(Some(1), VulgarFraction::new(1, 10_000).as_basis_points());
+    /// assert_eq!(Some(2500), VulgarFraction::new(1, 4).as_basis_points());
+    /// ```
+    #[must_use]
+    pub fn as_basis_points(&self) -> Option<T> {
+        self.scaled(10_000)
+    }
+
+    fn scaled(&self, scale: u16) -> Option<T> {
+        let numerator = self.numerator.into_impl();
+        let denominator = self.denominator.into_impl();
+        if denominator == T::Impl::ZERO {
+            return None;
+        }
+        let scaled = numerator.checked_mul(T::Impl::try_from(scale).ok()?)?;
+        let quotient = scaled / denominator;
+        let remainder = (scaled % denominator).checked_abs()?;
+        let divisor = denominator.checked_abs()?;
+        if remainder < divisor - remainder {
+            Some(T::from_impl(quotient))
+        } else if let Sign::Negative = numerator.sign() * denominator.sign() {
+            Some(T::from_impl(quotient - T::Impl::ONE))
+        } else {
+            Some(T::from_impl(quotient + T::Impl::ONE))
+        }
+    }
+}
+
+/// Formats a value in per mille followed by the per mille sign (`‰`).
+/// Use [`VulgarFraction::as_permille`] to convert a fraction to per mille.
+///
+/// The value is formatted like any other integer,
+/// so all formatting flags of integers are supported.
+///
+/// ```
+/// # use fmtastic::{Permille, VulgarFraction};
+/// assert_eq!("125‰", Permille(125).to_string());
+/// assert_eq!("+125‰", format!("{:+}", Permille(VulgarFraction::new(1, 8).as_permille().unwrap())));
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct Permille<T>(pub T);
+
+impl<T> fmt::Display for Permille<T>
+where
+    T: Integer + fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)?;
+        f.write_char('‰')
+    }
+}
+
+/// Formats a value in basis points followed by `bps`.
+/// Use [`VulgarFraction::as_basis_points`] to convert a fraction to basis points.
+///
+/// The value is formatted like any other integer,
+/// so all formatting flags of integers are supported.
+///
+/// ```
+/// # use fmtastic::{BasisPoints, VulgarFraction};
+/// assert_eq!("1 bps", BasisPoints(VulgarFraction::new(1, 10_000).as_basis_points().unwrap()).to_string());
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct BasisPoints<T>(pub T);
+
+impl<T> fmt::Display for BasisPoints<T>
+where
+    T: Integer + fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)?;
+        f.write_str(" bps")
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> VulgarFraction<T>
+where
+    T: Integer,
+{
+    /// Formats the fraction as LaTeX using `\frac`, e.g. `\frac{10}{3}`.
+    ///
+    /// The sign is moved in front of the fraction:
+    /// a negative fraction is formatted as `-\frac{10}{3}`,
+    /// a fraction where both numerator and denominator are negative as `\frac{10}{3}`.
+    ///
+    /// ```
+    /// # use fmtastic::VulgarFraction;
+    /// assert_eq!("\\frac{10}{3}", VulgarFraction::new(10, 3).to_latex());
+    /// assert_eq!("-\\frac{10}{3}", VulgarFraction::new(10, -3).to_latex());
+    /// assert_eq!("\\frac{10}{3}", VulgarFraction::new(-10, -3).to_latex());
+    /// ```
+    #[must_use]
+    pub fn to_latex(&self) -> alloc::string::String {
+        let numerator = self.numerator.into_impl();
+        let denominator = self.denominator.into_impl();
+        let sign = match numerator.sign() * denominator.sign() {
+            Sign::Negative => "-",
+            Sign::PositiveOrZero => "",
+        };
+        alloc::format!(
+            "{sign}\\frac{{{}}}{{{}}}",
+            numerator.unsigned_abs(),
+            denominator.unsigned_abs()
+        )
+    }
+}
+
+impl<T> VulgarFraction<T>
+where
+    T: Integer,
+{
+    /// Draws a horizontal bar, `width` characters wide, proportional to the value of the fraction,
+    /// followed by the value as percentage.
+    ///
+    /// The bar uses the block elements `█`, `▉`, `▊`, `▋`, `▌`, `▍`, `▎` and `▏`,
+    /// so each character represents eighths of the cell.
+    /// The length of the bar is rounded to the nearest eighth and the percentage
+    /// to the nearest integer (halfway cases are rounded up).
+    /// The bar is padded with spaces to always be `width` characters wide.
+    ///
+    /// The value is clamped to the range from zero to one: Negative values draw an empty bar,
+    /// values larger than one draw a full bar. A zero denominator draws an empty bar.
+    ///
+    /// ```
+    /// # use fmtastic::VulgarFraction;
+    /// assert_eq!("█████▏    56%", VulgarFraction::new(9, 16).bar(9).to_string());
+    /// assert_eq!("██████   ¾", VulgarFraction::new(3, 4).bar(8).fraction_label().to_string());
+    /// ```
+    #[must_use]
+    pub fn bar(self, width: usize) -> FractionBar<T> {
+        FractionBar {
+            fraction: self,
+            width,
+            fraction_label: false,
+        }
+    }
+}
+
+/// A horizontal bar proportional to a fraction, created by [`VulgarFraction::bar`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct FractionBar<T> {
+    fraction: VulgarFraction<T>,
+    width: usize,
+    fraction_label: bool,
+}
+
+impl<T> FractionBar<T> {
+    /// Labels the bar with the fraction instead of the percentage.
+    #[must_use]
+    pub fn fraction_label(mut self) -> Self {
+        self.fraction_label = true;
+        self
+    }
+}
+
+impl<T> fmt::Display for FractionBar<T>
+where
+    T: Integer,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let numerator = self.fraction.numerator.into_impl();
+        let denominator = self.fraction.denominator.into_impl();
+        let (eighths, percentage) = clamped_ratio(numerator, denominator)
+            .map(|(n, d)| {
+                let eighths = scale_ratio(n, d, self.width.saturating_mul(8));
+                (eighths, scale_ratio(n, d, 100))
+            })
+            .unwrap_or((0, 0));
+        bar_cells(eighths, self.width).try_for_each(|c| f.write_char(c))?;
+
+        if self.fraction_label {
+            write!(f, " {}", self.fraction)
+        } else {
+            write!(f, " {percentage}%")
+        }
+    }
+}
+
+impl<T> VulgarFraction<T>
+where
+    T: Integer,
+{
+    /// Adds two fractions, returning `None` if either denominator is zero
+    /// or if the result cannot be represented by `T`.
+    ///
+    /// The result is reduced (see [`VulgarFraction::reduced_parts`]) and its denominator is positive.
+    /// The operands are reduced before adding them, so that intermediate values stay small.
+    ///
+    /// ```
+    /// # use fmtastic::VulgarFraction;
+    /// assert_eq!(Some(VulgarFraction::new(5, 6)), VulgarFraction::new(1, 2).checked_add(&VulgarFraction::new(1, 3)));
+    /// assert_eq!(None, VulgarFraction::new(1, 0).checked_add(&VulgarFraction::new(1, 3)));
+    /// assert_eq!(None, VulgarFraction::new(i8::MAX, 1).checked_add(&VulgarFraction::new(1, 1)));
+    /// ```
+    #[must_use]
+    pub fn checked_add(&self, rhs: &Self) -> Option<Self> {
+        let (lhs_negative, lhs_n, lhs_d) = signed_parts(self)?;
+        let (rhs_negative, rhs_n, rhs_d) = signed_parts(rhs)?;
+        let divisor = gcd(lhs_d, rhs_d);
+        let denominator = lhs_d.checked_mul(rhs_d / divisor)?;
+        let lhs_n = lhs_n.checked_mul(rhs_d / divisor)?;
+        let rhs_n = rhs_n.checked_mul(lhs_d / divisor)?;
+        let (negative, numerator) = if lhs_negative == rhs_negative {
+            (lhs_negative, lhs_n.checked_add(rhs_n)?)
+        } else if lhs_n >= rhs_n {
+            (lhs_negative, lhs_n - rhs_n)
+        } else {
+            (rhs_negative, rhs_n - lhs_n)
+        };
+        from_signed_parts(negative, numerator, denominator)
+    }
+
+    /// Multiplies two fractions, returning `None` if either denominator is zero
+    /// or if the result cannot be represented by `T`.
+    ///
+    /// The result is reduced (see [`VulgarFraction::reduced_parts`]) and its denominator is positive.
+    /// Common factors are cancelled before multiplying, so that intermediate values stay small.
+    ///
+    /// ```
+    /// # use fmtastic::VulgarFraction;
+    /// assert_eq!(Some(VulgarFraction::new(-1, 3)), VulgarFraction::new(2, -3).checked_mul(&VulgarFraction::new(3, 6)));
+    /// assert_eq!(None, VulgarFraction::new(1, 0).checked_mul(&VulgarFraction::new(1, 3)));
+    /// ```
+    #[must_use]
+    pub fn checked_mul(&self, rhs: &Self) -> Option<Self> {
+        let (lhs_negative, lhs_n, lhs_d) = signed_parts(self)?;
+        let (rhs_negative, rhs_n, rhs_d) = signed_parts(rhs)?;
+        let (lhs_divisor, rhs_divisor) = (gcd(lhs_n, rhs_d), gcd(rhs_n, lhs_d));
+        let numerator = (lhs_n / lhs_divisor).checked_mul(rhs_n / rhs_divisor)?;
+        let denominator = (lhs_d / rhs_divisor).checked_mul(rhs_d / lhs_divisor)?;
+        from_signed_parts(lhs_negative != rhs_negative, numerator, denominator)
+    }
+
+    /// Rewrites two fractions over their least common denominator, e.g. ¹⁄₂ and ¹⁄₃ as ³⁄₆ and ²⁄₆,
+    /// as done before adding them by hand.
+    ///
+    /// The fractions are not reduced, so that they stay recognizable, e.g. ²⁄₄ and ³⁄₆
+    /// become ⁶⁄₁₂ and ⁶⁄₁₂. The least common denominator is the least common multiple
+    /// of the denominators, computed as `a / gcd(a, b) * b`, and each numerator
+    /// is multiplied by the factor by which its denominator grows.
+    /// The signs are moved to the numerators, so that both denominators are positive.
+    /// Reduce the fractions beforehand using [`VulgarFraction::reduced`] to get the smallest denominator.
+    ///
+    /// Returns `None` if either denominator is zero or if the common denominator
+    /// or a numerator cannot be represented by `T`.
+    ///
+    /// ```
+    /// # use fmtastic::VulgarFraction;
+    /// let (a, b) = VulgarFraction::common_denominator(VulgarFraction::new(1, 2), VulgarFraction::new(1, 3)).unwrap();
+    /// assert_eq!("³⁄₆ + ²⁄₆", format!("{a:#} + {b:#}"));
+    /// assert_eq!(None, VulgarFraction::common_denominator(VulgarFraction::new(1, 0), VulgarFraction::new(1, 3)));
+    /// assert_eq!(None, VulgarFraction::common_denominator(VulgarFraction::new(1, 16_u8), VulgarFraction::new(1, 17)));
+    /// ```
+    #[must_use]
+    pub fn common_denominator(a: Self, b: Self) -> Option<(Self, Self)> {
+        let (a_negative, a_n, a_d) = unreduced_signed_parts(&a)?;
+        let (b_negative, b_n, b_d) = unreduced_signed_parts(&b)?;
+        let divisor = gcd(a_d, b_d);
+        let denominator = (a_d / divisor).checked_mul(b_d)?;
+        let rescale = |negative: bool, numerator: <T::Impl as IntegerImpl>::Magnitude, factor| {
+            let numerator = T::Impl::from_magnitude(numerator.checked_mul(factor)?, negative)?;
+            let denominator = T::Impl::from_magnitude(denominator, false)?;
+            Some(VulgarFraction::new(
+                T::from_impl(numerator),
+                T::from_impl(denominator),
+            ))
+        };
+        Some((
+            rescale(a_negative, a_n, b_d / divisor)?,
+            rescale(b_negative, b_n, a_d / divisor)?,
+        ))
+    }
+}
+
+/// Returns the sign and the reduced magnitudes of numerator and denominator.
+/// Returns `None` if the denominator is zero.
+#[allow(clippy::type_complexity)]
+fn signed_parts<T: Integer>(
+    fraction: &VulgarFraction<T>,
+) -> Option<(
+    bool,
+    <T::Impl as IntegerImpl>::Magnitude,
+    <T::Impl as IntegerImpl>::Magnitude,
+)> {
+    let (negative, n, d) = unreduced_signed_parts(fraction)?;
+    let divisor = gcd(n, d);
+    Some((negative, n / divisor, d / divisor))
+}
+
+/// Returns the sign and the magnitudes of numerator and denominator, without reducing them.
+/// Returns `None` if the denominator is zero.
+#[allow(clippy::type_complexity)]
+fn unreduced_signed_parts<T: Integer>(
+    fraction: &VulgarFraction<T>,
+) -> Option<(
+    bool,
+    <T::Impl as IntegerImpl>::Magnitude,
+    <T::Impl as IntegerImpl>::Magnitude,
+)> {
+    let numerator = fraction.numerator.into_impl();
+    let denominator = fraction.denominator.into_impl();
+    if denominator == T::Impl::ZERO {
+        return None;
+    }
+    let negative = matches!(numerator.sign() * denominator.sign(), Sign::Negative);
+    Some((
+        negative,
+        numerator.unsigned_abs(),
+        denominator.unsigned_abs(),
+    ))
+}
+
+/// Creates a reduced fraction with a positive denominator from its sign and magnitudes.
+fn from_signed_parts<T: Integer>(
+    negative: bool,
+    numerator: <T::Impl as IntegerImpl>::Magnitude,
+    denominator: <T::Impl as IntegerImpl>::Magnitude,
+) -> Option<VulgarFraction<T>> {
+    let divisor = gcd(numerator, denominator);
+    let negative = negative && numerator != IntegerImpl::ZERO;
+    let numerator = T::Impl::from_magnitude(numerator / divisor, negative)?;
+    let denominator = T::Impl::from_magnitude(denominator / divisor, false)?;
+    Some(VulgarFraction::new(
+        T::from_impl(numerator),
+        T::from_impl(denominator),
+    ))
+}
+
+/// Sums fractions using [`VulgarFraction::checked_add`], starting from ⁰⁄₁.
+///
+/// ## Panics
+/// Panics if any denominator is zero or if the sum cannot be represented by `T`.
+/// Use [`VulgarFraction::checked_add`] to handle these cases.
+///
+/// ```
+/// # use fmtastic::VulgarFraction;
+/// let fractions = [VulgarFraction::new(1, 2), VulgarFraction::new(1, 3), VulgarFraction::new(1, 6)];
+/// assert_eq!(VulgarFraction::new(1, 1), fractions.iter().copied().sum());
+/// ```
+impl<T> Sum for VulgarFraction<T>
+where
+    T: Integer,
+{
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(fraction_from::<T>(T::Impl::ZERO), |sum, fraction| {
+            sum.checked_add(&fraction)
+                .expect("the sum of the fractions is undefined or out of range")
+        })
+    }
+}
+
+impl<'a, T> Sum<&'a VulgarFraction<T>> for VulgarFraction<T>
+where
+    T: Integer + 'a,
+{
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
+/// Multiplies fractions using [`VulgarFraction::checked_mul`], starting from ¹⁄₁.
+///
+/// ## Panics
+/// Panics if any denominator is zero or if the product cannot be represented by `T`.
+/// Use [`VulgarFraction::checked_mul`] to handle these cases.
+///
+/// ```
+/// # use fmtastic::VulgarFraction;
+/// let fractions = [VulgarFraction::new(2, 3), VulgarFraction::new(3, 4)];
+/// assert_eq!(VulgarFraction::new(1, 2), fractions.iter().product());
+/// ```
+impl<T> Product for VulgarFraction<T>
+where
+    T: Integer,
+{
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(fraction_from::<T>(T::Impl::ONE), |product, fraction| {
+            product
+                .checked_mul(&fraction)
+                .expect("the product of the fractions is undefined or out of range")
+        })
+    }
+}
+
+impl<'a, T> Product<&'a VulgarFraction<T>> for VulgarFraction<T>
+where
+    T: Integer + 'a,
+{
+    fn product<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.copied().product()
+    }
+}
+
+fn fraction_from<T: Integer>(n: T::Impl) -> VulgarFraction<T> {
+    VulgarFraction::new(T::from_impl(n), T::from_impl(T::Impl::ONE))
+}
+
+/// An error which can be returned by [`VulgarFraction::try_new`], [`VulgarFraction::recurring`]
+/// and [`VulgarFraction::from_f64`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum FractionError {
+    /// The denominator is zero.
+    ZeroDenominator,
+    /// The denominator is negative and the numerator or denominator cannot be negated
+    /// to make it positive, e.g. `i8::MIN`.
+    NegationOverflow,
+    /// The value to convert to a fraction is NaN or infinite.
+    NotFinite,
+    /// The value to convert to a fraction is too large for the numerator.
+    OutOfRange,
+}
+
+impl fmt::Display for FractionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let message = match self {
+            FractionError::ZeroDenominator => "the denominator of a fraction cannot be zero",
+            FractionError::NegationOverflow => {
+                "cannot make the denominator positive without overflowing"
+            }
+            FractionError::NotFinite => "cannot convert NaN or infinity to a fraction",
+            FractionError::OutOfRange => "the value is out of range for a fraction",
+        };
+        f.write_str(message)
+    }
+}
+
+/// Returns the magnitudes of numerator and denominator with the
+/// value clamped to the range from zero to one.
+/// Returns `None` if the value is zero, negative or undefined.
+fn clamped_ratio<T: IntegerImpl>(
+    numerator: T,
+    denominator: T,
+) -> Option<(T::Magnitude, T::Magnitude)> {
+    if numerator == T::ZERO || denominator == T::ZERO {
+        return None;
+    }
+    match numerator.sign() * denominator.sign() {
+        Sign::Negative => None,
+        Sign::PositiveOrZero => {
+            let (n, d) = (numerator.unsigned_abs(), denominator.unsigned_abs());
+            Some(if n > d { (d, d) } else { (n, d) })
+        }
+    }
+}
+
+impl<T> VulgarFraction<T> {
+    /// Sets the preferred style of the fraction, independent of the format string.
+    ///
+    /// With [`FractionStyle::SingleChar`] a [single character fraction] is used when
+    /// one exists, falling back to the slashed form otherwise.
+    /// With [`FractionStyle::Slashed`] the slashed form is always used.
+    /// With [`FractionStyle::FontBuilt`] the fallback leaves it to the font
+    /// to build up the fraction from regular digits.
+    ///
+    /// The preference takes precedence over the alternate flag (`#`), which is ignored.
+    /// The `+` flag is supported the same way as by [`VulgarFraction`].
+    ///
+    /// ```
+    /// # use fmtastic::{FractionStyle, VulgarFraction};
+    /// assert_eq!("¹⁄₂", VulgarFraction::new(1, 2).prefer(FractionStyle::Slashed).to_string());
+    /// assert_eq!("½", format!("{:#}", VulgarFraction::new(1, 2).prefer(FractionStyle::SingleChar)));
+    /// assert_eq!("7⁄16", VulgarFraction::new(7, 16).prefer(FractionStyle::FontBuilt).to_string());
+    /// ```
+    ///
+    /// [single character fraction]: http://unicodefractions.com
+    #[must_use]
+    pub fn prefer(self, style: FractionStyle) -> PreferredFraction<T> {
+        PreferredFraction(self, style)
+    }
+}
+
+/// The style in which a [`VulgarFraction`] is formatted, see [`VulgarFraction::prefer`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum FractionStyle {
+    /// Prefers a single character fraction such as `½`,
+    /// falling back to the slashed form if none exists.
+    SingleChar,
+    /// Always uses superscript and subscript digits separated by a fraction slash (U+2044), e.g. `¹⁄₂`.
+    Slashed,
+    /// Prefers a single character fraction such as `½`, falling back to the
+    /// regular digits `0`–`9` separated by a fraction slash (U+2044), e.g. `7⁄16`.
+    ///
+    /// Unicode has no small digits for fractions apart from the superscript and subscript digits,
+    /// whose sizes and baselines often don't match in fonts.
+    /// Instead, the fraction slash asks the font to build up the fraction from the surrounding digits.
+    /// Fonts that support the OpenType `frac` feature render them with their own
+    /// small numerator and denominator digits, which usually look better than `⁷⁄₁₆`.
+    /// Other fonts show the regular digits with a slash.
+    FontBuilt,
+}
+
+/// A fraction with a preferred style, created by [`VulgarFraction::prefer`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct PreferredFraction<T>(VulgarFraction<T>, FractionStyle);
+
+impl VulgarFraction<i64> {
+    /// Compares the values of two fractions, e.g. ⅓ < ½ and ½ = ²⁄₄.
+    ///
+    /// The fractions are compared by cross-multiplying numerators and denominators
+    /// using 128-bit arithmetic, which cannot overflow for any pair of `i64` fractions.
+    /// Negative denominators are supported.
+    /// Returns `None` if either denominator is zero, as the value is undefined.
+    ///
+    /// This method is only available for `i64` and smaller integers converted to `i64`,
+    /// as there is no wider type to compare `i128` fractions with.
+    ///
+    /// ```
+    /// # use fmtastic::VulgarFraction;
+    /// # use core::cmp::Ordering;
+    /// assert_eq!(Some(Ordering::Less), VulgarFraction::new(1, 3).cmp_value(&VulgarFraction::new(1, 2)));
+    /// assert_eq!(Some(Ordering::Equal), VulgarFraction::new(1, 2).cmp_value(&VulgarFraction::new(-2, -4)));
+    /// assert_eq!(None, VulgarFraction::new(1, 0).cmp_value(&VulgarFraction::new(1, 2)));
+    /// ```
+    #[must_use]
+    pub fn cmp_value(&self, other: &Self) -> Option<Ordering> {
+        if self.denominator == 0 || other.denominator == 0 {
+            return None;
+        }
+        let lhs = i128::from(self.numerator) * i128::from(other.denominator);
+        let rhs = i128::from(other.numerator) * i128::from(self.denominator);
+        let ordering = lhs.cmp(&rhs);
+        if (self.denominator < 0) != (other.denominator < 0) {
+            Some(ordering.reverse())
+        } else {
+            Some(ordering)
+        }
+    }
+
+    /// Approximates a floating point number by the closest fraction
+    /// whose denominator is at most `max_denominator`, e.g. ⅓ for `0.333`.
+    ///
+    /// The approximation is found using the continued fraction expansion of `value`:
+    /// The result is the last convergent or semiconvergent whose denominator fits,
+    /// which is the best rational approximation with a bounded denominator.
+    /// The result is reduced, has a positive denominator and carries the sign in the numerator.
+    /// Whole numbers have the denominator one, e.g. ²⁄₁ for `2.0`.
+    ///
+    /// ## Errors
+    /// Returns [`FractionError::NotFinite`] if `value` is NaN or infinite,
+    /// [`FractionError::OutOfRange`] if the numerator cannot be represented by an `i64`,
+    /// and [`FractionError::ZeroDenominator`] if `max_denominator` is zero.
+    ///
+    /// ```
+    /// # use fmtastic::{FractionError, VulgarFraction};
+    /// assert_eq!(Ok(VulgarFraction::new(1, 2)), VulgarFraction::from_f64(0.5, 100));
+    /// assert_eq!(Ok(VulgarFraction::new(-1, 3)), VulgarFraction::from_f64(-1.0 / 3.0, 100));
+    /// assert_eq!(Ok(VulgarFraction::new(355, 113)), VulgarFraction::from_f64(core::f64::consts::PI, 1000));
+    /// assert_eq!(Err(FractionError::NotFinite), VulgarFraction::from_f64(f64::NAN, 100));
+    /// ```
+    pub fn from_f64(value: f64, max_denominator: u32) -> Result<Self, FractionError> {
+        if !value.is_finite() {
+            return Err(FractionError::NotFinite);
+        }
+        if max_denominator == 0 {
+            return Err(FractionError::ZeroDenominator);
+        }
+        let magnitude = if value < 0.0 { -value } else { value };
+        if magnitude >= i64::MAX as f64 {
+            return Err(FractionError::OutOfRange);
+        }
+        let max_denominator = u128::from(max_denominator);
+        // The previous two convergents h₀/k₀ and h₁/k₁, starting with 0/1 and 1/0.
+        let (mut h0, mut k0, mut h1, mut k1) = (0_u128, 1_u128, 1_u128, 0_u128);
+        let mut x = magnitude;
+        loop {
+            // The saturating cast is the floor of any value that is not out of range anyway.
+            let a = x as u128;
+            let next = a
+                .checked_mul(h1)
+                .and_then(|h| h.checked_add(h0))
+                .zip(a.checked_mul(k1).and_then(|k| k.checked_add(k0)));
+            match next {
+                Some((h2, k2)) if k2 <= max_denominator => {
+                    (h0, k0, h1, k1) = (h1, k1, h2, k2);
+                }
+                _ => {
+                    // The denominator of the first convergent is one, so k₁ is not zero here.
+                    let t = (max_denominator - k0) / k1;
+                    let (h, k) = (t * h1 + h0, t * k1 + k0);
+                    let error = |h: u128, k: u128| {
+                        let difference = h as f64 / k as f64 - magnitude;
+                        if difference < 0.0 {
+                            -difference
+                        } else {
+                            difference
+                        }
+                    };
+                    if t > 0 && error(h, k) < error(h1, k1) {
+                        (h1, k1) = (h, k);
+                    }
+                    break;
+                }
+            }
+            let fraction = x - a as f64;
+            if fraction == 0.0 {
+                break;
+            }
+            x = 1.0 / fraction;
+        }
+        let numerator = i64::try_from(h1).map_err(|_| FractionError::OutOfRange)?;
+        let denominator = i64::try_from(k1).map_err(|_| FractionError::OutOfRange)?;
+        let numerator = if value < 0.0 { -numerator } else { numerator };
+        Ok(VulgarFraction::new(numerator, denominator))
+    }
+}
+
+/// Rational approximations of common mathematical constants.
+///
+/// ```
+/// # use fmtastic::VulgarFraction;
+/// assert_eq!("²²⁄₇", VulgarFraction::approx_pi().to_string());
+/// assert_eq!("³⁵⁵⁄₁₁₃", VulgarFraction::approx_pi_precise().to_string());
+/// ```
+impl VulgarFraction<i64> {
+    /// Approximates π as 22/7, with an absolute error of about 1.3 × 10⁻³.
+    #[must_use]
+    pub const fn approx_pi() -> Self {
+        Self::new(22, 7)
+    }
+
+    /// Approximates π as 355/113, with an absolute error of about 2.7 × 10⁻⁷.
+    #[must_use]
+    pub const fn approx_pi_precise() -> Self {
+        Self::new(355, 113)
+    }
+
+    /// Approximates Euler's number *e* as 19/7, with an absolute error of about 4.0 × 10⁻³.
+    #[must_use]
+    pub const fn approx_e() -> Self {
+        Self::new(19, 7)
+    }
+
+    /// Approximates Euler's number *e* as 2721/1001, with an absolute error of about 1.1 × 10⁻⁷.
+    #[must_use]
+    pub const fn approx_e_precise() -> Self {
+        Self::new(2721, 1001)
+    }
+
+    /// Approximates the square root of two (√2) as 7/5, with an absolute error of about 1.4 × 10⁻².
+    #[must_use]
+    pub const fn approx_sqrt_2() -> Self {
+        Self::new(7, 5)
+    }
+
+    /// Approximates the square root of two (√2) as 1393/985, with an absolute error of about 3.6 × 10⁻⁷.
+    #[must_use]
+    pub const fn approx_sqrt_2_precise() -> Self {
+        Self::new(1393, 985)
+    }
+
+    /// Approximates the golden ratio φ as 8/5, with an absolute error of about 1.8 × 10⁻².
+    #[must_use]
+    pub const fn approx_golden_ratio() -> Self {
+        Self::new(8, 5)
+    }
+
+    /// Approximates the golden ratio φ as 1597/987, with an absolute error of about 4.6 × 10⁻⁷.
+    #[must_use]
+    pub const fn approx_golden_ratio_precise() -> Self {
+        Self::new(1597, 987)
+    }
 }
 
 impl<T> From<(T, T)> for VulgarFraction<T> {
@@ -68,7 +1138,7 @@ where
     T: Integer,
 {
     fn from(value: T) -> Self {
-        VulgarFraction::new(value, <T::Impl as IntegerImpl>::ONE.into_public())
+        VulgarFraction::new(value, T::from_impl(<T::Impl as IntegerImpl>::ONE))
     }
 }
 
@@ -77,35 +1147,99 @@ where
     T: Integer,
 {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let (sign, numerator, denominator) =
-            extract_sign(self.numerator.into_impl(), self.denominator.into_impl(), f);
-
-        if let Some(sign) = sign {
-            f.write_char(sign)?;
-        }
+        let style = if f.alternate() {
+            FractionStyle::Slashed
+        } else {
+            FractionStyle::SingleChar
+        };
+        fmt_signed_fraction(self, style, true, f)
+    }
+}
 
-        if let Some(frac) = (!f.alternate())
-            .then(|| find_single_character_fraction(numerator, denominator))
-            .flatten()
-        {
-            f.write_char(frac)
+impl<T> fmt::Display for SignZero<VulgarFraction<T>>
+where
+    T: Integer,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let style = if f.alternate() {
+            FractionStyle::Slashed
         } else {
-            write!(f, "{}", Superscript(numerator.into_public()))?;
-            const FRACTION_SLASH: char = '\u{2044}';
-            f.write_char(FRACTION_SLASH)?;
-            write!(f, "{}", Subscript(denominator.into_public()))
-        }
+            FractionStyle::SingleChar
+        };
+        fmt_signed_fraction(&self.0, style, self.1, f)
+    }
+}
+
+impl<T> fmt::Display for PreferredFraction<T>
+where
+    T: Integer,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt_signed_fraction(&self.0, self.1, true, f)
+    }
+}
+
+fn fmt_signed_fraction<T>(
+    fraction: &VulgarFraction<T>,
+    style: FractionStyle,
+    sign_zero: bool,
+    f: &mut fmt::Formatter,
+) -> fmt::Result
+where
+    T: Integer,
+{
+    let numerator = fraction.numerator.into_impl();
+    let denominator = fraction.denominator.into_impl();
+
+    if f.sign_plus() {
+        let negative = matches!(numerator.sign() * denominator.sign(), Sign::Negative);
+        let magnitude = FractionMagnitude {
+            numerator: numerator.unsigned_abs(),
+            denominator: denominator.unsigned_abs(),
+            style,
+        };
+        let with_sign = WithSign::from_parts(negative, numerator == IntegerImpl::ZERO, magnitude)
+            .sign_zero(sign_zero);
+        fmt::Display::fmt(&with_sign, f)
+    } else {
+        fmt_fraction(numerator, denominator, style, f)
+    }
+}
+
+/// The magnitude of a fraction, without a sign.
+struct FractionMagnitude<T> {
+    numerator: T,
+    denominator: T,
+    style: FractionStyle,
+}
+
+impl<T: IntegerImpl> fmt::Display for FractionMagnitude<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_fraction(self.numerator, self.denominator, self.style, f)
     }
 }
 
-fn extract_sign<T>(numerator: T, denominator: T, f: &fmt::Formatter) -> (Option<char>, T, T)
+fn fmt_fraction<T>(
+    numerator: T,
+    denominator: T,
+    style: FractionStyle,
+    f: &mut fmt::Formatter,
+) -> fmt::Result
 where
     T: IntegerImpl,
 {
-    match numerator.sign() * denominator.sign() {
-        Sign::PositiveOrZero if f.sign_plus() => (Some('+'), numerator.abs(), denominator.abs()),
-        Sign::Negative if f.sign_plus() => (Some('-'), numerator.abs(), denominator.abs()),
-        _ => (None, numerator, denominator),
+    const FRACTION_SLASH: char = '\u{2044}';
+    if let Some(frac) = (style != FractionStyle::Slashed)
+        .then(|| find_single_character_fraction(numerator, denominator))
+        .flatten()
+    {
+        f.write_char(frac)
+    } else if style == FractionStyle::FontBuilt {
+        write!(f, "{numerator}{FRACTION_SLASH}{denominator}")
+    } else {
+        write!(f, "{}", Superscript(numerator.into_public()))?;
+        f.write_char(FRACTION_SLASH)?;
+        write!(f, "{}", Subscript(denominator.into_public()))
     }
 }
 
@@ -136,3 +1270,566 @@ where
         _ => None,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn formats_created_from_fraction_are_copy() {
+        fn assert_copy<T: Copy>(_: T) {}
+        let fraction = VulgarFraction::new(1, 2);
+        assert_copy(fraction.spelled());
+        assert_copy(fraction.boxed());
+        assert_copy(fraction.mixed());
+        assert_copy(fraction.bar(8));
+        assert_copy(fraction.prefer(FractionStyle::Slashed));
+        assert_copy(fraction.recurring().unwrap());
+    }
+
+    #[test]
+    fn sums_fractions() {
+        let fractions = [
+            VulgarFraction::new(1, 2),
+            VulgarFraction::new(1, 3),
+            VulgarFraction::new(1, 6),
+        ];
+        assert_eq!(VulgarFraction::new(1, 1), fractions.iter().copied().sum());
+        assert_eq!(VulgarFraction::new(1, 1), fractions.iter().sum());
+        assert_eq!(
+            VulgarFraction::new(0, 1),
+            core::iter::empty::<VulgarFraction<i64>>().sum()
+        );
+        assert_eq!(
+            VulgarFraction::new(-1, 4),
+            [VulgarFraction::new(1, -2), VulgarFraction::new(1, 4)]
+                .iter()
+                .sum()
+        );
+        assert_eq!(
+            VulgarFraction::new(0, 1),
+            [VulgarFraction::new(2, 3), VulgarFraction::new(-4, 6)]
+                .iter()
+                .sum()
+        );
+    }
+
+    #[test]
+    fn multiplies_fractions() {
+        let fractions = [
+            VulgarFraction::new(1, 2),
+            VulgarFraction::new(2, 3),
+            VulgarFraction::new(-3, 4),
+        ];
+        assert_eq!(VulgarFraction::new(-1, 4), fractions.iter().product());
+        assert_eq!(
+            VulgarFraction::new(1, 1),
+            core::iter::empty::<VulgarFraction<u8>>().product()
+        );
+        assert_eq!(
+            VulgarFraction::new(0, 1),
+            [VulgarFraction::new(0, -5), VulgarFraction::new(-1, 3)]
+                .iter()
+                .product()
+        );
+    }
+
+    #[test]
+    fn keeps_intermediate_values_small() {
+        assert_eq!(
+            Some(VulgarFraction::new(127_u8, 1)),
+            VulgarFraction::new(254_u8, 254).checked_mul(&VulgarFraction::new(127, 1))
+        );
+        assert_eq!(
+            Some(VulgarFraction::new(1_u8, 1)),
+            VulgarFraction::new(100_u8, 200).checked_add(&VulgarFraction::new(50, 100))
+        );
+        assert_eq!(
+            Some(VulgarFraction::new(i8::MIN, 1)),
+            VulgarFraction::new(i8::MIN, 2).checked_add(&VulgarFraction::new(-64, 1))
+        );
+        assert_eq!(
+            Some(VulgarFraction::new(1_i8, 1)),
+            VulgarFraction::new(i8::MIN, i8::MIN).checked_mul(&VulgarFraction::new(-1, -1))
+        );
+    }
+
+    #[test]
+    fn detects_overflow() {
+        assert_eq!(
+            None,
+            VulgarFraction::new(1_u8, 16).checked_add(&VulgarFraction::new(1, 17))
+        );
+        assert_eq!(
+            None,
+            VulgarFraction::new(i8::MIN, 1).checked_mul(&VulgarFraction::new(-1, 1))
+        );
+        assert_eq!(
+            None,
+            VulgarFraction::new(1, 2).checked_add(&VulgarFraction::new(1, 0))
+        );
+        assert_eq!(
+            None,
+            VulgarFraction::new(0, 0).checked_mul(&VulgarFraction::new(1, 1))
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "the sum of the fractions is undefined or out of range")]
+    fn panics_when_sum_overflows() {
+        let _: VulgarFraction<u8> = [VulgarFraction::new(255, 1), VulgarFraction::new(1, 1)]
+            .iter()
+            .sum();
+    }
+
+    #[test]
+    fn checks_and_normalizes_denominator() {
+        assert_eq!(
+            Err(FractionError::ZeroDenominator),
+            VulgarFraction::try_new(1, 0)
+        );
+        assert_eq!(
+            Err(FractionError::ZeroDenominator),
+            VulgarFraction::try_new(0_u8, 0)
+        );
+        assert_eq!(
+            Ok(VulgarFraction::new(-3, 4)),
+            VulgarFraction::try_new(3, -4)
+        );
+        assert_eq!(
+            Ok(VulgarFraction::new(3, 4)),
+            VulgarFraction::try_new(-3, -4)
+        );
+        assert_eq!(
+            Ok(VulgarFraction::new(-3, 4)),
+            VulgarFraction::try_new(-3, 4)
+        );
+        assert_eq!(
+            Ok(VulgarFraction::new(0, 4)),
+            VulgarFraction::try_new(0, -4)
+        );
+        assert_eq!(
+            Ok(VulgarFraction::new(7_u8, 2)),
+            VulgarFraction::try_new(7_u8, 2)
+        );
+        assert_eq!(
+            Ok(VulgarFraction::new(i8::MIN, i8::MAX)),
+            VulgarFraction::try_new(i8::MIN, i8::MAX)
+        );
+        assert_eq!(
+            Err(FractionError::NegationOverflow),
+            VulgarFraction::try_new(1, i8::MIN)
+        );
+        assert_eq!(
+            Err(FractionError::NegationOverflow),
+            VulgarFraction::try_new(i8::MIN, -1)
+        );
+    }
+
+    #[test]
+    fn reduces_parts_with_positive_denominator() {
+        for (expected, (numerator, denominator)) in [
+            ((1, 2), (2, 4)),
+            ((1, 2), (-2, -4)),
+            ((-1, 2), (2, -4)),
+            ((-1, 2), (-2, 4)),
+            ((3, 1), (3, 1)),
+            ((0, 1), (0, -7)),
+            ((5, 0), (5, 0)),
+            ((0, 0), (0, 0)),
+        ] {
+            assert_eq!(
+                expected,
+                VulgarFraction::new(numerator, denominator).reduced_parts()
+            );
+        }
+        assert_eq!(
+            (1, 1),
+            VulgarFraction::new(i8::MIN, i8::MIN).reduced_parts()
+        );
+        assert_eq!((-64, 1), VulgarFraction::new(i8::MIN, 2).reduced_parts());
+        assert_eq!((85, 1), VulgarFraction::new(255_u8, 3).reduced_parts());
+    }
+
+    #[test]
+    fn reduces_to_lowest_terms() {
+        for (expected, (numerator, denominator)) in [
+            ((5, 2), (10, 4)),
+            ((-5, 2), (-10, 4)),
+            ((-5, 2), (10, -4)),
+            ((5, 2), (-10, -4)),
+            ((0, 1), (0, 5)),
+            ((0, 1), (0, -5)),
+            ((7, 3), (7, 3)),
+        ] {
+            assert_eq!(
+                VulgarFraction::new(expected.0, expected.1),
+                VulgarFraction::new(numerator, denominator).reduced()
+            );
+        }
+        assert_eq!("⁻⁵⁄₂", VulgarFraction::new(10, -4).reduced().to_string());
+        assert_eq!("½", VulgarFraction::new(127_u8, 254).reduced().to_string());
+    }
+
+    #[test]
+    fn formats_mixed_numbers() {
+        for (expected, (numerator, denominator)) in [
+            ("3⅓", (10, 3)),
+            ("2", (6, 3)),
+            ("1", (-1, -1)),
+            ("12¹⁄₁₁", (133, 11)),
+            ("-3⅓", (-10, 3)),
+            ("-3⅓", (10, -3)),
+            ("-2", (-6, 3)),
+            ("¾", (3, 4)),
+            ("⁻³⁄₄", (-3, 4)),
+            ("⁰⁄₅", (0, 5)),
+            ("¹⁰⁄₀", (10, 0)),
+        ] {
+            assert_eq!(
+                expected,
+                VulgarFraction::new(numerator, denominator)
+                    .mixed()
+                    .to_string()
+            );
+        }
+    }
+
+    #[test]
+    fn approximates_floats_within_denominator_bound() {
+        for (expected, (value, max_denominator)) in [
+            ((3, 4), (0.75, 4)),
+            ((1, 2), (0.5, 100)),
+            ((1, 3), (1.0 / 3.0, 100)),
+            ((1, 3), (0.333, 10)),
+            ((333, 1000), (0.333, 1000)),
+            ((2, 1), (2.0, 7)),
+            ((0, 1), (0.0, 7)),
+            ((0, 1), (-0.0, 7)),
+            ((0, 1), (1e-300, 100)),
+            ((-7, 4), (-1.75, 4)),
+            ((-2, 1), (-1.75, 1)),
+            ((22, 7), (core::f64::consts::PI, 10)),
+            ((311, 99), (core::f64::consts::PI, 100)),
+            ((355, 113), (core::f64::consts::PI, 16_000)),
+            ((94_053, 29_938), (core::f64::consts::PI, 30_000)),
+            ((1, 1), (0.999_999, 1000)),
+            ((1, u32::MAX as i64), (1.0 / f64::from(u32::MAX), u32::MAX)),
+        ] {
+            assert_eq!(
+                Ok(VulgarFraction::new(expected.0, expected.1)),
+                VulgarFraction::from_f64(value, max_denominator),
+                "{value} with denominator at most {max_denominator}"
+            );
+        }
+    }
+
+    #[test]
+    fn rejects_floats_without_fraction() {
+        for value in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+            assert_eq!(
+                Err(FractionError::NotFinite),
+                VulgarFraction::from_f64(value, 100)
+            );
+        }
+        assert_eq!(
+            Err(FractionError::OutOfRange),
+            VulgarFraction::from_f64(1e19, 100)
+        );
+        assert_eq!(
+            Err(FractionError::ZeroDenominator),
+            VulgarFraction::from_f64(0.5, 0)
+        );
+        assert_eq!(
+            Ok(VulgarFraction::new(1 << 62, 1)),
+            VulgarFraction::from_f64(4.611_686_018_427_388e18, 100)
+        );
+    }
+
+    #[test]
+    fn formats_mixed_numbers_with_flags() {
+        assert_eq!("3¹⁄₃", format!("{:#}", VulgarFraction::new(10, 3).mixed()));
+        assert_eq!("+3⅓", format!("{:+}", VulgarFraction::new(10, 3).mixed()));
+        assert_eq!("-2", format!("{:+}", VulgarFraction::new(6, -3).mixed()));
+        assert_eq!("+¾", format!("{:+}", VulgarFraction::new(3, 4).mixed()));
+        assert_eq!("-128", VulgarFraction::new(i8::MIN, 1).mixed().to_string());
+        assert_eq!(
+            "127¹⁄₂",
+            format!("{:#}", VulgarFraction::new(u8::MAX, 2).mixed())
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "out of range")]
+    fn reducing_unrepresentable_parts_panics() {
+        let _ = VulgarFraction::new(i8::MIN, -1).reduced_parts();
+    }
+
+    #[test]
+    fn prefers_single_character_fraction() {
+        let fraction = VulgarFraction::new(3, 4).prefer(FractionStyle::SingleChar);
+        assert_eq!("¾", fraction.to_string());
+        assert_eq!("¾", format!("{fraction:#}"));
+        assert_eq!(
+            "+¾",
+            format!(
+                "{:+}",
+                VulgarFraction::new(-3, -4).prefer(FractionStyle::SingleChar)
+            )
+        );
+        assert_eq!(
+            "³⁄₇",
+            VulgarFraction::new(3, 7)
+                .prefer(FractionStyle::SingleChar)
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn prefers_slashed_fraction() {
+        let fraction = VulgarFraction::new(3, 4).prefer(FractionStyle::Slashed);
+        assert_eq!("³⁄₄", fraction.to_string());
+        assert_eq!("³⁄₄", format!("{fraction:#}"));
+        assert_eq!(
+            "-³⁄₄",
+            format!(
+                "{:+}",
+                VulgarFraction::new(3, -4).prefer(FractionStyle::Slashed)
+            )
+        );
+    }
+
+    #[test]
+    fn leaves_fallback_to_font() {
+        let fraction = VulgarFraction::new(7, 16).prefer(FractionStyle::FontBuilt);
+        assert_eq!("7\u{2044}16", fraction.to_string());
+        assert_eq!(
+            ['7', '\u{2044}', '1', '6'],
+            *fraction.to_string().chars().collect::<Vec<_>>()
+        );
+        assert_eq!(
+            "-7\u{2044}16",
+            format!(
+                "{:+}",
+                VulgarFraction::new(-7, 16).prefer(FractionStyle::FontBuilt)
+            )
+        );
+        assert_eq!(
+            "½",
+            VulgarFraction::new(1, 2)
+                .prefer(FractionStyle::FontBuilt)
+                .to_string()
+        );
+        assert_eq!(
+            "+0\u{2044}7",
+            format!(
+                "{:+}",
+                VulgarFraction::new(0, 7).prefer(FractionStyle::FontBuilt)
+            )
+        );
+    }
+
+    #[test]
+    fn rewrites_over_common_denominator() {
+        for ((a, b), expected) in [
+            (((1, 2), (1, 3)), ((3, 6), (2, 6))),
+            (((1, 4), (1, 6)), ((3, 12), (2, 12))),
+            (((2, 4), (3, 6)), ((6, 12), (6, 12))),
+            (((-1, 4), (1, -6)), ((-3, 12), (-2, 12))),
+            (((-1, -4), (0, 5)), ((5, 20), (0, 20))),
+            (((5, 1), (1, 7)), ((35, 7), (1, 7))),
+        ] {
+            let expected = (
+                VulgarFraction::from(expected.0),
+                VulgarFraction::from(expected.1),
+            );
+            assert_eq!(
+                Some(expected),
+                VulgarFraction::common_denominator(
+                    VulgarFraction::from(a),
+                    VulgarFraction::from(b)
+                )
+            );
+        }
+    }
+
+    #[test]
+    fn rejects_common_denominator_out_of_range() {
+        let common_denominator = |a: (i8, i8), b: (i8, i8)| {
+            VulgarFraction::common_denominator(VulgarFraction::from(a), VulgarFraction::from(b))
+        };
+        assert_eq!(None, common_denominator((1, 0), (1, 2)));
+        assert_eq!(None, common_denominator((1, 2), (0, 0)));
+        assert_eq!(None, common_denominator((1, 11), (1, 13)));
+        assert_eq!(None, common_denominator((100, 1), (1, 2)));
+        assert_eq!(None, common_denominator((1, i8::MIN), (1, 3)));
+        assert_eq!(
+            Some((VulgarFraction::new(-128, 2), VulgarFraction::new(1, 2))),
+            common_denominator((i8::MIN, 2), (1, 2))
+        );
+    }
+
+    #[test]
+    fn signs_zero_fraction_only_if_enabled() {
+        assert_eq!(
+            "+⁰⁄₁",
+            format!("{:+}", VulgarFraction::new(0, 1).sign_zero(true))
+        );
+        assert_eq!(
+            "-⁰⁄₁",
+            format!("{:+}", VulgarFraction::new(0, -1).sign_zero(true))
+        );
+        assert_eq!(
+            "⁰⁄₁",
+            format!("{:+}", VulgarFraction::new(0, 1).sign_zero(false))
+        );
+        assert_eq!(
+            "⁰⁄₁",
+            format!("{:+}", VulgarFraction::new(0, -1).sign_zero(false))
+        );
+        assert_eq!(
+            "↉",
+            format!("{:+}", VulgarFraction::new(0, 3).sign_zero(false))
+        );
+        assert_eq!(
+            "-½",
+            format!("{:+}", VulgarFraction::new(-1, 2).sign_zero(false))
+        );
+        assert_eq!(
+            "⁰⁄₋₁",
+            VulgarFraction::new(0, -1).sign_zero(false).to_string()
+        );
+    }
+
+    #[test]
+    fn formats_spelled_fractions() {
+        let over = |n: i32, d: i32| VulgarFraction::new(n, d).spelled();
+        let slash = |n: i32, d: i32| over(n, d).separator(FractionSeparator::Slash);
+        assert_eq!("10 over 3", over(10, 3).to_string());
+        assert_eq!("-1 over 4", over(-1, 4).to_string());
+        assert_eq!("10/3", slash(10, 3).to_string());
+        assert_eq!("-1/4", slash(-1, 4).to_string());
+        assert_eq!("+10 over 3", format!("{:+}", over(-10, -3)));
+        assert_eq!("-1/4", format!("{:+}", slash(1, -4)));
+        assert_eq!(
+            "-128 over 1",
+            format!("{:+}", VulgarFraction::new(i8::MIN, 1).spelled())
+        );
+    }
+
+    #[test]
+    fn compares_values_without_overflow() {
+        let cmp = |(a, b), (c, d)| VulgarFraction::new(a, b).cmp_value(&VulgarFraction::new(c, d));
+        let max = i64::MAX;
+        assert_eq!(Some(Ordering::Less), cmp((max - 1, max), (max, max)));
+        assert_eq!(
+            Some(Ordering::Less),
+            cmp((max, max - 1), (max - 1, max - 2))
+        );
+        assert_eq!(Some(Ordering::Equal), cmp((max, max), (i64::MIN, i64::MIN)));
+        assert_eq!(Some(Ordering::Less), cmp((i64::MIN, 1), (max, 1)));
+        assert_eq!(Some(Ordering::Less), cmp((max, -1), (i64::MIN + 2, 1)));
+        assert_eq!(Some(Ordering::Less), cmp((1, -max), (1, i64::MIN)));
+        assert_eq!(Some(Ordering::Less), cmp((-1, 3), (1, -4)));
+        assert_eq!(None, cmp((0, 0), (0, 0)));
+    }
+
+    #[test]
+    fn maps_numerator_and_denominator() {
+        let fraction = VulgarFraction::new(3_u8, 4_u8).map_both(u32::from);
+        assert_eq!(VulgarFraction::new(3_u32, 4_u32), fraction);
+        assert_eq!(
+            "¹⁄₂",
+            format!("{:#}", VulgarFraction::new(-1, -2).map_both(i32::abs))
+        );
+    }
+
+    #[test]
+    fn converts_to_permille_and_basis_points() {
+        assert_eq!(Some(125), VulgarFraction::new(1, 8).as_permille());
+        assert_eq!(Some(1), VulgarFraction::new(1, 10_000).as_basis_points());
+        assert_eq!(Some(1), VulgarFraction::new(1, 2000).as_permille()); // 0.5 rounds up
+        assert_eq!(Some(-1), VulgarFraction::new(-1, 2000).as_permille()); // -0.5 rounds down
+        assert_eq!(Some(-1), VulgarFraction::new(1, -2000).as_permille());
+        assert_eq!(Some(0), VulgarFraction::new(1, 2001).as_permille());
+        assert_eq!(Some(667), VulgarFraction::new(2_u32, 3).as_permille());
+        assert_eq!(None, VulgarFraction::new(1, 0).as_permille());
+        assert_eq!(None, VulgarFraction::new(i32::MAX, 1).as_basis_points());
+    }
+
+    #[test]
+    fn formats_fraction_of_min_values_with_sign() {
+        assert_eq!(
+            "+¹²⁸⁄₁₂₈",
+            format!("{:+}", VulgarFraction::new(i8::MIN, i8::MIN))
+        );
+        assert_eq!("-¹²⁸⁄₁", format!("{:+}", VulgarFraction::new(i8::MIN, 1)));
+    }
+
+    #[test]
+    fn approximates_constants() {
+        for (expected, fraction) in [
+            ((22, 7), VulgarFraction::approx_pi()),
+            ((355, 113), VulgarFraction::approx_pi_precise()),
+            ((19, 7), VulgarFraction::approx_e()),
+            ((2721, 1001), VulgarFraction::approx_e_precise()),
+            ((7, 5), VulgarFraction::approx_sqrt_2()),
+            ((1393, 985), VulgarFraction::approx_sqrt_2_precise()),
+            ((8, 5), VulgarFraction::approx_golden_ratio()),
+            ((1597, 987), VulgarFraction::approx_golden_ratio_precise()),
+        ] {
+            assert_eq!(VulgarFraction::from(expected), fraction);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn formats_as_latex() {
+        assert_eq!("\\frac{1}{4}", VulgarFraction::new(1, 4).to_latex());
+        assert_eq!("-\\frac{10}{3}", VulgarFraction::new(-10, 3).to_latex());
+        assert_eq!("-\\frac{0}{1}", VulgarFraction::new(0, -1).to_latex());
+        assert_eq!(
+            "\\frac{128}{128}",
+            VulgarFraction::new(i8::MIN, i8::MIN).to_latex()
+        );
+    }
+
+    #[test]
+    fn draws_bar() {
+        assert_eq!("         0%", VulgarFraction::new(0, 1).bar(8).to_string());
+        assert_eq!("████     50%", VulgarFraction::new(1, 2).bar(8).to_string());
+        assert_eq!("██████   75%", VulgarFraction::new(3, 4).bar(8).to_string());
+        assert_eq!(
+            "████████ 100%",
+            VulgarFraction::new(1, 1).bar(8).to_string()
+        );
+        assert_eq!("▏        2%", VulgarFraction::new(1, 64).bar(8).to_string());
+        assert_eq!("█▊ 90%", VulgarFraction::new(9, 10).bar(2).to_string());
+    }
+
+    #[test]
+    fn draws_clamped_bar() {
+        assert_eq!("████ 100%", VulgarFraction::new(5, 4).bar(4).to_string());
+        assert_eq!("     0%", VulgarFraction::new(-1, 4).bar(4).to_string());
+        assert_eq!("     0%", VulgarFraction::new(1, 0).bar(4).to_string());
+        assert_eq!(
+            "████ ⁻⁵⁄₋₄",
+            VulgarFraction::new(-5, -4)
+                .bar(4)
+                .fraction_label()
+                .to_string()
+        );
+        assert_eq!(" 50%", VulgarFraction::new(1, 2).bar(0).to_string());
+    }
+
+    #[test]
+    fn formats_boxed_fraction() {
+        assert_eq!("12\n──\n3 ", VulgarFraction::new(12, 3).boxed().to_string());
+        assert_eq!("3 \n──\n12", VulgarFraction::new(3, 12).boxed().to_string());
+        assert_eq!(
+            " 1 \n───\n123",
+            VulgarFraction::new(1, 123).boxed().to_string()
+        );
+        assert_eq!("-7\n──\n0 ", VulgarFraction::new(-7, 0).boxed().to_string());
+    }
+}
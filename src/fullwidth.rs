@@ -0,0 +1,214 @@
+use crate::digits::{fmt_with_digit_table, max_digits};
+use crate::integer::IntegerImpl;
+use crate::{Integer, Plain, SignGlyphs};
+use core::fmt;
+
+/// Formats an integer using the fullwidth digits `０` – `９` (U+FF10 – U+FF19)
+/// from the [Halfwidth and Fullwidth Forms] block, e.g. for aligning numbers with CJK text.
+///
+/// Hexadecimal digits use the fullwidth letters `Ａ` – `Ｆ` (or `ａ` – `ｆ` for [`LowerHex`](fmt::LowerHex))
+/// and the signs use the fullwidth plus `＋` (U+FF0B) and hyphen-minus `－` (U+FF0D).
+/// See [`SignGlyphs::FULLWIDTH`].
+/// Negative numbers are written as sign and magnitude in all bases,
+/// e.g. `－１０１` for `-5` in binary, unlike the two's complement written by [`core::fmt`].
+///
+/// [Halfwidth and Fullwidth Forms]: https://www.unicode.org/charts/PDF/UFF00.pdf
+///
+/// ```
+/// use fmtastic::Fullwidth;
+///
+/// assert_eq!("６２８", format!("{}", Fullwidth(628_u32)));
+/// assert_eq!("－４２", Fullwidth(-42).to_string());
+///
+/// // Binary
+/// assert_eq!("１０１", format!("{:b}", Fullwidth(5_u8)));
+///
+/// // Hexadecimal
+/// assert_eq!("Ｃ０ＤＥ", format!("{:X}", Fullwidth(0xC0DE_u16)));
+/// assert_eq!("ｃ０ｄｅ", format!("{:x}", Fullwidth(0xC0DE_u16)));
+/// ```
+///
+/// ## Formatting Flags
+/// ### Sign: `+`
+/// Use the `+` flag to always include the fullwidth plus sign for positive numbers.
+///
+/// ```
+/// # use fmtastic::Fullwidth;
+/// assert_eq!("＋６２８", format!("{:+}", Fullwidth(628)));
+/// ```
+///
+/// ## Layout
+/// `Fullwidth<T>` is `#[repr(transparent)]`, so it has the same size, alignment and ABI as `T`.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct Fullwidth<T>(pub T);
+
+impl<T> Fullwidth<T> {
+    /// Returns a reference to the wrapped value.
+    #[must_use]
+    pub fn as_inner(&self) -> &T {
+        &self.0
+    }
+
+    /// Applies a function to the wrapped value.
+    ///
+    /// ```
+    /// # use fmtastic::Fullwidth;
+    /// assert_eq!(Fullwidth(4), Fullwidth(3_u8).map(|n| n + 1));
+    /// ```
+    #[must_use]
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> Fullwidth<U> {
+        Fullwidth(f(self.0))
+    }
+
+    /// Formats the wrapped value using its standard formatting,
+    /// ignoring the glyphs of this format. See [`Plain`].
+    #[must_use]
+    pub fn plain(self) -> Plain<T> {
+        Plain(self.0)
+    }
+}
+
+impl<T> From<T> for Fullwidth<T>
+where
+    T: Integer,
+{
+    fn from(value: T) -> Self {
+        Fullwidth(value)
+    }
+}
+
+impl<T> Fullwidth<T>
+where
+    T: Integer,
+{
+    /// The largest number of bytes written when formatting a value of type `T`
+    /// using any of the formatting traits, for sizing buffers.
+    ///
+    /// The longest output is the binary representation of the largest magnitude
+    /// with a sign, with one digit per bit of `T`. The sign and each digit are encoded in three bytes.
+    ///
+    /// ```
+    /// # use fmtastic::Fullwidth;
+    /// assert_eq!((8 + 1) * 3, Fullwidth::<i8>::MAX_LEN);
+    /// ```
+    pub const MAX_LEN: usize = (max_digits(<T::Impl as IntegerImpl>::BITS, 2) + 1) * 3;
+}
+
+impl<T> fmt::Display for Fullwidth<T>
+where
+    T: Integer,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_fullwidth(self.0.into_impl(), 10, &DIGITS, f)
+    }
+}
+
+impl<T> fmt::Binary for Fullwidth<T>
+where
+    T: Integer,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_fullwidth(self.0.into_impl(), 2, &DIGITS, f)
+    }
+}
+
+impl<T> fmt::UpperHex for Fullwidth<T>
+where
+    T: Integer,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_fullwidth(self.0.into_impl(), 16, &DIGITS, f)
+    }
+}
+
+impl<T> fmt::LowerHex for Fullwidth<T>
+where
+    T: Integer,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_fullwidth(self.0.into_impl(), 16, &LOWERCASE_DIGITS, f)
+    }
+}
+
+fn fmt_fullwidth<T: IntegerImpl>(
+    n: T,
+    radix: u32,
+    digits: &[char; 16],
+    f: &mut fmt::Formatter<'_>,
+) -> fmt::Result {
+    fmt_with_digit_table(n, radix, digits, SignGlyphs::FULLWIDTH, f)
+}
+
+const DIGITS: [char; 16] = [
+    '０', '１', '２', '３', '４', '５', '６', '７', '８', '９', 'Ａ', 'Ｂ', 'Ｃ', 'Ｄ', 'Ｅ', 'Ｆ',
+];
+
+const LOWERCASE_DIGITS: [char; 16] = [
+    '０', '１', '２', '３', '４', '５', '６', '７', '８', '９', 'ａ', 'ｂ', 'ｃ', 'ｄ', 'ｅ', 'ｆ',
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn maps_digits_to_fullwidth() {
+        for (digit, fullwidth) in ('0'..='9').zip(DIGITS) {
+            assert_eq!(u32::from(digit) + 0xFEE0, u32::from(fullwidth));
+        }
+        for (digit, (upper, lower)) in
+            ('A'..='F').zip(DIGITS[10..].iter().zip(&LOWERCASE_DIGITS[10..]))
+        {
+            assert_eq!(u32::from(digit) + 0xFEE0, u32::from(*upper));
+            assert_eq!(
+                u32::from(digit.to_ascii_lowercase()) + 0xFEE0,
+                u32::from(*lower)
+            );
+        }
+    }
+
+    #[test]
+    fn matches_standard_formatting() {
+        let to_fullwidth = |s: String| {
+            s.chars()
+                .map(|c| char::from_u32(u32::from(c) + 0xFEE0).unwrap())
+                .collect::<String>()
+        };
+        for n in i16::MIN..=i16::MAX {
+            assert_eq!(to_fullwidth(n.to_string()), Fullwidth(n).to_string());
+            assert_eq!(
+                to_fullwidth(format!("{n:+}")),
+                format!("{:+}", Fullwidth(n))
+            );
+        }
+        for n in 0..=u16::MAX {
+            assert_eq!(
+                to_fullwidth(format!("{n:b}")),
+                format!("{:b}", Fullwidth(n))
+            );
+            assert_eq!(
+                to_fullwidth(format!("{n:X}")),
+                format!("{:X}", Fullwidth(n))
+            );
+            assert_eq!(
+                to_fullwidth(format!("{n:x}")),
+                format!("{:x}", Fullwidth(n))
+            );
+        }
+    }
+
+    #[test]
+    fn formats_extreme_values() {
+        assert_eq!(
+            "－１７０１４１１８３４６０４６９２３１７３１６８７３０３７１５８８４１０５７２８",
+            Fullwidth(i128::MIN).to_string()
+        );
+        assert_eq!("－１０００００００", format!("{:b}", Fullwidth(i8::MIN)));
+        assert_eq!(
+            Fullwidth::<i8>::MAX_LEN,
+            format!("{:b}", Fullwidth(i8::MIN)).len()
+        );
+        assert_eq!("ＦＦ", format!("{:X}", Fullwidth(u8::MAX)));
+    }
+}
@@ -0,0 +1,123 @@
+use crate::integer::{scale_ratio, IntegerImpl, Sign};
+use crate::Integer;
+use core::cmp::Ordering;
+use core::fmt::{self, Write};
+
+/// Formats a value as one row of a horizontal histogram, e.g. `████▌     `.
+///
+/// The row is `width` cells wide and filled in proportion to `value / max`.
+/// Full cells use the full block `█`, the remainder is rounded to the nearest eighth
+/// (halfway cases are rounded up) and drawn using one of the
+/// left eighth blocks `▏▎▍▌▋▊▉`. The rest of the row is padded with spaces,
+/// so that rows of the same width line up.
+///
+/// Values are clamped: Values greater than or equal to `max` fill the whole row,
+/// zero and negative values leave it empty.
+/// If `max` is not positive, the row is empty.
+///
+/// ```
+/// # use fmtastic::Histogram;
+/// assert_eq!("████▌     ", Histogram::row(45, 100, 10).to_string());
+/// assert_eq!("███", Histogram::row(7, 5, 3).to_string()); // clamped
+/// assert_eq!("█▊", Histogram::row(7, 8, 2).chars().collect::<String>());
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct Histogram<T> {
+    value: T,
+    max: T,
+    width: usize,
+}
+
+impl<T> Histogram<T>
+where
+    T: Integer,
+{
+    /// Creates a row of `width` cells showing `value` relative to `max`.
+    #[must_use]
+    pub fn row(value: T, max: T, width: usize) -> Self {
+        Histogram { value, max, width }
+    }
+
+    /// Returns an iterator over the cells of the row, from left to right.
+    /// There are always exactly `width` cells.
+    pub fn chars(&self) -> impl Iterator<Item = char> {
+        bar_cells(self.eighths(), self.width)
+    }
+
+    fn eighths(&self) -> usize {
+        let (value, max) = (self.value.into_impl(), self.max.into_impl());
+        let negative = |n: T::Impl| matches!(n.sign(), Sign::Negative);
+        if value == T::Impl::ZERO || negative(value) || max == T::Impl::ZERO || negative(max) {
+            0
+        } else if value >= max {
+            self.width.saturating_mul(8)
+        } else {
+            scale_ratio(
+                value.unsigned_abs(),
+                max.unsigned_abs(),
+                self.width.saturating_mul(8),
+            )
+        }
+    }
+}
+
+impl<T> fmt::Display for Histogram<T>
+where
+    T: Integer,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.chars().try_for_each(|c| f.write_char(c))
+    }
+}
+
+/// Iterates the cells of a bar `width` cells wide, filled with the given number of eighths.
+pub(crate) fn bar_cells(eighths: usize, width: usize) -> impl Iterator<Item = char> {
+    const PARTIAL_BLOCKS: [char; 8] = [
+        ' ', '\u{258F}', '\u{258E}', '\u{258D}', '\u{258C}', '\u{258B}', '\u{258A}', '\u{2589}',
+    ];
+    const FULL_BLOCK: char = '\u{2588}';
+
+    let (full, partial) = (eighths / 8, eighths % 8);
+    (0..width).map(move |cell| match cell.cmp(&full) {
+        Ordering::Less => FULL_BLOCK,
+        Ordering::Equal => PARTIAL_BLOCKS[partial],
+        Ordering::Greater => ' ',
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fills_whole_row_for_max() {
+        assert_eq!("██████████", Histogram::row(100, 100, 10).to_string());
+        assert_eq!("█", Histogram::row(u128::MAX, u128::MAX, 1).to_string());
+    }
+
+    #[test]
+    fn leaves_row_empty_for_zero() {
+        assert_eq!("          ", Histogram::row(0, 100, 10).to_string());
+        assert_eq!("   ", Histogram::row(-5, 100, 3).to_string());
+        assert_eq!("   ", Histogram::row(5, 0, 3).to_string());
+        assert_eq!("   ", Histogram::row(5, -10, 3).to_string());
+        assert_eq!("", Histogram::row(5, 10, 0).to_string());
+    }
+
+    #[test]
+    fn fills_fractional_cells() {
+        assert_eq!("███▎      ", Histogram::row(33, 100, 10).to_string());
+        assert_eq!("████▌     ", Histogram::row(45, 100, 10).to_string());
+        assert_eq!("▏         ", Histogram::row(1, 80, 10).to_string());
+        assert_eq!("██████████", Histogram::row(101_u8, 100, 10).to_string());
+        assert_eq!(10, Histogram::row(33, 100, 10).chars().count());
+    }
+
+    #[test]
+    fn does_not_overflow_for_large_widths() {
+        let full: String = Histogram::row(1, 1, usize::MAX).chars().take(3).collect();
+        assert_eq!("███", full);
+        let empty: String = Histogram::row(0, 1, usize::MAX).chars().take(3).collect();
+        assert_eq!("   ", empty);
+    }
+}
@@ -1,26 +1,35 @@
 use core::fmt;
-use core::ops::{Div, Rem, Sub};
+use core::num::{Saturating, Wrapping};
+use core::ops::{Add, Div, Rem, Sub};
 use core::ops::{Mul, SubAssign};
 
 pub(crate) trait IntegerImpl
 where
     Self: Copy,
+    Self: fmt::Debug,
+    Self: fmt::Display,
+    Self: Mul<Self, Output = Self>,
     Self: Div<Self, Output = Self>,
     Self: Rem<Self, Output = Self>,
     Self: TryInto<u8>,
     Self: TryFrom<u16>,
     Self: PartialOrd<Self>,
+    Self: Add<Self, Output = Self>,
     Self: Sub<Self, Output = Self>,
     Self: SubAssign<Self>,
 {
     const ZERO: Self;
     const ONE: Self;
     const FIVE: Self;
+    const BITS: u32;
 
     type Public: crate::Integer;
-    type BaseTwo: Base<Self>;
-    type BaseTen: Base<Self>;
-    type BaseSixteen: Base<Self>;
+    /// The unsigned counterpart used to represent the magnitude,
+    /// as the magnitude of e.g. `i32::MIN` does not fit into an `i32`.
+    type Magnitude: IntegerImpl;
+    type BaseTwo: Base<Self::Magnitude>;
+    type BaseTen: Base<Self::Magnitude>;
+    type BaseSixteen: Base<Self::Magnitude>;
 
     fn range(from: Self, to: Self) -> impl DoubleEndedIterator<Item = Self>;
 
@@ -32,8 +41,22 @@ where
         }
     }
 
-    fn abs(self) -> Self;
+    fn checked_abs(self) -> Option<Self>;
 
+    fn unsigned_abs(self) -> Self::Magnitude;
+
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+
+    fn abs_diff(self, other: Self) -> Self::Magnitude;
+
+    /// Creates a value from its magnitude and sign,
+    /// returning `None` if the value is out of range.
+    fn from_magnitude(magnitude: Self::Magnitude, negative: bool) -> Option<Self>;
+
+    /// Converts a value known to be small (such as a single digit) to `usize`.
+    /// Magnitudes are never converted, as `usize` may be as narrow as 16 bits.
     fn as_usize(self) -> usize;
 
     fn pow(self, exp: u32) -> Self;
@@ -41,6 +64,37 @@ where
     fn into_public(self) -> Self::Public;
 }
 
+/// Computes `n * factor / d` rounded to the nearest integer (halfway cases are rounded up)
+/// without overflowing. Requires `n <= d` and `d > 0`.
+pub(crate) fn scale_ratio<T: IntegerImpl>(n: T, d: T, factor: usize) -> usize {
+    debug_assert!(n <= d && d > T::ZERO);
+    let (mut quotient, mut remainder) = (0, T::ZERO);
+    for _ in 0..factor {
+        if remainder >= d - n {
+            remainder -= d - n;
+            quotient += 1;
+        } else {
+            remainder = remainder + n;
+        }
+    }
+    if remainder >= d - remainder {
+        quotient + 1
+    } else {
+        quotient
+    }
+}
+
+/// Computes the greatest common divisor of `a` and `b` using the Euclidean algorithm.
+/// The greatest common divisor of zero and zero is zero.
+pub(crate) fn gcd<T: IntegerImpl>(mut a: T, mut b: T) -> T {
+    while b != T::ZERO {
+        let remainder = a % b;
+        a = b;
+        b = remainder;
+    }
+    a
+}
+
 #[allow(dead_code)] // This is clearly used dear compiler
 pub(crate) trait UnsignedIntegerImpl: IntegerImpl + crate::roman::RomanInteger {}
 
@@ -88,6 +142,7 @@ macro_rules! common_integer_items {
         const ZERO: Self = 0;
         const ONE: Self = 1;
         const FIVE: Self = 5;
+        const BITS: u32 = <$ty>::BITS;
 
         type Public = $ty;
         type BaseTwo = Two;
@@ -106,6 +161,18 @@ macro_rules! common_integer_items {
             self.pow(exp)
         }
 
+        fn checked_mul(self, rhs: Self) -> Option<Self> {
+            self.checked_mul(rhs)
+        }
+
+        fn checked_add(self, rhs: Self) -> Option<Self> {
+            self.checked_add(rhs)
+        }
+
+        fn abs_diff(self, other: Self) -> Self::Magnitude {
+            self.abs_diff(other)
+        }
+
         fn into_public(self) -> Self::Public {
             self
         }
@@ -152,6 +219,10 @@ macro_rules! impl_unsigned_integer {
                 fn into_impl(self) -> $ty {
                     self
                 }
+
+                fn from_impl(value: $ty) -> Self {
+                    value
+                }
             }
 
             impl crate::ToUnsignedIntegerImpl for $ty {
@@ -163,9 +234,19 @@ macro_rules! impl_unsigned_integer {
             impl IntegerImpl for $ty {
                 common_integer_items!($ty);
 
-                fn abs(self) -> Self {
+                type Magnitude = $ty;
+
+                fn checked_abs(self) -> Option<Self> {
+                    Some(self)
+                }
+
+                fn unsigned_abs(self) -> Self {
                     self
                 }
+
+                fn from_magnitude(magnitude: Self, negative: bool) -> Option<Self> {
+                    (!negative || magnitude == 0).then_some(magnitude)
+                }
             }
 
             impl_bases!($ty);
@@ -174,7 +255,7 @@ macro_rules! impl_unsigned_integer {
 }
 
 macro_rules! impl_signed_integer {
-    ($($ty:ty),+) => {
+    ($($ty:ty => $unsigned:ty),+) => {
         $(
             impl crate::Integer for $ty {}
             impl crate::SignedInteger for $ty {}
@@ -185,21 +266,204 @@ macro_rules! impl_signed_integer {
                 fn into_impl(self) -> $ty {
                     self
                 }
+
+                fn from_impl(value: $ty) -> Self {
+                    value
+                }
             }
 
             impl IntegerImpl for $ty {
                 common_integer_items!($ty);
 
-                fn abs(self) -> Self {
-                    self.abs()
+                type Magnitude = $unsigned;
+
+                fn checked_abs(self) -> Option<Self> {
+                    self.checked_abs()
                 }
-            }
 
-            impl_bases!($ty);
+                fn unsigned_abs(self) -> $unsigned {
+                    self.unsigned_abs()
+                }
+
+                fn from_magnitude(magnitude: $unsigned, negative: bool) -> Option<Self> {
+                    if negative {
+                        (0 as $ty).checked_sub_unsigned(magnitude)
+                    } else {
+                        <$ty>::try_from(magnitude).ok()
+                    }
+                }
+            }
         )+
     };
 }
 
 impl_unsigned_integer!(u8, u16, u32, u64, u128, usize);
 
-impl_signed_integer!(i8, i16, i32, i64, i128, isize);
+impl_signed_integer!(i8 => u8, i16 => u16, i32 => u32, i64 => u64, i128 => u128, isize => usize);
+
+macro_rules! impl_integer_wrapper {
+    ($($wrapper:ident),+) => {
+        $(
+            impl<T: crate::Integer> crate::Integer for $wrapper<T> {}
+            impl<T: crate::SignedInteger> crate::SignedInteger for $wrapper<T> {}
+            impl<T: crate::UnsignedInteger> crate::UnsignedInteger for $wrapper<T> {}
+
+            impl<T: crate::Integer> crate::ToIntegerImpl for $wrapper<T> {
+                type Impl = T::Impl;
+
+                fn into_impl(self) -> T::Impl {
+                    self.0.into_impl()
+                }
+
+                fn from_impl(value: T::Impl) -> Self {
+                    $wrapper(T::from_impl(value))
+                }
+            }
+
+            impl<T: crate::UnsignedInteger> crate::ToUnsignedIntegerImpl for $wrapper<T> {
+                type UnsignedImpl = T::UnsignedImpl;
+            }
+        )+
+    };
+}
+
+// Wrappers are formatted using the contained value.
+impl_integer_wrapper!(Wrapping, Saturating);
+
+/// Implements [`Integer`](crate::Integer) for a newtype around a primitive integer,
+/// so that it can be formatted using this crate's formats.
+///
+/// The newtype must be a tuple struct whose only field is the primitive integer
+/// and the field must be accessible where the macro is invoked.
+/// The newtype must also implement [`Copy`].
+///
+/// Newtypes around unsigned integers also implement [`UnsignedInteger`](crate::UnsignedInteger),
+/// newtypes around signed integers implement [`SignedInteger`](crate::SignedInteger).
+///
+/// ```
+/// use fmtastic::{impl_fmtastic_integer, Superscript, TallyMarks};
+///
+/// #[derive(Debug, Clone, Copy)]
+/// struct Count(u32);
+///
+/// impl_fmtastic_integer!(Count => u32);
+///
+/// assert_eq!("¹²", Superscript(Count(12)).to_string());
+/// assert_eq!("𝍸𝍷𝍷", TallyMarks(Count(7)).to_string());
+/// ```
+#[macro_export]
+macro_rules! impl_fmtastic_integer {
+    ($ty:ty => u8) => { $crate::impl_fmtastic_integer!(@unsigned $ty => u8); };
+    ($ty:ty => u16) => { $crate::impl_fmtastic_integer!(@unsigned $ty => u16); };
+    ($ty:ty => u32) => { $crate::impl_fmtastic_integer!(@unsigned $ty => u32); };
+    ($ty:ty => u64) => { $crate::impl_fmtastic_integer!(@unsigned $ty => u64); };
+    ($ty:ty => u128) => { $crate::impl_fmtastic_integer!(@unsigned $ty => u128); };
+    ($ty:ty => usize) => { $crate::impl_fmtastic_integer!(@unsigned $ty => usize); };
+    ($ty:ty => i8) => { $crate::impl_fmtastic_integer!(@signed $ty => i8); };
+    ($ty:ty => i16) => { $crate::impl_fmtastic_integer!(@signed $ty => i16); };
+    ($ty:ty => i32) => { $crate::impl_fmtastic_integer!(@signed $ty => i32); };
+    ($ty:ty => i64) => { $crate::impl_fmtastic_integer!(@signed $ty => i64); };
+    ($ty:ty => i128) => { $crate::impl_fmtastic_integer!(@signed $ty => i128); };
+    ($ty:ty => isize) => { $crate::impl_fmtastic_integer!(@signed $ty => isize); };
+    (@unsigned $ty:ty => $inner:ty) => {
+        $crate::impl_fmtastic_integer!(@integer $ty => $inner);
+
+        impl $crate::UnsignedInteger for $ty {}
+
+        impl $crate::__private::ToUnsignedIntegerImpl for $ty {
+            type UnsignedImpl = $inner;
+        }
+    };
+    (@signed $ty:ty => $inner:ty) => {
+        $crate::impl_fmtastic_integer!(@integer $ty => $inner);
+
+        impl $crate::SignedInteger for $ty {}
+    };
+    (@integer $ty:ty => $inner:ty) => {
+        impl $crate::Integer for $ty {}
+
+        impl $crate::__private::ToIntegerImpl for $ty {
+            type Impl = $inner;
+
+            fn into_impl(self) -> $inner {
+                self.0
+            }
+
+            fn from_impl(value: $inner) -> Self {
+                Self(value)
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::scale_ratio;
+    use crate::{Superscript, TallyMarks};
+    use core::num::{Saturating, Wrapping};
+
+    const _: () = assert!(core::mem::size_of::<Superscript<u32>>() == core::mem::size_of::<u32>());
+
+    #[test]
+    fn wrappers_have_the_layout_of_the_wrapped_value() {
+        use crate::{Outlined, Segmented, Subscript};
+        use core::mem::{align_of, size_of};
+        assert_eq!(size_of::<u32>(), size_of::<Superscript<u32>>());
+        assert_eq!(align_of::<u32>(), align_of::<Superscript<u32>>());
+        assert_eq!(size_of::<i128>(), size_of::<Subscript<i128>>());
+        assert_eq!(align_of::<u64>(), align_of::<Segmented<u64>>());
+        assert_eq!(size_of::<[u16; 4]>(), size_of::<[Outlined<u16>; 4]>());
+    }
+
+    #[test]
+    fn formats_wrapping_integers_using_inner_value() {
+        assert_eq!("¹²³", Superscript(Wrapping(123_u32)).to_string());
+        assert_eq!("⁻¹²³", Superscript(Wrapping(-123_i32)).to_string());
+        assert_eq!("𝍸𝍸𝍷𝍷", TallyMarks(Wrapping(12_u32)).to_string());
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    struct Count(u32);
+
+    crate::impl_fmtastic_integer!(Count => u32);
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    struct Offset(i8);
+
+    crate::impl_fmtastic_integer!(Offset => i8);
+
+    #[test]
+    fn formats_newtype_integers_using_inner_value() {
+        assert_eq!("¹²", Superscript(Count(12)).to_string());
+        assert_eq!("𝍸𝍸𝍷𝍷", TallyMarks(Count(12)).to_string());
+        assert_eq!("⁻¹²⁸", Superscript(Offset(i8::MIN)).to_string());
+        assert_eq!(
+            Err(crate::OutOfRange {
+                value: Count(4000),
+                min: Count(1),
+                max: Count(3999)
+            }),
+            crate::Roman::new(Count(4000))
+        );
+    }
+
+    #[test]
+    fn scales_ratio_without_overflow() {
+        assert_eq!(0, scale_ratio(0_u8, 3, 100));
+        assert_eq!(33, scale_ratio(1_u8, 3, 100));
+        assert_eq!(67, scale_ratio(2_u8, 3, 100));
+        assert_eq!(50, scale_ratio(1_u8, 2, 100));
+        assert_eq!(100, scale_ratio(3_u8, 3, 100));
+        assert_eq!(4, scale_ratio(u128::MAX / 2, u128::MAX, 8));
+        assert_eq!(64, scale_ratio(254_u8, 255, 64));
+    }
+
+    #[test]
+    fn formats_saturating_integers_using_inner_value() {
+        assert_eq!(
+            "²⁵⁵",
+            Superscript(Saturating(u8::MAX) + Saturating(1)).to_string()
+        );
+        assert_eq!("𝍸𝍷", TallyMarks(Saturating(6_u64)).to_string());
+    }
+}
@@ -39,6 +39,8 @@
 //! assert_eq!("🯶🯲🯸", format!("{}", Segmented(628_u32)));
 //! ```
 //!
+//! Use [`SegmentedClock`] to format a time of day such as `🯱🯲:🯳🯴`.
+//!
 //! [Legacy Computing]: https://www.unicode.org/charts/PDF/U1FB00.pdf
 //!
 //! # Outlined
@@ -52,6 +54,14 @@
 //!
 //! [Legacy Computing Supplement]: https://www.unicode.org/charts/PDF/U1CC00.pdf
 //!
+//! # Turned Digits
+//! Formats an unsigned integer as if it was turned upside-down.
+//!
+//! ```
+//! # use fmtastic::Turned;
+//! assert_eq!("9ϛㄣ↋↊1", Turned(123456_u32).to_string());
+//! ```
+//!
 //! # Tally Marks
 //! Formats an unsigned integer as tally marks.
 //!
@@ -70,14 +80,83 @@
 //! assert_eq!("☐ Do the dishes", format!("{} Do the dishes", BallotBox(false)));
 //! assert_eq!("☒ Laundry", format!("{:#} Laundry", BallotBox(true)));
 //! ```
+//!
+//! # Balanced Ternary
+//! Formats integers in balanced ternary using the trits `T`, `0` and `1`.
+//!
+//! ```
+//! # use fmtastic::BalancedTernary;
+//! assert_eq!("1TT", BalancedTernary(5).to_string());
+//! ```
+//!
+//! # Sparklines
+//! Formats integers as block elements for drawing sparklines.
+//!
+//! ```
+//! # use fmtastic::{sparkline, Spark};
+//! assert_eq!("▅", Spark(50, 0, 100).to_string());
+//! assert_eq!("▁▃▅█▅", sparkline(&[1, 3, 5, 8, 5]).to_string());
+//! ```
+//!
+//! # Compact Numbers
+//! Formats large integers compactly using SI prefixes.
+//!
+//! ```
+//! # use fmtastic::SiCompact;
+//! assert_eq!("1.5k", SiCompact(1500).to_string());
+//! assert_eq!("3.4ᴹ", SiCompact(3_400_000).superscript().to_string());
+//! ```
+//!
+//! # Layout
+//! The formats wrapping a single value, such as [`Superscript`], [`Subscript`],
+//! [`Segmented`], [`Outlined`], [`TallyMarks`] or [`Plain`], are `#[repr(transparent)]`:
+//! They are guaranteed to have the same size, alignment and ABI as the wrapped value.
+//!
+//! # Crate Features
+//! This crate is `no_std` and has no default features.
+//!
+//! * `alloc`: Enables methods that return an allocated `String`,
+//!   such as `VulgarFraction::to_latex`, and formats that own their items,
+//!   such as `Checklist`.
+//! * `serde`: Implements `Serialize` and `Deserialize` for [`VulgarFraction`], [`Superscript`],
+//!   [`Subscript`], [`Roman`], [`BallotBox`], [`TallyMarks`], [`Segmented`] and [`Outlined`].
+//!   The numeric payload is serialized rather than the formatted glyphs, e.g. a [`Roman`]
+//!   numeral as its value. Deserializing a [`Roman`] numeral fails for values out of range.
+//! * `bytemuck`: Implements `Pod` and `Zeroable` for the formats wrapping a single value
+//!   (see [Layout](#layout)) whenever the wrapped value does, e.g. for `Superscript<u32>`.
+//!   [`BallotBox`] and [`Toggle`] only implement `Zeroable`.
+//!
+//! All formats implement [`Display`](core::fmt::Display), so `no_std` crates
+//! that use `alloc` can get a `String` from any format through the
+//! blanket implementation of `alloc::string::ToString`, no feature required:
+//!
+//! ```
+//! # extern crate alloc;
+//! use alloc::string::ToString;
+//! # use fmtastic::Superscript;
+//! assert_eq!("²", Superscript(2).to_string());
+//! ```
 
-#![forbid(unsafe_code)]
+#![cfg_attr(not(feature = "bytemuck"), forbid(unsafe_code))]
+#![cfg_attr(feature = "bytemuck", deny(unsafe_code))]
 #![warn(missing_docs)]
 #![cfg_attr(not(test), no_std)]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 /// An abstraction over all integer types.
 /// Integers can be formatted as [`Subscript`], [`Subscript`] or [`VulgarFraction`].
 ///
+/// This trait is implemented for all primitive integer types as well as for
+/// [`Wrapping`](core::num::Wrapping) and [`Saturating`](core::num::Saturating),
+/// which are formatted using the contained value.
+/// Newtypes around integers can implement this trait using [`impl_fmtastic_integer`].
+///
+/// The formats do not depend on the target's pointer width:
+/// `usize` and `isize` behave like the fixed-size integer of the same width,
+/// including on targets where they are only 16 bits wide.
+///
 /// Use this trait if you want to abstract over integers that can be formatted
 /// by one of this crate's formats:
 ///
@@ -91,7 +170,6 @@
 ///     format!("x{}", Subscript(index))
 /// }
 /// ```
-#[allow(private_bounds)]
 pub trait Integer: ToIntegerImpl + Copy {}
 
 /// Abstraction over signed integer types.
@@ -99,17 +177,31 @@ pub trait SignedInteger: Integer {}
 
 /// Abstraction over unsigned integer types.
 /// Unsigned integers can be formatted as [`Segmented`] or [`TallyMarks`].
-#[allow(private_bounds)]
 pub trait UnsignedInteger: Integer + ToUnsignedIntegerImpl {}
 
-pub(crate) trait ToIntegerImpl {
-    type Impl: crate::integer::IntegerImpl<Public = Self>;
+pub(crate) use __private::{AtMost64Bits, ToIntegerImpl, ToUnsignedIntegerImpl};
 
-    fn into_impl(self) -> Self::Impl;
-}
+/// Implementation details used by [`impl_fmtastic_integer`], not part of the public API.
+#[doc(hidden)]
+pub mod __private {
+    #[allow(private_bounds)]
+    pub trait ToIntegerImpl {
+        type Impl: crate::integer::IntegerImpl;
+
+        fn into_impl(self) -> Self::Impl;
+
+        fn from_impl(value: Self::Impl) -> Self;
+    }
+
+    #[allow(private_bounds)]
+    pub trait ToUnsignedIntegerImpl: ToIntegerImpl<Impl = Self::UnsignedImpl> {
+        type UnsignedImpl: crate::integer::UnsignedIntegerImpl;
+    }
 
-pub(crate) trait ToUnsignedIntegerImpl: ToIntegerImpl<Impl = Self::UnsignedImpl> {
-    type UnsignedImpl: integer::UnsignedIntegerImpl<Public = Self>;
+    /// Unsigned integers of at most 64 bits, which can be converted to `u64` without loss.
+    pub trait AtMost64Bits {
+        fn to_u64(self) -> u64;
+    }
 }
 
 mod sub_superscript;
@@ -127,8 +219,82 @@ mod roman;
 pub use roman::*;
 mod outlined;
 pub use outlined::*;
+mod turned;
+pub use turned::*;
+mod out_of_range;
+pub use out_of_range::*;
+mod matrix;
+pub use matrix::*;
+mod unit_exponent;
+pub use unit_exponent::*;
+mod words;
+pub use words::*;
+mod enclosed;
+pub use enclosed::*;
+mod plain;
+pub use plain::*;
+mod spark;
+pub use spark::*;
+mod balanced_ternary;
+pub use balanced_ternary::*;
+mod dingbat;
+pub use dingbat::*;
+mod chemical_formula;
+pub use chemical_formula::*;
+mod si_compact;
+pub use si_compact::*;
+mod mixed;
+pub use mixed::*;
+mod histogram;
+pub use histogram::*;
+mod cjk_enclosed;
+pub use cjk_enclosed::*;
+mod dms;
+pub use dms::*;
+mod toggle;
+pub use toggle::*;
+mod with_sign;
+pub use with_sign::*;
+mod recurring;
+pub use recurring::*;
+mod number;
+pub use number::*;
+mod daiji;
+pub use daiji::*;
+mod bounded;
+pub use bounded::*;
+mod prime_factorization;
+pub use prime_factorization::*;
+mod place_value;
+pub use place_value::*;
+mod status_light;
+pub use status_light::*;
+mod circled;
+pub use circled::*;
+mod parenthesized;
+pub use parenthesized::*;
+mod fullwidth;
+pub use fullwidth::*;
+mod double_struck;
+pub use double_struck::*;
+mod math_digits;
+pub use math_digits::*;
+mod script_str;
+pub use script_str::*;
+mod dice;
+pub use dice::*;
+mod cjk_numeral;
+pub use cjk_numeral::*;
+mod native_digits;
+pub use native_digits::*;
+mod ordinal;
+pub use ordinal::*;
+#[cfg(feature = "bytemuck")]
+mod pod;
 
 mod digits;
+mod float;
+pub use digits::{fmt_digits, fmt_with, Digits, ParseDigitError};
 
 #[doc = include_str!("../readme.md")]
 #[cfg(doctest)]
@@ -0,0 +1,186 @@
+use crate::digits::max_digits;
+use crate::integer::IntegerImpl;
+use crate::outlined::fmt_outlined;
+use crate::{Plain, UnsignedInteger};
+use core::fmt;
+
+macro_rules! math_digits {
+    ($(#[$meta:meta])* $name:ident, $digits:ident) => {
+        $(#[$meta])*
+        ///
+        /// ## Layout
+        #[doc = concat!("`", stringify!($name), "<T>` is `#[repr(transparent)]`, so it has the same size, alignment and ABI as `T`.")]
+        #[derive(Debug, Clone, Copy, Eq, PartialEq)]
+        #[repr(transparent)]
+        pub struct $name<T>(pub T);
+
+        impl<T> $name<T> {
+            /// Returns a reference to the wrapped value.
+            #[must_use]
+            pub fn as_inner(&self) -> &T {
+                &self.0
+            }
+
+            /// Applies a function to the wrapped value.
+            ///
+            /// ```
+            #[doc = concat!("# use fmtastic::", stringify!($name), ";")]
+            #[doc = concat!("assert_eq!(", stringify!($name), "(4), ", stringify!($name), "(3_u8).map(|n| n + 1));")]
+            /// ```
+            #[must_use]
+            pub fn map<U>(self, f: impl FnOnce(T) -> U) -> $name<U> {
+                $name(f(self.0))
+            }
+
+            /// Formats the wrapped value using its standard formatting,
+            /// ignoring the glyphs of this format. See [`Plain`].
+            #[must_use]
+            pub fn plain(self) -> Plain<T> {
+                Plain(self.0)
+            }
+        }
+
+        impl<T> From<T> for $name<T>
+        where
+            T: UnsignedInteger,
+        {
+            fn from(value: T) -> Self {
+                $name(value)
+            }
+        }
+
+        impl<T> $name<T>
+        where
+            T: UnsignedInteger,
+        {
+            /// The largest number of bytes written when formatting a value of type `T`
+            /// using any of the formatting traits, for sizing buffers.
+            ///
+            /// The longest output is the binary representation of the largest value,
+            /// with one digit per bit of `T`. Each digit is encoded in four bytes.
+            pub const MAX_LEN: usize = max_digits(<T::Impl as IntegerImpl>::BITS, 2) * 4;
+        }
+
+        impl<T> fmt::Binary for $name<T>
+        where
+            T: UnsignedInteger,
+        {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt_outlined::<_, <T::Impl as IntegerImpl>::BaseTwo>(self.0.into_impl(), &$digits, f)
+            }
+        }
+
+        impl<T> fmt::Display for $name<T>
+        where
+            T: UnsignedInteger,
+        {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt_outlined::<_, <T::Impl as IntegerImpl>::BaseTen>(self.0.into_impl(), &$digits, f)
+            }
+        }
+    };
+}
+
+math_digits!(
+    /// Formats an unsigned integer using the mathematical bold digits `𝟎` – `𝟗` (U+1D7CE – U+1D7D7)
+    /// from the [Mathematical Alphanumeric Symbols] block.
+    ///
+    /// [Mathematical Alphanumeric Symbols]: https://www.unicode.org/charts/PDF/U1D400.pdf
+    ///
+    /// ```
+    /// use fmtastic::MathBold;
+    ///
+    /// assert_eq!("𝟔𝟐𝟖", MathBold(628_u32).to_string());
+    /// assert_eq!("𝟏𝟎𝟏", format!("{:b}", MathBold(5_u8)));
+    /// ```
+    MathBold,
+    BOLD_DIGITS
+);
+
+math_digits!(
+    /// Formats an unsigned integer using the mathematical sans-serif digits `𝟢` – `𝟫` (U+1D7E2 – U+1D7EB)
+    /// from the [Mathematical Alphanumeric Symbols] block.
+    ///
+    /// [Mathematical Alphanumeric Symbols]: https://www.unicode.org/charts/PDF/U1D400.pdf
+    ///
+    /// ```
+    /// use fmtastic::MathSansSerif;
+    ///
+    /// assert_eq!("𝟨𝟤𝟪", MathSansSerif(628_u32).to_string());
+    /// assert_eq!("𝟣𝟢𝟣", format!("{:b}", MathSansSerif(5_u8)));
+    /// ```
+    MathSansSerif,
+    SANS_SERIF_DIGITS
+);
+
+math_digits!(
+    /// Formats an unsigned integer using the mathematical sans-serif bold digits `𝟬` – `𝟵` (U+1D7EC – U+1D7F5)
+    /// from the [Mathematical Alphanumeric Symbols] block.
+    ///
+    /// [Mathematical Alphanumeric Symbols]: https://www.unicode.org/charts/PDF/U1D400.pdf
+    ///
+    /// ```
+    /// use fmtastic::MathSansSerifBold;
+    ///
+    /// assert_eq!("𝟲𝟮𝟴", MathSansSerifBold(628_u32).to_string());
+    /// assert_eq!("𝟭𝟬𝟭", format!("{:b}", MathSansSerifBold(5_u8)));
+    /// ```
+    MathSansSerifBold,
+    SANS_SERIF_BOLD_DIGITS
+);
+
+math_digits!(
+    /// Formats an unsigned integer using the mathematical monospace digits `𝟶` – `𝟿` (U+1D7F6 – U+1D7FF)
+    /// from the [Mathematical Alphanumeric Symbols] block.
+    ///
+    /// [Mathematical Alphanumeric Symbols]: https://www.unicode.org/charts/PDF/U1D400.pdf
+    ///
+    /// ```
+    /// use fmtastic::MathMonospace;
+    ///
+    /// assert_eq!("𝟼𝟸𝟾", MathMonospace(628_u32).to_string());
+    /// assert_eq!("𝟷𝟶𝟷", format!("{:b}", MathMonospace(5_u8)));
+    /// ```
+    MathMonospace,
+    MONOSPACE_DIGITS
+);
+
+const BOLD_DIGITS: [&str; 10] = ["𝟎", "𝟏", "𝟐", "𝟑", "𝟒", "𝟓", "𝟔", "𝟕", "𝟖", "𝟗"];
+
+const SANS_SERIF_DIGITS: [&str; 10] = ["𝟢", "𝟣", "𝟤", "𝟥", "𝟦", "𝟧", "𝟨", "𝟩", "𝟪", "𝟫"];
+
+const SANS_SERIF_BOLD_DIGITS: [&str; 10] = ["𝟬", "𝟭", "𝟮", "𝟯", "𝟰", "𝟱", "𝟲", "𝟳", "𝟴", "𝟵"];
+
+const MONOSPACE_DIGITS: [&str; 10] = ["𝟶", "𝟷", "𝟸", "𝟹", "𝟺", "𝟻", "𝟼", "𝟽", "𝟾", "𝟿"];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn maps_digits_to_consecutive_code_points() {
+        for (first, digits) in [
+            (0x1D7CE, BOLD_DIGITS),
+            (0x1D7E2, SANS_SERIF_DIGITS),
+            (0x1D7EC, SANS_SERIF_BOLD_DIGITS),
+            (0x1D7F6, MONOSPACE_DIGITS),
+        ] {
+            for (offset, digit) in (0..).zip(digits) {
+                let mut chars = digit.chars();
+                assert_eq!(Some(first + offset), chars.next().map(u32::from));
+                assert_eq!(None, chars.next());
+            }
+        }
+    }
+
+    #[test]
+    fn formats_zero_and_extreme_values() {
+        assert_eq!("𝟎", MathBold(0_u8).to_string());
+        assert_eq!("𝟢", format!("{:b}", MathSansSerif(0_u8)));
+        assert_eq!("𝟮𝟱𝟱", MathSansSerifBold(u8::MAX).to_string());
+        assert_eq!(
+            MathMonospace::<u128>::MAX_LEN,
+            format!("{:b}", MathMonospace(u128::MAX)).len()
+        );
+    }
+}
@@ -0,0 +1,118 @@
+use crate::Subscript;
+use core::fmt;
+
+/// Formats the elements of a matrix using subscript indices, e.g. `a₁₁ a₁₂`.
+///
+/// Indices start at one. Each element consists of the symbol followed by
+/// the row and column index as [`Subscript`].
+/// By default, elements are separated by a single space,
+/// rows are separated by a newline (`\n`) and the row and column index are not separated.
+///
+/// ```
+/// # use fmtastic::SubscriptMatrix;
+/// assert_eq!("a₁₁ a₁₂\na₂₁ a₂₂", SubscriptMatrix::new("a", 2, 2).to_string());
+/// assert_eq!(
+///     "x₁,₁ | x₁,₂",
+///     SubscriptMatrix::new("x", 1, 2)
+///         .index_separator(",")
+///         .column_separator(" | ")
+///         .to_string()
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct SubscriptMatrix {
+    symbol: &'static str,
+    rows: usize,
+    columns: usize,
+    index_separator: &'static str,
+    column_separator: &'static str,
+    row_separator: &'static str,
+}
+
+impl SubscriptMatrix {
+    /// Creates a matrix with the given symbol and dimensions.
+    #[must_use]
+    pub fn new(symbol: &'static str, rows: usize, columns: usize) -> Self {
+        Self {
+            symbol,
+            rows,
+            columns,
+            index_separator: "",
+            column_separator: " ",
+            row_separator: "\n",
+        }
+    }
+
+    /// Sets the separator written between the row and the column index.
+    #[must_use]
+    pub fn index_separator(mut self, separator: &'static str) -> Self {
+        self.index_separator = separator;
+        self
+    }
+
+    /// Sets the separator written between two elements of a row.
+    #[must_use]
+    pub fn column_separator(mut self, separator: &'static str) -> Self {
+        self.column_separator = separator;
+        self
+    }
+
+    /// Sets the separator written between two rows.
+    #[must_use]
+    pub fn row_separator(mut self, separator: &'static str) -> Self {
+        self.row_separator = separator;
+        self
+    }
+}
+
+impl fmt::Display for SubscriptMatrix {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in 1..=self.rows {
+            if row > 1 {
+                f.write_str(self.row_separator)?;
+            }
+            for column in 1..=self.columns {
+                if column > 1 {
+                    f.write_str(self.column_separator)?;
+                }
+                write!(
+                    f,
+                    "{}{}{}{}",
+                    self.symbol,
+                    Subscript(row),
+                    self.index_separator,
+                    Subscript(column)
+                )?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn formats_two_by_two_matrix() {
+        assert_eq!(
+            "a₁₁ a₁₂\na₂₁ a₂₂",
+            SubscriptMatrix::new("a", 2, 2).to_string()
+        );
+    }
+
+    #[test]
+    fn formats_matrix_with_custom_separators() {
+        let matrix = SubscriptMatrix::new("m", 2, 3)
+            .index_separator(",")
+            .column_separator(", ")
+            .row_separator("; ");
+        assert_eq!("m₁,₁, m₁,₂, m₁,₃; m₂,₁, m₂,₂, m₂,₃", matrix.to_string());
+    }
+
+    #[test]
+    fn formats_empty_matrix() {
+        assert_eq!("", SubscriptMatrix::new("a", 0, 0).to_string());
+        assert_eq!("\n", SubscriptMatrix::new("a", 2, 0).to_string());
+    }
+}
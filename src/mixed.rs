@@ -0,0 +1,145 @@
+use crate::{Digits, Outlined, Plain, Segmented, Subscript, Superscript, UnsignedInteger};
+use core::fmt;
+
+/// Formats each decimal digit of an unsigned integer in a different [`DigitStyle`],
+/// cycling through the given styles.
+///
+/// The most significant digit uses the first style, the next digit the second style
+/// and so on. After the last style the rotation starts over with the first style.
+/// An empty slice of styles is rejected by [`Mixed::new`] with an [`EmptyStylesError`].
+///
+/// ```
+/// # use fmtastic::{DigitStyle, Mixed};
+/// let styles = [DigitStyle::Outlined, DigitStyle::Segmented];
+/// assert_eq!("𜳱🯲𜳳🯴", Mixed::new(1234_u32, &styles).unwrap().to_string());
+/// assert!(Mixed::new(1234_u32, &[]).is_err());
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct Mixed<'a, T>(T, &'a [DigitStyle]);
+
+/// The style of a single digit of a [`Mixed`] number.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum DigitStyle {
+    /// An ASCII digit, e.g. `1`. See [`Plain`].
+    Plain,
+    /// A superscript digit, e.g. `¹`. See [`Superscript`].
+    Superscript,
+    /// A subscript digit, e.g. `₁`. See [`Subscript`].
+    Subscript,
+    /// A seven-segment digit, e.g. `🯱`. See [`Segmented`].
+    Segmented,
+    /// An outlined digit, e.g. `𜳱`. See [`Outlined`].
+    Outlined,
+}
+
+impl<'a, T> Mixed<'a, T>
+where
+    T: UnsignedInteger,
+{
+    /// Creates a new [`Mixed`] number.
+    /// Returns an [`EmptyStylesError`] if `styles` is empty.
+    pub fn new(value: T, styles: &'a [DigitStyle]) -> Result<Self, EmptyStylesError> {
+        if styles.is_empty() {
+            Err(EmptyStylesError(()))
+        } else {
+            Ok(Mixed(value, styles))
+        }
+    }
+}
+
+impl<'a, T> Mixed<'a, T> {
+    /// Returns a reference to the wrapped value.
+    #[must_use]
+    pub fn as_inner(&self) -> &T {
+        &self.0
+    }
+
+    /// Returns the styles the digits cycle through.
+    #[must_use]
+    pub fn styles(&self) -> &'a [DigitStyle] {
+        self.1
+    }
+
+    /// Applies a function to the wrapped value, keeping the styles.
+    #[must_use]
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> Mixed<'a, U> {
+        Mixed(f(self.0), self.1)
+    }
+
+    /// Formats the wrapped value using its standard formatting,
+    /// ignoring the glyphs of this format. See [`Plain`].
+    #[must_use]
+    pub fn plain(self) -> Plain<T> {
+        Plain(self.0)
+    }
+}
+
+impl<T> fmt::Display for Mixed<'_, T>
+where
+    T: UnsignedInteger,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Digits::new(self.0)
+            .zip(self.1.iter().cycle())
+            .try_for_each(|(digit, style)| fmt_digit(digit as u8, *style, f))
+    }
+}
+
+fn fmt_digit(digit: u8, style: DigitStyle, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match style {
+        DigitStyle::Plain => write!(f, "{digit}"),
+        DigitStyle::Superscript => write!(f, "{}", Superscript(digit)),
+        DigitStyle::Subscript => write!(f, "{}", Subscript(digit)),
+        DigitStyle::Segmented => write!(f, "{}", Segmented(digit)),
+        DigitStyle::Outlined => write!(f, "{}", Outlined(digit)),
+    }
+}
+
+/// An error which is returned when creating a [`Mixed`] number without any styles.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct EmptyStylesError(());
+
+impl fmt::Display for EmptyStylesError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("expected at least one digit style")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn alternates_styles_across_digits() {
+        let styles = [DigitStyle::Outlined, DigitStyle::Segmented];
+        assert_eq!("𜳱🯲𜳳🯴𜳵", Mixed::new(12345_u32, &styles).unwrap().to_string());
+        let styles = [
+            DigitStyle::Plain,
+            DigitStyle::Superscript,
+            DigitStyle::Subscript,
+        ];
+        assert_eq!(
+            "1²₃4⁵₆7",
+            Mixed::new(1234567_u32, &styles).unwrap().to_string()
+        );
+    }
+
+    #[test]
+    fn formats_zero_and_single_style() {
+        let styles = [DigitStyle::Segmented];
+        assert_eq!("🯰", Mixed::new(0_u8, &styles).unwrap().to_string());
+        assert_eq!("🯱🯰🯰", Mixed::new(100_u8, &styles).unwrap().to_string());
+        assert_eq!(
+            "𜳳𜳴𜳰",
+            Mixed::new(340_u16, &[DigitStyle::Outlined])
+                .unwrap()
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn rejects_empty_styles() {
+        assert_eq!(Err(EmptyStylesError(())), Mixed::new(1_u32, &[]));
+    }
+}
@@ -0,0 +1,277 @@
+use crate::digits::{fmt_with_digit_table, max_digits};
+use crate::integer::IntegerImpl;
+use crate::{Integer, Plain, SignGlyphs};
+use core::fmt;
+
+macro_rules! native_digits {
+    ($(#[$meta:meta])* $name:ident, $digits:ident, $digit_len:literal) => {
+        $(#[$meta])*
+        ///
+        /// ## Formatting Flags
+        /// ### Sign: `+`
+        /// Use the `+` flag to always include the plus sign for positive numbers.
+        /// The ASCII signs are used.
+        ///
+        /// ## Layout
+        #[doc = concat!("`", stringify!($name), "<T>` is `#[repr(transparent)]`, so it has the same size, alignment and ABI as `T`.")]
+        #[derive(Debug, Clone, Copy, Eq, PartialEq)]
+        #[repr(transparent)]
+        pub struct $name<T>(pub T);
+
+        impl<T> $name<T> {
+            /// Returns a reference to the wrapped value.
+            #[must_use]
+            pub fn as_inner(&self) -> &T {
+                &self.0
+            }
+
+            /// Applies a function to the wrapped value.
+            ///
+            /// ```
+            #[doc = concat!("# use fmtastic::", stringify!($name), ";")]
+            #[doc = concat!("assert_eq!(", stringify!($name), "(4), ", stringify!($name), "(3_u8).map(|n| n + 1));")]
+            /// ```
+            #[must_use]
+            pub fn map<U>(self, f: impl FnOnce(T) -> U) -> $name<U> {
+                $name(f(self.0))
+            }
+
+            /// Formats the wrapped value using its standard formatting,
+            /// ignoring the glyphs of this format. See [`Plain`].
+            #[must_use]
+            pub fn plain(self) -> Plain<T> {
+                Plain(self.0)
+            }
+        }
+
+        impl<T> From<T> for $name<T>
+        where
+            T: Integer,
+        {
+            fn from(value: T) -> Self {
+                $name(value)
+            }
+        }
+
+        impl<T> $name<T>
+        where
+            T: Integer,
+        {
+            /// The largest number of bytes written when formatting a value of type `T`, for sizing buffers.
+            ///
+            /// The longest output is a sign followed by the decimal digits of the largest magnitude.
+            #[doc = concat!("The sign is encoded in one byte and each digit in ", stringify!($digit_len), " bytes.")]
+            ///
+            /// ```
+            #[doc = concat!("# use fmtastic::", stringify!($name), ";")]
+            #[doc = concat!("assert_eq!(1 + 3 * ", stringify!($digit_len), ", ", stringify!($name), "::<i8>::MAX_LEN);")]
+            /// ```
+            pub const MAX_LEN: usize = 1 + max_digits(<T::Impl as IntegerImpl>::BITS, 10) * $digit_len;
+        }
+
+        impl<T> fmt::Display for $name<T>
+        where
+            T: Integer,
+        {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt_with_digit_table(self.0.into_impl(), 10, &$digits, SignGlyphs::ASCII, f)
+            }
+        }
+    };
+}
+
+native_digits!(
+    /// Formats an integer using the Arabic-Indic digits `٠` – `٩` (U+0660 – U+0669)
+    /// from the [Arabic] block, as used in Arabic text.
+    ///
+    /// ## Right-to-left text
+    /// The digits are written in the usual order, most significant first.
+    /// They are laid out left to right even within right-to-left text,
+    /// but the position of the sign and of the number within its surroundings is determined
+    /// by the [Unicode Bidirectional Algorithm] of the application displaying the text.
+    /// E.g. in a right-to-left paragraph `-١٢` may be displayed with the sign to the right of the digits.
+    /// To keep the sign attached, isolate the number, e.g. between a first strong isolate (U+2068)
+    /// and a pop directional isolate (U+2069).
+    ///
+    /// [Arabic]: https://www.unicode.org/charts/PDF/U0600.pdf
+    /// [Unicode Bidirectional Algorithm]: https://www.unicode.org/reports/tr9/
+    ///
+    /// ```
+    /// use fmtastic::ArabicIndic;
+    ///
+    /// assert_eq!("٦٢٨", ArabicIndic(628_u32).to_string());
+    /// assert_eq!("-٤٢", ArabicIndic(-42).to_string());
+    /// assert_eq!("+٦٢٨", format!("{:+}", ArabicIndic(628)));
+    /// assert_eq!("\u{2068}-٤٢\u{2069}", format!("\u{2068}{}\u{2069}", ArabicIndic(-42)));
+    /// ```
+    ArabicIndic,
+    ARABIC_INDIC_DIGITS,
+    2
+);
+
+native_digits!(
+    /// Formats an integer using the extended Arabic-Indic digits `۰` – `۹` (U+06F0 – U+06F9)
+    /// from the [Arabic] block, as used in Persian and Urdu text.
+    ///
+    /// The digits four, five and six differ in shape from [`ArabicIndic`].
+    /// Fonts for Urdu may show further differences, e.g. for seven.
+    /// See [`ArabicIndic`] for how to display the sign within right-to-left text.
+    ///
+    /// [Arabic]: https://www.unicode.org/charts/PDF/U0600.pdf
+    ///
+    /// ```
+    /// use fmtastic::ExtendedArabicIndic;
+    ///
+    /// assert_eq!("۱۴۰۳", ExtendedArabicIndic(1403_u32).to_string());
+    /// assert_eq!("-۴۲", ExtendedArabicIndic(-42).to_string());
+    /// assert_eq!("+۵", format!("{:+}", ExtendedArabicIndic(5)));
+    /// ```
+    ExtendedArabicIndic,
+    EXTENDED_ARABIC_INDIC_DIGITS,
+    2
+);
+
+native_digits!(
+    /// Formats an integer using the Devanagari digits `०` – `९` (U+0966 – U+096F)
+    /// from the [Devanagari] block, as used for Hindi, Marathi and Nepali.
+    ///
+    /// [Devanagari]: https://www.unicode.org/charts/PDF/U0900.pdf
+    ///
+    /// ```
+    /// use fmtastic::Devanagari;
+    ///
+    /// assert_eq!("६२८", Devanagari(628_u32).to_string());
+    /// assert_eq!("-४२", Devanagari(-42).to_string());
+    /// assert_eq!("+४२", format!("{:+}", Devanagari(42)));
+    /// ```
+    Devanagari,
+    DEVANAGARI_DIGITS,
+    3
+);
+
+native_digits!(
+    /// Formats an integer using the Bengali digits `০` – `৯` (U+09E6 – U+09EF)
+    /// from the [Bengali] block, as used for Bengali and Assamese.
+    ///
+    /// [Bengali]: https://www.unicode.org/charts/PDF/U0980.pdf
+    ///
+    /// ```
+    /// use fmtastic::Bengali;
+    ///
+    /// assert_eq!("৬২৮", Bengali(628_u32).to_string());
+    /// assert_eq!("-৪২", Bengali(-42).to_string());
+    /// assert_eq!("+৪২", format!("{:+}", Bengali(42)));
+    /// ```
+    Bengali,
+    BENGALI_DIGITS,
+    3
+);
+
+native_digits!(
+    /// Formats an integer using the Thai digits `๐` – `๙` (U+0E50 – U+0E59)
+    /// from the [Thai] block.
+    ///
+    /// [Thai]: https://www.unicode.org/charts/PDF/U0E00.pdf
+    ///
+    /// ```
+    /// use fmtastic::Thai;
+    ///
+    /// assert_eq!("๖๒๘", Thai(628_u32).to_string());
+    /// assert_eq!("-๔๒", Thai(-42).to_string());
+    /// assert_eq!("+๔๒", format!("{:+}", Thai(42)));
+    /// ```
+    Thai,
+    THAI_DIGITS,
+    3
+);
+
+const ARABIC_INDIC_DIGITS: [&str; 10] = ["٠", "١", "٢", "٣", "٤", "٥", "٦", "٧", "٨", "٩"];
+
+const EXTENDED_ARABIC_INDIC_DIGITS: [&str; 10] = ["۰", "۱", "۲", "۳", "۴", "۵", "۶", "۷", "۸", "۹"];
+
+const DEVANAGARI_DIGITS: [&str; 10] = ["०", "१", "२", "३", "४", "५", "६", "७", "८", "९"];
+
+const BENGALI_DIGITS: [&str; 10] = ["০", "১", "২", "৩", "৪", "৫", "৬", "৭", "৮", "৯"];
+
+const THAI_DIGITS: [&str; 10] = ["๐", "๑", "๒", "๓", "๔", "๕", "๖", "๗", "๘", "๙"];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn remap(s: String, zero: u32) -> String {
+        s.chars()
+            .map(|c| match c.to_digit(10) {
+                Some(digit) => char::from_u32(zero + digit).unwrap(),
+                None => c,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn maps_digits_to_consecutive_code_points() {
+        for (zero, digits) in [
+            (0x0660, ARABIC_INDIC_DIGITS),
+            (0x06F0, EXTENDED_ARABIC_INDIC_DIGITS),
+            (0x0966, DEVANAGARI_DIGITS),
+            (0x09E6, BENGALI_DIGITS),
+            (0x0E50, THAI_DIGITS),
+        ] {
+            for (offset, digit) in (0..).zip(digits) {
+                let mut chars = digit.chars();
+                assert_eq!(Some(zero + offset), chars.next().map(u32::from));
+                assert_eq!(None, chars.next());
+            }
+        }
+    }
+
+    #[test]
+    fn formats_multi_digit_numbers() {
+        assert_eq!("१२३४५६७८९०", Devanagari(1_234_567_890_u32).to_string());
+        assert_eq!("১২৩৪৫৬৭৮৯০", Bengali(1_234_567_890_u32).to_string());
+        assert_eq!("๑๒๓๔๕๖๗๘๙๐", Thai(1_234_567_890_u32).to_string());
+        assert_eq!("-๒๕๖๖", Thai(-2566).to_string());
+        assert_eq!("०", Devanagari(0_u8).to_string());
+    }
+
+    #[test]
+    fn output_never_exceeds_max_len() {
+        assert_eq!(
+            Devanagari::<i128>::MAX_LEN,
+            Devanagari(i128::MIN).to_string().len()
+        );
+        assert!((i8::MIN..=i8::MAX).all(|n| {
+            [Bengali(n).to_string(), format!("{:+}", Thai(n))]
+                .iter()
+                .all(|s| s.len() <= Thai::<i8>::MAX_LEN)
+        }));
+    }
+
+    #[test]
+    fn matches_standard_formatting() {
+        for n in i16::MIN..=i16::MAX {
+            assert_eq!(remap(n.to_string(), 0x0660), ArabicIndic(n).to_string());
+            assert_eq!(
+                remap(format!("{n:+}"), 0x06F0),
+                format!("{:+}", ExtendedArabicIndic(n))
+            );
+        }
+    }
+
+    #[test]
+    fn formats_extreme_values() {
+        assert_eq!("٠", ArabicIndic(0_u8).to_string());
+        assert_eq!(
+            remap(i128::MIN.to_string(), 0x0660),
+            ArabicIndic(i128::MIN).to_string()
+        );
+        assert_eq!(
+            ArabicIndic::<i128>::MAX_LEN,
+            ArabicIndic(i128::MIN).to_string().len()
+        );
+        assert_eq!(
+            ExtendedArabicIndic::<u64>::MAX_LEN - 1,
+            ExtendedArabicIndic(u64::MAX).to_string().len()
+        );
+    }
+}
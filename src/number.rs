@@ -0,0 +1,231 @@
+use crate::integer::{IntegerImpl, Sign};
+use crate::outlined::{fmt_outlined, DIGITS as OUTLINED_DIGITS};
+use crate::seven_segment::fmt_seven_segment;
+use crate::tally_marks::fmt_tally_marks;
+use crate::turned::fmt_turned;
+use crate::{BalancedTernary, Integer, Plain, Roman, Subscript, Superscript, Words};
+use core::fmt;
+
+/// Formats an integer in a [`NumberFormat`] chosen at runtime,
+/// e.g. from a configuration file.
+///
+/// The [`Display`](fmt::Display) implementation dispatches to the formatter of the chosen format,
+/// so `Number(n, NumberFormat::Superscript)` is formatted exactly like `Superscript(n)`,
+/// including all formatting flags supported by that format.
+///
+/// Some formats can only represent some values: [`NumberFormat::Roman`] is limited
+/// to the values from 1 to 3999 and [`NumberFormat::Segmented`], [`NumberFormat::Outlined`],
+/// [`NumberFormat::TallyMarks`] and [`NumberFormat::Turned`] cannot represent negative values.
+/// Values which the chosen format cannot represent are formatted as [`NumberFormat::Decimal`] instead.
+/// Use [`Number::is_supported`] to check this upfront.
+///
+/// ```
+/// # use fmtastic::{Number, NumberFormat};
+/// assert_eq!("¹²", Number(12, NumberFormat::Superscript).to_string());
+/// assert_eq!("ⅩⅠⅠ", Number(12, NumberFormat::Roman).to_string());
+/// assert_eq!("𝍸𝍸𝍷𝍷", Number(12_u8, NumberFormat::TallyMarks).to_string());
+///
+/// // Negative values cannot be formatted as tally marks
+/// assert_eq!("-12", Number(-12, NumberFormat::TallyMarks).to_string());
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct Number<T>(pub T, pub NumberFormat);
+
+/// The format of a [`Number`].
+///
+/// | Format                                   | Type                | Negative values | 12       |
+/// |------------------------------------------|---------------------|-----------------|----------|
+/// | [`NumberFormat::Decimal`] (default)      | —                   | yes             | `12`     |
+/// | [`NumberFormat::Superscript`]            | [`Superscript`]     | yes             | `¹²`     |
+/// | [`NumberFormat::Subscript`]              | [`Subscript`]       | yes             | `₁₂`     |
+/// | [`NumberFormat::Words`]                  | [`Words`]           | yes             | `twelve` |
+/// | [`NumberFormat::BalancedTernary`]        | [`BalancedTernary`] | yes             | `110`    |
+/// | [`NumberFormat::Roman`]                  | [`Roman`]           | no (1 to 3999)  | `ⅩⅠⅠ`    |
+/// | [`NumberFormat::Segmented`]              | [`Segmented`]       | no              | `🯱🯲`     |
+/// | [`NumberFormat::Outlined`]               | [`Outlined`]        | no              | `𜳱𜳲`     |
+/// | [`NumberFormat::TallyMarks`]             | [`TallyMarks`]      | no              | `𝍸𝍸𝍷𝍷`   |
+/// | [`NumberFormat::Turned`]                 | [`Turned`]          | no              | `↊1`     |
+///
+/// [`Segmented`]: crate::Segmented
+/// [`Outlined`]: crate::Outlined
+/// [`TallyMarks`]: crate::TallyMarks
+/// [`Turned`]: crate::Turned
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+#[non_exhaustive]
+pub enum NumberFormat {
+    /// The standard formatting of the integer, see [`Plain`].
+    #[default]
+    Decimal,
+    /// See [`Superscript`].
+    Superscript,
+    /// See [`Subscript`].
+    Subscript,
+    /// See [`Words`].
+    Words,
+    /// See [`BalancedTernary`].
+    BalancedTernary,
+    /// See [`Roman`].
+    Roman,
+    /// See [`Segmented`](crate::Segmented).
+    Segmented,
+    /// See [`Outlined`](crate::Outlined).
+    Outlined,
+    /// See [`TallyMarks`](crate::TallyMarks).
+    TallyMarks,
+    /// See [`Turned`](crate::Turned).
+    Turned,
+}
+
+impl<T> Number<T> {
+    /// Returns a reference to the wrapped value.
+    #[must_use]
+    pub fn as_inner(&self) -> &T {
+        &self.0
+    }
+
+    /// Formats the wrapped value using its standard formatting,
+    /// ignoring the chosen format. See [`Plain`].
+    #[must_use]
+    pub fn plain(self) -> Plain<T> {
+        Plain(self.0)
+    }
+}
+
+impl<T> Number<T>
+where
+    T: Integer,
+{
+    /// Returns `true` if the chosen format can represent the value
+    /// and `false` if the value is formatted as [`NumberFormat::Decimal`] instead.
+    ///
+    /// ```
+    /// # use fmtastic::{Number, NumberFormat};
+    /// assert!(Number(4000_u16, NumberFormat::Superscript).is_supported());
+    /// assert!(!Number(4000_u16, NumberFormat::Roman).is_supported());
+    /// assert!(!Number(-1, NumberFormat::Segmented).is_supported());
+    /// ```
+    #[must_use]
+    pub fn is_supported(&self) -> bool {
+        let n = self.0.into_impl();
+        match self.1 {
+            NumberFormat::Decimal
+            | NumberFormat::Superscript
+            | NumberFormat::Subscript
+            | NumberFormat::Words
+            | NumberFormat::BalancedTernary => true,
+            NumberFormat::Roman => {
+                n > T::Impl::ZERO && T::Impl::try_from(3999).map_or(true, |max| n <= max)
+            }
+            NumberFormat::Segmented
+            | NumberFormat::Outlined
+            | NumberFormat::TallyMarks
+            | NumberFormat::Turned => matches!(n.sign(), Sign::PositiveOrZero),
+        }
+    }
+}
+
+impl<T> fmt::Display for Number<T>
+where
+    T: Integer,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let n = self.0.into_impl();
+        if !self.is_supported() {
+            return fmt::Display::fmt(&n, f);
+        }
+        match self.1 {
+            NumberFormat::Decimal => fmt::Display::fmt(&n, f),
+            NumberFormat::Superscript => fmt::Display::fmt(&Superscript(self.0), f),
+            NumberFormat::Subscript => fmt::Display::fmt(&Subscript(self.0), f),
+            NumberFormat::Words => fmt::Display::fmt(&Words(self.0), f),
+            NumberFormat::BalancedTernary => fmt::Display::fmt(&BalancedTernary(self.0), f),
+            NumberFormat::Roman => match Roman::new(n.as_usize() as u16) {
+                Ok(roman) => fmt::Display::fmt(&roman, f),
+                Err(_) => unreachable!("the value has been checked to be in range"),
+            },
+            NumberFormat::Segmented => {
+                fmt_seven_segment::<_, <T::Impl as IntegerImpl>::BaseTen>(n, f)
+            }
+            NumberFormat::Outlined => {
+                fmt_outlined::<_, <T::Impl as IntegerImpl>::BaseTen>(n, &OUTLINED_DIGITS, f)
+            }
+            NumberFormat::TallyMarks => fmt_tally_marks(n, f),
+            NumberFormat::Turned => fmt_turned::<_, <T::Impl as IntegerImpl>::BaseTen>(n, f),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Outlined, Segmented, TallyMarks, Turned};
+
+    #[test]
+    fn dispatches_to_formats() {
+        for (expected, format) in [
+            ("12", NumberFormat::Decimal),
+            ("¹²", NumberFormat::Superscript),
+            ("₁₂", NumberFormat::Subscript),
+            ("twelve", NumberFormat::Words),
+            ("110", NumberFormat::BalancedTernary),
+            ("ⅩⅠⅠ", NumberFormat::Roman),
+            ("🯱🯲", NumberFormat::Segmented),
+            ("\u{1CCF1}\u{1CCF2}", NumberFormat::Outlined),
+            ("𝍸𝍸𝍷𝍷", NumberFormat::TallyMarks),
+            ("↊1", NumberFormat::Turned),
+        ] {
+            assert_eq!(expected, Number(12_u32, format).to_string());
+            assert_eq!(expected, Number(12_i64, format).to_string());
+        }
+    }
+
+    #[test]
+    fn matches_wrapped_formats() {
+        for n in 0..=u8::MAX {
+            assert_eq!(
+                Segmented(n).to_string(),
+                Number(n, NumberFormat::Segmented).to_string()
+            );
+            assert_eq!(
+                Outlined(n).to_string(),
+                Number(n, NumberFormat::Outlined).to_string()
+            );
+            assert_eq!(
+                TallyMarks(n).to_string(),
+                Number(n, NumberFormat::TallyMarks).to_string()
+            );
+            assert_eq!(
+                Turned(n).to_string(),
+                Number(n, NumberFormat::Turned).to_string()
+            );
+        }
+        for n in i8::MIN..=i8::MAX {
+            assert_eq!(
+                format!("{:+#}", Superscript(n)),
+                format!("{:+#}", Number(n, NumberFormat::Superscript))
+            );
+            assert_eq!(
+                format!("{:#}", Words(n)),
+                format!("{:#}", Number(n, NumberFormat::Words))
+            );
+        }
+        assert_eq!("ⅿⅿⅹⅹⅰⅴ", format!("{:#}", Number(2024, NumberFormat::Roman)));
+    }
+
+    #[test]
+    fn falls_back_to_decimal() {
+        assert_eq!("-5", Number(-5, NumberFormat::TallyMarks).to_string());
+        assert_eq!("-5", Number(-5_i8, NumberFormat::Segmented).to_string());
+        assert_eq!("0", Number(0, NumberFormat::Roman).to_string());
+        assert_eq!("4000", Number(4000, NumberFormat::Roman).to_string());
+        assert_eq!(
+            "+0004000",
+            format!("{:+08}", Number(4000, NumberFormat::Roman))
+        );
+        assert_eq!("ⅭⅩⅩⅤⅠⅠ", Number(i8::MAX, NumberFormat::Roman).to_string());
+        assert_eq!(
+            u128::MAX.to_string(),
+            Number(u128::MAX, NumberFormat::Roman).to_string()
+        );
+    }
+}
@@ -0,0 +1,200 @@
+use crate::digits::{iter_digits_rev, max_digits};
+use crate::integer::IntegerImpl;
+use crate::{Integer, Plain, SuperscriptStr, WithSign};
+use core::fmt;
+
+/// Formats an integer with its English ordinal suffix, e.g. `1st`, `2nd`, `3rd` or `4th`.
+///
+/// The suffix is determined by the last two digits: Numbers ending in 11, 12 and 13 get `th`,
+/// all other numbers ending in 1, 2 and 3 get `st`, `nd` and `rd` respectively,
+/// and all remaining numbers get `th`.
+/// Negative numbers use the suffix of their magnitude, e.g. `-1st`.
+/// See [`Words`](crate::Words) for ordinals written as words.
+///
+/// ```
+/// # use fmtastic::Ordinal;
+/// assert_eq!("1st", Ordinal(1).to_string());
+/// assert_eq!("2nd", Ordinal(2).to_string());
+/// assert_eq!("3rd", Ordinal(3).to_string());
+/// assert_eq!("4th", Ordinal(4).to_string());
+/// assert_eq!("11th", Ordinal(11).to_string());
+/// assert_eq!("101st", Ordinal(101).to_string());
+/// assert_eq!("-22nd", Ordinal(-22).to_string());
+/// ```
+///
+/// ## Formatting Flags
+/// ### Sign: `+`
+/// Use the `+` flag to always include the plus sign for positive numbers.
+///
+/// ```
+/// # use fmtastic::Ordinal;
+/// assert_eq!("+1st", format!("{:+}", Ordinal(1)));
+/// ```
+///
+/// ### Alternate `#`
+/// The alternate flag `#` writes the suffix in superscript, see [`SuperscriptStr`].
+///
+/// ```
+/// # use fmtastic::Ordinal;
+/// assert_eq!("1ˢᵗ", format!("{:#}", Ordinal(1)));
+/// assert_eq!("42ⁿᵈ", format!("{:#}", Ordinal(42)));
+/// ```
+///
+/// ## Layout
+/// `Ordinal<T>` is `#[repr(transparent)]`, so it has the same size, alignment and ABI as `T`.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct Ordinal<T>(pub T);
+
+impl<T> Ordinal<T> {
+    /// Returns a reference to the wrapped value.
+    #[must_use]
+    pub fn as_inner(&self) -> &T {
+        &self.0
+    }
+
+    /// Applies a function to the wrapped value.
+    ///
+    /// ```
+    /// # use fmtastic::Ordinal;
+    /// assert_eq!(Ordinal(4), Ordinal(3_u8).map(|n| n + 1));
+    /// ```
+    #[must_use]
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> Ordinal<U> {
+        Ordinal(f(self.0))
+    }
+
+    /// Formats the wrapped value using its standard formatting,
+    /// ignoring the suffix of this format. See [`Plain`].
+    #[must_use]
+    pub fn plain(self) -> Plain<T> {
+        Plain(self.0)
+    }
+}
+
+impl<T> From<T> for Ordinal<T>
+where
+    T: Integer,
+{
+    fn from(value: T) -> Self {
+        Ordinal(value)
+    }
+}
+
+impl<T> Ordinal<T>
+where
+    T: Integer,
+{
+    /// The largest number of bytes written when formatting a value of type `T`, for sizing buffers.
+    ///
+    /// The longest output is a sign followed by the decimal digits of the largest magnitude
+    /// and the suffix in superscript, which is encoded in at most six bytes (`ⁿᵈ`).
+    ///
+    /// ```
+    /// # use fmtastic::Ordinal;
+    /// assert_eq!(1 + 3 + 6, Ordinal::<i8>::MAX_LEN);
+    /// ```
+    pub const MAX_LEN: usize = 1 + max_digits(<T::Impl as IntegerImpl>::BITS, 10) + 6;
+
+    /// Returns the ordinal suffix of the value: `st`, `nd`, `rd` or `th`.
+    ///
+    /// ```
+    /// # use fmtastic::Ordinal;
+    /// assert_eq!("rd", Ordinal(23).suffix());
+    /// assert_eq!("th", Ordinal(113).suffix());
+    /// ```
+    #[must_use]
+    pub fn suffix(&self) -> &'static str {
+        let mut digits =
+            iter_digits_rev::<_, <T::Impl as IntegerImpl>::BaseTen>(self.0.into_impl());
+        match (digits.next(), digits.next()) {
+            (_, Some(1)) => "th",
+            (Some(1), _) => "st",
+            (Some(2), _) => "nd",
+            (Some(3), _) => "rd",
+            _ => "th",
+        }
+    }
+}
+
+impl<T> fmt::Display for Ordinal<T>
+where
+    T: Integer,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let n = self.0.into_impl();
+        fmt::Display::fmt(&WithSign::from_impl(n, n.unsigned_abs()), f)?;
+        if f.alternate() {
+            write!(f, "{}", SuperscriptStr(self.suffix()))
+        } else {
+            f.write_str(self.suffix())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn uses_th_for_eleven_to_thirteen() {
+        for n in [11, 12, 13, 111, 212, 1013, -11, -112] {
+            assert_eq!(format!("{n}th"), Ordinal(n).to_string());
+        }
+    }
+
+    #[test]
+    fn uses_suffix_of_last_digit() {
+        for (expected, input) in [
+            ("0th", 0),
+            ("1st", 1),
+            ("2nd", 2),
+            ("3rd", 3),
+            ("4th", 4),
+            ("10th", 10),
+            ("21st", 21),
+            ("22nd", 22),
+            ("23rd", 23),
+            ("100th", 100),
+            ("101st", 101),
+            ("1002nd", 1002),
+            ("-1st", -1),
+            ("-3rd", -3),
+        ] {
+            assert_eq!(expected, Ordinal(input).to_string());
+        }
+    }
+
+    #[test]
+    fn formats_superscript_suffix() {
+        assert_eq!("3ʳᵈ", format!("{:#}", Ordinal(3_u8)));
+        assert_eq!("11ᵗʰ", format!("{:#}", Ordinal(11_u8)));
+        assert_eq!("-2ⁿᵈ", format!("{:#}", Ordinal(-2)));
+    }
+
+    #[test]
+    fn formats_sign_with_plus_flag() {
+        for (expected, input) in [("+0th", 0), ("+1st", 1), ("+12th", 12), ("-23rd", -23)] {
+            assert_eq!(expected, format!("{:+}", Ordinal(input)));
+        }
+        assert_eq!("+2ⁿᵈ", format!("{:+#}", Ordinal(2_u8)));
+        assert_eq!("-128th", format!("{:+}", Ordinal(i8::MIN)));
+    }
+
+    #[test]
+    fn output_never_exceeds_max_len() {
+        assert!((i8::MIN..=i8::MAX).all(|n| {
+            [Ordinal(n).to_string(), format!("{:#}", Ordinal(n))]
+                .iter()
+                .all(|s| s.len() <= Ordinal::<i8>::MAX_LEN)
+        }));
+        assert_eq!(
+            "170141183460469231731687303715884105727th",
+            Ordinal(i128::MAX).to_string()
+        );
+        assert_eq!(
+            "340282366920938463463374607431768211455th",
+            Ordinal(u128::MAX).to_string()
+        );
+    }
+}
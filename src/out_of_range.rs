@@ -0,0 +1,34 @@
+use core::fmt;
+
+/// The error returned when constructing a format for a value
+/// that the format cannot represent.
+///
+/// ```
+/// # use fmtastic::{OutOfRange, Roman};
+/// assert_eq!(
+///     Err(OutOfRange { value: 4000, min: 1, max: 3999 }),
+///     Roman::new(4000_u16)
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct OutOfRange<T> {
+    /// The value that is out of range.
+    pub value: T,
+    /// The smallest value accepted by the format.
+    pub min: T,
+    /// The largest value accepted by the format.
+    pub max: T,
+}
+
+impl<T> fmt::Display for OutOfRange<T>
+where
+    T: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} is out of range, expected a value between {} and {}",
+            self.value, self.min, self.max
+        )
+    }
+}
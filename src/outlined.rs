@@ -1,6 +1,6 @@
-use crate::digits::iter_digits;
+use crate::digits::{digit_from_char, fmt_with, max_digits};
 use crate::integer::{Base, IntegerImpl};
-use crate::UnsignedInteger;
+use crate::{Enclosed, Enclosure, ParseDigitError, Plain, UnsignedInteger};
 use core::fmt;
 
 /// Formats an unsigned integer using outlined digits
@@ -34,7 +34,18 @@ use core::fmt;
 /// // Hexadecimal
 /// assert_eq!("𜳱𜳘𜳘𜳛𜳰", format!("{:X}", Outlined(0x1CCF0_u32)));
 /// ```
+///
+/// ## Lowercase Hexadecimal
+/// There are no outlined lowercase letters, so [`LowerHex`](fmt::LowerHex)
+/// uses the same outlined uppercase letters as [`UpperHex`](fmt::UpperHex).
+/// The [ASCII fallback](Outlined::ascii_fallback) uses lowercase letters, e.g. `[a]`.
+///
+/// ## Layout
+/// `Outlined<T>` is `#[repr(transparent)]`, so it has the same size, alignment and ABI as `T`.
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+#[repr(transparent)]
 pub struct Outlined<T>(pub T);
 
 impl<T> From<T> for Outlined<T>
@@ -46,12 +57,100 @@ where
     }
 }
 
+/// Converts an ASCII digit (`'0'` to `'9'`) to a single digit.
+///
+/// ## Errors
+/// Returns a [`ParseDigitError`] if the character is not an ASCII digit.
+///
+/// ```
+/// # use fmtastic::Outlined;
+/// assert_eq!("𜳷", Outlined::<u8>::try_from('7').unwrap().to_string());
+/// assert!(Outlined::<u8>::try_from('x').is_err());
+/// ```
+impl<T> TryFrom<char> for Outlined<T>
+where
+    T: UnsignedInteger,
+{
+    type Error = ParseDigitError;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        digit_from_char(value).map(Outlined)
+    }
+}
+
+impl<T> Outlined<T> {
+    /// Returns a reference to the wrapped value.
+    #[must_use]
+    pub fn as_inner(&self) -> &T {
+        &self.0
+    }
+
+    /// Applies a function to the wrapped value.
+    ///
+    /// ```
+    /// # use fmtastic::Outlined;
+    /// assert_eq!(Outlined(4), Outlined(3_u8).map(|n| n + 1));
+    /// ```
+    #[must_use]
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> Outlined<U> {
+        Outlined(f(self.0))
+    }
+
+    /// Formats the wrapped value using its standard formatting,
+    /// ignoring the glyphs of this format. See [`Plain`].
+    #[must_use]
+    pub fn plain(self) -> Plain<T> {
+        Plain(self.0)
+    }
+
+    /// Appends a combining enclosing mark after each digit, see [`Enclosed`].
+    #[must_use]
+    pub fn enclosed(self, enclosure: Enclosure) -> Enclosed<Self> {
+        Enclosed(self, enclosure)
+    }
+
+    /// Uses plain ASCII digits enclosed in square brackets (e.g. `[6]`)
+    /// instead of the outlined digits, for environments where no font
+    /// supporting the [Legacy Computing Supplement] block is available.
+    ///
+    /// [Legacy Computing Supplement]: https://www.unicode.org/charts/PDF/U1CC00.pdf
+    ///
+    /// ```
+    /// use fmtastic::Outlined;
+    ///
+    /// assert_eq!("[6][2][8]", Outlined(628_u32).ascii_fallback().to_string());
+    /// assert_eq!("[1][0][1]", format!("{:b}", Outlined(0b101_u8).ascii_fallback()));
+    /// assert_eq!("[1][C][C][F][0]", format!("{:X}", Outlined(0x1CCF0_u32).ascii_fallback()));
+    /// ```
+    #[must_use]
+    pub fn ascii_fallback(self) -> AsciiOutlined<T> {
+        AsciiOutlined(self.0)
+    }
+}
+
+impl<T> Outlined<T>
+where
+    T: UnsignedInteger,
+{
+    /// The largest number of bytes written when formatting a value of type `T`
+    /// using any of the formatting traits, for sizing buffers.
+    ///
+    /// The longest output is the binary representation of the largest value,
+    /// with one digit per bit of `T`. Each digit is encoded in four bytes.
+    ///
+    /// ```
+    /// # use fmtastic::Outlined;
+    /// assert_eq!(16 * 4, Outlined::<u16>::MAX_LEN);
+    /// ```
+    pub const MAX_LEN: usize = max_digits(<T::Impl as IntegerImpl>::BITS, 2) * 4;
+}
+
 impl<T> fmt::Binary for Outlined<T>
 where
     T: UnsignedInteger,
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt_outlined::<_, <T::Impl as IntegerImpl>::BaseTwo>(self.0.into_impl(), f)
+        fmt_outlined::<_, <T::Impl as IntegerImpl>::BaseTwo>(self.0.into_impl(), &DIGITS, f)
     }
 }
 
@@ -60,7 +159,7 @@ where
     T: UnsignedInteger,
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt_outlined::<_, <T::Impl as IntegerImpl>::BaseTen>(self.0.into_impl(), f)
+        fmt_outlined::<_, <T::Impl as IntegerImpl>::BaseTen>(self.0.into_impl(), &DIGITS, f)
     }
 }
 
@@ -69,15 +168,101 @@ where
     T: UnsignedInteger,
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt_outlined::<_, <T::Impl as IntegerImpl>::BaseSixteen>(self.0.into_impl(), f)
+        fmt_outlined::<_, <T::Impl as IntegerImpl>::BaseSixteen>(self.0.into_impl(), &DIGITS, f)
     }
 }
 
-fn fmt_outlined<T: IntegerImpl, B: Base<T>>(n: T, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-    iter_digits::<_, B>(n).try_for_each(|digit| write!(f, "{}", DIGITS[digit]))
+impl<T> fmt::LowerHex for Outlined<T>
+where
+    T: UnsignedInteger,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_outlined::<_, <T::Impl as IntegerImpl>::BaseSixteen>(self.0.into_impl(), &DIGITS, f)
+    }
+}
+
+/// An [`Outlined`] number that is formatted using plain ASCII digits
+/// enclosed in square brackets, created by [`Outlined::ascii_fallback`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct AsciiOutlined<T>(pub T);
+
+impl<T> AsciiOutlined<T>
+where
+    T: UnsignedInteger,
+{
+    /// The largest number of bytes written when formatting a value of type `T`
+    /// using any of the formatting traits, for sizing buffers.
+    ///
+    /// The longest output is the binary representation of the largest value,
+    /// with one digit per bit of `T`. Each digit takes three bytes, e.g. `[1]`.
+    pub const MAX_LEN: usize = max_digits(<T::Impl as IntegerImpl>::BITS, 2) * 3;
 }
 
-const DIGITS: [&str; 16] = [
+impl<T> fmt::Binary for AsciiOutlined<T>
+where
+    T: UnsignedInteger,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_outlined::<_, <T::Impl as IntegerImpl>::BaseTwo>(self.0.into_impl(), &ASCII_DIGITS, f)
+    }
+}
+
+impl<T> fmt::Display for AsciiOutlined<T>
+where
+    T: UnsignedInteger,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_outlined::<_, <T::Impl as IntegerImpl>::BaseTen>(self.0.into_impl(), &ASCII_DIGITS, f)
+    }
+}
+
+impl<T> fmt::UpperHex for AsciiOutlined<T>
+where
+    T: UnsignedInteger,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_outlined::<_, <T::Impl as IntegerImpl>::BaseSixteen>(
+            self.0.into_impl(),
+            &ASCII_DIGITS,
+            f,
+        )
+    }
+}
+
+impl<T> fmt::LowerHex for AsciiOutlined<T>
+where
+    T: UnsignedInteger,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_outlined::<_, <T::Impl as IntegerImpl>::BaseSixteen>(
+            self.0.into_impl(),
+            &ASCII_LOWERCASE_DIGITS,
+            f,
+        )
+    }
+}
+
+/// Formats the digits of `n` in the base `B` using a table with at least `B` digit glyphs.
+pub(crate) fn fmt_outlined<T: IntegerImpl, B: Base<T::Magnitude>>(
+    n: T,
+    digits: &[&str],
+    f: &mut fmt::Formatter<'_>,
+) -> fmt::Result {
+    fmt_with(n.into_public(), B::VALUE.as_usize() as u32, digits, f)
+}
+
+const ASCII_DIGITS: [&str; 16] = [
+    "[0]", "[1]", "[2]", "[3]", "[4]", "[5]", "[6]", "[7]", "[8]", "[9]", "[A]", "[B]", "[C]",
+    "[D]", "[E]", "[F]",
+];
+
+const ASCII_LOWERCASE_DIGITS: [&str; 16] = [
+    "[0]", "[1]", "[2]", "[3]", "[4]", "[5]", "[6]", "[7]", "[8]", "[9]", "[a]", "[b]", "[c]",
+    "[d]", "[e]", "[f]",
+];
+
+pub(crate) const DIGITS: [&str; 16] = [
     // Outlined digits 0-9
     "\u{1CCF0}",
     "\u{1CCF1}",
@@ -97,3 +282,72 @@ const DIGITS: [&str; 16] = [
     "\u{1CCDA}",
     "\u{1CCDB}",
 ];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn output_never_exceeds_max_len() {
+        fn max_len<F: fmt::Display + fmt::Binary + fmt::UpperHex + fmt::LowerHex>(f: F) -> usize {
+            [
+                format!("{f}"),
+                format!("{f:b}"),
+                format!("{f:X}"),
+                format!("{f:x}"),
+            ]
+            .iter()
+            .map(String::len)
+            .max()
+            .unwrap()
+        }
+        for n in 0..=u8::MAX {
+            assert!(max_len(Outlined(n)) <= Outlined::<u8>::MAX_LEN);
+            assert!(max_len(Outlined(n).ascii_fallback()) <= AsciiOutlined::<u8>::MAX_LEN);
+        }
+        for n in 0..=u16::MAX {
+            assert!(max_len(Outlined(n)) <= Outlined::<u16>::MAX_LEN);
+            assert!(max_len(Outlined(n).ascii_fallback()) <= AsciiOutlined::<u16>::MAX_LEN);
+        }
+        for n in [0, 1, 0xF, u32::MAX / 2, u32::MAX] {
+            assert!(max_len(Outlined(n)) <= Outlined::<u32>::MAX_LEN);
+            assert!(max_len(Outlined(n).ascii_fallback()) <= AsciiOutlined::<u32>::MAX_LEN);
+        }
+        assert_eq!(Outlined::<u64>::MAX_LEN, max_len(Outlined(u64::MAX)));
+    }
+
+    #[test]
+    fn converts_ascii_digits() {
+        for (c, expected) in ('0'..='9').zip(0_u8..) {
+            assert_eq!(Ok(Outlined(expected)), Outlined::try_from(c));
+        }
+        for c in ['a', 'x', ' ', '٣', '-'] {
+            assert!(Outlined::<u32>::try_from(c).is_err());
+        }
+    }
+
+    #[test]
+    fn formats_lower_hex_using_uppercase_letters() {
+        assert_eq!(
+            "\u{1CCD6}\u{1CCD7}\u{1CCD8}",
+            format!("{:x}", Outlined(0xabc_u32))
+        );
+        assert_eq!(
+            format!("{:X}", Outlined(0x1CCF0_u32)),
+            format!("{:x}", Outlined(0x1CCF0_u32))
+        );
+        assert_eq!(
+            "[a][b][c]",
+            format!("{:x}", Outlined(0xabc_u32).ascii_fallback())
+        );
+        assert_eq!("[0]", format!("{:x}", Outlined(0_u8).ascii_fallback()));
+    }
+
+    #[test]
+    fn ascii_fallback_switches_to_ascii_digits() {
+        assert_eq!("𜳱𜳲", Outlined(12_u8).to_string());
+        assert_eq!("[1][2]", Outlined(12_u8).ascii_fallback().to_string());
+        assert_eq!("[0]", Outlined(0_u8).ascii_fallback().to_string());
+        assert_eq!("[F][F]", format!("{:X}", Outlined(255_u8).ascii_fallback()));
+    }
+}
@@ -0,0 +1,144 @@
+use crate::digits::max_digits;
+use crate::integer::IntegerImpl;
+use crate::{Plain, UnsignedInteger};
+use core::fmt::{self, Write};
+
+/// Formats an unsigned integer as a parenthesized number, e.g. `⑷`,
+/// as commonly used for numbering lists and clauses of legal texts.
+///
+/// The numbers 1 to 20 are written as the single parenthesized characters `⑴` – `⒇`
+/// (U+2474 – U+2487) from the [Enclosed Alphanumerics] block.
+/// There are no such characters for zero and values above 20, these are written using
+/// ASCII parentheses around the decimal digits instead, e.g. `(21)`.
+/// This fallback keeps every value formattable and looks similar,
+/// but the characters differ and take up more columns.
+///
+/// ```
+/// # use fmtastic::Parenthesized;
+/// assert_eq!("⑴", Parenthesized(1_u8).to_string());
+/// assert_eq!("⒇", Parenthesized(20_u8).to_string());
+/// assert_eq!("(21)", Parenthesized(21_u8).to_string());
+/// assert_eq!("(0)", Parenthesized(0_u8).to_string());
+/// ```
+///
+/// [Enclosed Alphanumerics]: https://www.unicode.org/charts/PDF/U2460.pdf
+///
+/// ## Layout
+/// `Parenthesized<T>` is `#[repr(transparent)]`, so it has the same size, alignment and ABI as `T`.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct Parenthesized<T>(pub T);
+
+impl<T> Parenthesized<T> {
+    /// Returns a reference to the wrapped value.
+    #[must_use]
+    pub fn as_inner(&self) -> &T {
+        &self.0
+    }
+
+    /// Applies a function to the wrapped value.
+    ///
+    /// ```
+    /// # use fmtastic::Parenthesized;
+    /// assert_eq!(Parenthesized(4), Parenthesized(3_u8).map(|n| n + 1));
+    /// ```
+    #[must_use]
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> Parenthesized<U> {
+        Parenthesized(f(self.0))
+    }
+
+    /// Formats the wrapped value using its standard formatting,
+    /// ignoring the glyphs of this format. See [`Plain`].
+    #[must_use]
+    pub fn plain(self) -> Plain<T> {
+        Plain(self.0)
+    }
+}
+
+impl<T> From<T> for Parenthesized<T>
+where
+    T: UnsignedInteger,
+{
+    fn from(value: T) -> Self {
+        Parenthesized(value)
+    }
+}
+
+impl<T> Parenthesized<T>
+where
+    T: UnsignedInteger,
+{
+    /// The largest number of bytes written when formatting a value of type `T`,
+    /// for sizing buffers.
+    ///
+    /// This is the number of decimal digits of the largest value of `T`
+    /// plus the two ASCII parentheses of the fallback. A single parenthesized
+    /// character takes three bytes, which is never more than that.
+    ///
+    /// ```
+    /// # use fmtastic::Parenthesized;
+    /// assert_eq!(10 + 2, Parenthesized::<u32>::MAX_LEN);
+    /// ```
+    pub const MAX_LEN: usize = max_digits(<T::Impl as IntegerImpl>::BITS, 10) + 2;
+}
+
+impl<T> fmt::Display for Parenthesized<T>
+where
+    T: UnsignedInteger,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_parenthesized(self.0.into_impl(), f)
+    }
+}
+
+fn fmt_parenthesized<T: IntegerImpl>(n: T, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    const PARENTHESIZED_ONE: u32 = 0x2474;
+    let is_single_char = n != T::ZERO && T::try_from(20).map_or(true, |max| n <= max);
+    match is_single_char
+        .then(|| char::from_u32(PARENTHESIZED_ONE + n.as_usize() as u32 - 1))
+        .flatten()
+    {
+        Some(c) => f.write_char(c),
+        None => write!(f, "({n})"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn formats_boundary_values() {
+        for (expected, input) in [
+            ("(0)", 0_u8),
+            ("⑴", 1),
+            ("⑼", 9),
+            ("⑽", 10),
+            ("⒇", 20),
+            ("(21)", 21),
+            ("(255)", u8::MAX),
+        ] {
+            assert_eq!(expected, Parenthesized(input).to_string());
+        }
+    }
+
+    #[test]
+    fn uses_single_characters_from_one_to_twenty() {
+        for n in 1..=20_u32 {
+            let formatted = Parenthesized(n).to_string();
+            let c = formatted.chars().next().unwrap();
+            assert_eq!(1, formatted.chars().count());
+            assert_eq!(0x2474 + n - 1, u32::from(c));
+        }
+    }
+
+    #[test]
+    fn output_never_exceeds_max_len() {
+        assert!((0..=u8::MAX)
+            .all(|n| Parenthesized(n).to_string().len() <= Parenthesized::<u8>::MAX_LEN));
+        assert_eq!(
+            Parenthesized::<u128>::MAX_LEN,
+            Parenthesized(u128::MAX).to_string().len()
+        );
+    }
+}
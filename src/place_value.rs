@@ -0,0 +1,206 @@
+use crate::digits::iter_digits;
+use crate::integer::IntegerImpl;
+use crate::words::SCALES;
+use crate::{Plain, Superscript, UnsignedInteger};
+use core::fmt;
+
+/// Formats an unsigned integer as the sum of its decimal digits times their place values,
+/// e.g. `6×10² + 2×10¹ + 8×10⁰` for 628, for teaching place value.
+///
+/// Each digit is followed by its place value in one of the [`PlaceValueStyle`]s:
+///
+/// | Style                                 | 628                           | 305                      |
+/// |---------------------------------------|-------------------------------|--------------------------|
+/// | [`PlaceValueStyle::Powers`] (default) | `6×10² + 2×10¹ + 8×10⁰`       | `3×10² + 5×10⁰`          |
+/// | [`PlaceValueStyle::Names`]            | `6(hundreds) 2(tens) 8(ones)` | `3(hundreds) 5(ones)`    |
+///
+/// The exponents are written as [`Superscript`] and the place names use the short scale
+/// like [`Words`](crate::Words), e.g. `ten thousands` for 10⁴ and `millions` for 10⁶.
+///
+/// Digits that are zero are omitted by default, which can be changed with [`PlaceValue::zeros`].
+/// Zero itself is always written as a single term, e.g. `0×10⁰`.
+///
+/// ```
+/// # use fmtastic::{PlaceValue, PlaceValueStyle};
+/// assert_eq!("6×10² + 2×10¹ + 8×10⁰", PlaceValue::new(628_u32).to_string());
+/// assert_eq!(
+///     "6(hundreds) 2(tens) 8(ones)",
+///     PlaceValue::new(628_u32).style(PlaceValueStyle::Names).to_string()
+/// );
+/// assert_eq!("3×10² + 0×10¹ + 5×10⁰", PlaceValue::new(305_u32).zeros(true).to_string());
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct PlaceValue<T> {
+    value: T,
+    style: PlaceValueStyle,
+    zeros: bool,
+}
+
+/// The notation of the place values of a [`PlaceValue`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+#[non_exhaustive]
+pub enum PlaceValueStyle {
+    /// The digits times powers of ten, joined by ` + `, e.g. `6×10² + 2×10¹ + 8×10⁰`.
+    #[default]
+    Powers,
+    /// The digits followed by the names of their places in parentheses,
+    /// separated by spaces, e.g. `6(hundreds) 2(tens) 8(ones)`.
+    Names,
+}
+
+impl<T> PlaceValue<T> {
+    /// Creates a new [`PlaceValue`] in the default [`PlaceValueStyle`], omitting zero digits.
+    #[must_use]
+    pub fn new(value: T) -> Self {
+        PlaceValue {
+            value,
+            style: PlaceValueStyle::default(),
+            zeros: false,
+        }
+    }
+
+    /// Sets the notation of the place values.
+    #[must_use]
+    pub fn style(mut self, style: PlaceValueStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Controls whether the terms of zero digits are written. They are omitted by default.
+    #[must_use]
+    pub fn zeros(mut self, zeros: bool) -> Self {
+        self.zeros = zeros;
+        self
+    }
+
+    /// Returns a reference to the wrapped value.
+    #[must_use]
+    pub fn as_inner(&self) -> &T {
+        &self.value
+    }
+
+    /// Formats the wrapped value using its standard formatting,
+    /// ignoring the place values. See [`Plain`].
+    #[must_use]
+    pub fn plain(self) -> Plain<T> {
+        Plain(self.value)
+    }
+}
+
+impl<T> From<T> for PlaceValue<T>
+where
+    T: UnsignedInteger,
+{
+    fn from(value: T) -> Self {
+        PlaceValue::new(value)
+    }
+}
+
+impl<T> fmt::Display for PlaceValue<T>
+where
+    T: UnsignedInteger,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let n = self.value.into_impl();
+        let len = iter_digits::<_, <T::Impl as IntegerImpl>::BaseTen>(n).count();
+        let separator = match self.style {
+            PlaceValueStyle::Powers => " + ",
+            PlaceValueStyle::Names => " ",
+        };
+        let terms = iter_digits::<_, <T::Impl as IntegerImpl>::BaseTen>(n)
+            .zip((0..len).rev())
+            .filter(|&(digit, _)| digit != 0 || self.zeros || n == T::Impl::ZERO);
+        for (index, (digit, position)) in terms.enumerate() {
+            if index > 0 {
+                f.write_str(separator)?;
+            }
+            match self.style {
+                PlaceValueStyle::Powers => write!(f, "{digit}×10{}", Superscript(position))?,
+                PlaceValueStyle::Names => {
+                    write!(f, "{digit}(")?;
+                    fmt_place_name(position, f)?;
+                    f.write_str(")")?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Writes the name of the place with the value 10^`position`,
+/// e.g. `hundred thousands` for the position 5.
+fn fmt_place_name(position: usize, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match (position / 3, position % 3) {
+        (0, 0) => f.write_str("ones"),
+        (0, 1) => f.write_str("tens"),
+        (0, _) => f.write_str("hundreds"),
+        (scale, place) => {
+            let prefix = ["", "ten ", "hundred "][place];
+            write!(f, "{prefix}{}s", SCALES[scale - 1])
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn formats_powers_of_ten() {
+        for (expected, input) in [
+            ("6×10² + 2×10¹ + 8×10⁰", 628_u64),
+            ("3×10² + 5×10⁰", 305),
+            ("1×10⁴ + 7×10⁰", 10_007),
+            ("1×10³", 1000),
+            ("9×10⁰", 9),
+            ("0×10⁰", 0),
+        ] {
+            assert_eq!(expected, PlaceValue::new(input).to_string());
+        }
+    }
+
+    #[test]
+    fn formats_place_names() {
+        for (expected, input) in [
+            ("6(hundreds) 2(tens) 8(ones)", 628_u64),
+            ("3(hundreds) 5(ones)", 305),
+            ("1(ten thousands) 7(ones)", 10_007),
+            ("4(hundred thousands) 2(thousands)", 402_000),
+            ("1(millions) 1(hundreds)", 1_000_100),
+            ("1(billions)", 1_000_000_000),
+            ("0(ones)", 0),
+        ] {
+            assert_eq!(
+                expected,
+                PlaceValue::new(input)
+                    .style(PlaceValueStyle::Names)
+                    .to_string()
+            );
+        }
+    }
+
+    #[test]
+    fn writes_zeros_if_enabled() {
+        let place_value = PlaceValue::new(305_u16).zeros(true);
+        assert_eq!("3×10² + 0×10¹ + 5×10⁰", place_value.to_string());
+        assert_eq!(
+            "3(hundreds) 0(tens) 5(ones)",
+            place_value.style(PlaceValueStyle::Names).to_string()
+        );
+        assert_eq!("0×10⁰", PlaceValue::new(0_u8).zeros(true).to_string());
+    }
+
+    #[test]
+    fn formats_extreme_values() {
+        assert_eq!(
+            "2×10² + 5×10¹ + 5×10⁰",
+            PlaceValue::new(u8::MAX).to_string()
+        );
+        let formatted = PlaceValue::new(u128::MAX)
+            .style(PlaceValueStyle::Names)
+            .to_string();
+        assert!(formatted
+            .starts_with("3(hundred undecillions) 4(ten undecillions) 2(hundred decillions)"));
+        assert!(formatted.ends_with("4(hundreds) 5(tens) 5(ones)"));
+    }
+}
@@ -0,0 +1,35 @@
+use core::fmt;
+
+/// Formats a value using its standard formatting,
+/// created by calling `plain` on one of the integer formats.
+///
+/// This ignores the glyphs of the format the value was wrapped in
+/// and defers to the formatting traits of the inner value,
+/// so all formatting flags such as `+`, `#` or the width are honored.
+///
+/// ```
+/// # use fmtastic::{Segmented, Superscript};
+/// assert_eq!("42", Superscript(42).plain().to_string());
+/// assert_eq!("+42", format!("{:+}", Superscript(42).plain()));
+/// assert_eq!("0b101", format!("{:#b}", Segmented(5_u8).plain()));
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct Plain<T>(pub T);
+
+macro_rules! impl_fmt_traits {
+    ($($trait:ident),+) => {
+        $(
+            impl<T> fmt::$trait for Plain<T>
+            where
+                T: fmt::$trait,
+            {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    fmt::$trait::fmt(&self.0, f)
+                }
+            }
+        )+
+    };
+}
+
+impl_fmt_traits!(Display, Binary, Octal, LowerHex, UpperHex);
@@ -0,0 +1,57 @@
+//! Implementations of [`bytemuck::Pod`] and [`bytemuck::Zeroable`] for the `#[repr(transparent)]` wrappers.
+#![allow(unsafe_code)]
+
+use crate::*;
+use bytemuck::{Pod, Zeroable};
+
+macro_rules! impl_pod {
+    ($($name:ident),* $(,)?) => {
+        $(
+            // SAFETY: The wrapper is `#[repr(transparent)]` over its only field of type `T`,
+            // so it is valid for exactly the bit patterns that are valid for `T`.
+            unsafe impl<T: Zeroable> Zeroable for $name<T> {}
+            unsafe impl<T: Pod> Pod for $name<T> {}
+        )*
+    };
+}
+
+impl_pod!(
+    ArabicIndic,
+    AsciiOutlined,
+    BalancedTernary,
+    BasisPoints,
+    Bengali,
+    CjkNumeral,
+    Circled,
+    Devanagari,
+    Dice,
+    DoubleStruck,
+    ExtendedArabicIndic,
+    Fullwidth,
+    MathBold,
+    MathMonospace,
+    MathSansSerif,
+    MathSansSerifBold,
+    Ordinal,
+    Outlined,
+    Parenthesized,
+    Permille,
+    Plain,
+    PrimeFactorization,
+    Segmented,
+    SiCompact,
+    SpacedTallyMarks,
+    AnnotatedTallyMarks,
+    Subscript,
+    Superscript,
+    SuperscriptSiCompact,
+    TallyMarks,
+    Thai,
+    Turned,
+    Words,
+);
+
+// SAFETY: Both are `#[repr(transparent)]` over a `bool`, for which zero is `false`.
+// They are not `Pod`, as only zero and one are valid bit patterns of a `bool`.
+unsafe impl Zeroable for BallotBox {}
+unsafe impl Zeroable for Toggle {}
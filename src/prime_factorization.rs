@@ -0,0 +1,348 @@
+use crate::integer::gcd;
+use crate::{AtMost64Bits, Plain, Superscript, UnsignedInteger};
+use core::fmt::{self, Write};
+
+/// Formats an unsigned integer as its prime factorization,
+/// with the exponents as [`Superscript`] and the factors joined by a middle dot (`·`),
+/// e.g. `2³·3²·5` for 360.
+///
+/// The prime factors are written in ascending order, an exponent of one is omitted.
+/// Zero has no prime factorization and is written as `0`.
+/// One is the empty product and is written as `1`.
+///
+/// ## Supported Types
+/// Values of up to 64 bits are supported, i.e. `u8`, `u16`, `u32`, `u64` and `usize`.
+/// Factoring larger values is not feasible in general, so `u128` cannot be formatted.
+///
+/// ```compile_fail
+/// # use fmtastic::PrimeFactorization;
+/// PrimeFactorization(360_u128).to_string();
+/// ```
+///
+/// ## Algorithm
+/// The factors below 1000 are found by trial division: Dividing by two and then by every
+/// odd number from three on. Whatever remains has only larger prime factors,
+/// so it is prime if it is less than 1000². Otherwise it is tested for primality
+/// using the [Miller–Rabin test] with a fixed set of bases, which is deterministic for 64-bit values.
+/// Composite values are split into two factors using [Pollard's rho algorithm],
+/// which are then factored in the same way.
+/// This takes at most a few milliseconds for every 64-bit value.
+///
+/// [Miller–Rabin test]: https://en.wikipedia.org/wiki/Miller%E2%80%93Rabin_primality_test
+/// [Pollard's rho algorithm]: https://en.wikipedia.org/wiki/Pollard%27s_rho_algorithm
+///
+/// ```
+/// # use fmtastic::PrimeFactorization;
+/// assert_eq!("360 = 2³·3²·5", format!("360 = {}", PrimeFactorization(360_u32)));
+/// assert_eq!("97", PrimeFactorization(97_u8).to_string());
+/// assert_eq!("1", PrimeFactorization(1_u8).to_string());
+/// assert_eq!("4294967279·4294967291", PrimeFactorization(18446743979220271189_u64).to_string());
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct PrimeFactorization<T>(pub T);
+
+impl<T> PrimeFactorization<T> {
+    /// Returns a reference to the wrapped value.
+    #[must_use]
+    pub fn as_inner(&self) -> &T {
+        &self.0
+    }
+
+    /// Applies a function to the wrapped value.
+    ///
+    /// ```
+    /// # use fmtastic::PrimeFactorization;
+    /// assert_eq!(PrimeFactorization(4), PrimeFactorization(3_u8).map(|n| n + 1));
+    /// ```
+    #[must_use]
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> PrimeFactorization<U> {
+        PrimeFactorization(f(self.0))
+    }
+
+    /// Formats the wrapped value using its standard formatting,
+    /// ignoring the factorization. See [`Plain`].
+    #[must_use]
+    pub fn plain(self) -> Plain<T> {
+        Plain(self.0)
+    }
+}
+
+impl<T> From<T> for PrimeFactorization<T>
+where
+    T: UnsignedInteger,
+    T::Impl: AtMost64Bits,
+{
+    fn from(value: T) -> Self {
+        PrimeFactorization(value)
+    }
+}
+
+impl<T> fmt::Display for PrimeFactorization<T>
+where
+    T: UnsignedInteger,
+    T::Impl: AtMost64Bits,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_prime_factorization(self.0.into_impl().to_u64(), f)
+    }
+}
+
+macro_rules! impl_at_most_64_bits {
+    ($($ty:ty),*) => {
+        $(
+            impl AtMost64Bits for $ty {
+                fn to_u64(self) -> u64 {
+                    u64::from(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_at_most_64_bits!(u8, u16, u32, u64);
+
+#[cfg(any(
+    target_pointer_width = "16",
+    target_pointer_width = "32",
+    target_pointer_width = "64"
+))]
+impl AtMost64Bits for usize {
+    fn to_u64(self) -> u64 {
+        // Lossless, as `usize` has at most 64 bits on these targets.
+        self as u64
+    }
+}
+
+/// The largest divisor tried by trial division.
+const TRIAL_DIVISION_LIMIT: u64 = 1000;
+
+fn fmt_prime_factorization(n: u64, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    if n <= 1 {
+        return write!(f, "{n}");
+    }
+    let mut factors = Factors {
+        primes: [0; 64],
+        len: 0,
+    };
+    let n = trial_division(n, &mut factors);
+    factor_large(n, &mut factors);
+    let primes = &mut factors.primes[..factors.len];
+    primes.sort_unstable();
+    let mut rest = &primes[..];
+    while let Some(&prime) = rest.first() {
+        let exponent = rest.iter().take_while(|&&p| p == prime).count();
+        write!(f, "{prime}")?;
+        if exponent > 1 {
+            write!(f, "{}", Superscript(exponent))?;
+        }
+        rest = &rest[exponent..];
+        if !rest.is_empty() {
+            f.write_char('·')?;
+        }
+    }
+    Ok(())
+}
+
+/// The prime factors of a value, with repetitions.
+/// A 64-bit value has at most 64 prime factors, all of them two.
+struct Factors {
+    primes: [u64; 64],
+    len: usize,
+}
+
+impl Factors {
+    fn push(&mut self, prime: u64) {
+        self.primes[self.len] = prime;
+        self.len += 1;
+    }
+}
+
+/// Divides out all prime factors up to [`TRIAL_DIVISION_LIMIT`] and returns the rest.
+fn trial_division(mut n: u64, factors: &mut Factors) -> u64 {
+    let divisors = core::iter::once(2).chain((3..TRIAL_DIVISION_LIMIT).step_by(2));
+    for divisor in divisors {
+        while n % divisor == 0 {
+            factors.push(divisor);
+            n /= divisor;
+        }
+    }
+    n
+}
+
+/// Factors a value without prime factors up to [`TRIAL_DIVISION_LIMIT`].
+fn factor_large(n: u64, factors: &mut Factors) {
+    if n == 1 {
+        return;
+    }
+    if n < TRIAL_DIVISION_LIMIT * TRIAL_DIVISION_LIMIT || is_prime(n) {
+        factors.push(n);
+    } else {
+        let divisor = pollard_rho(n);
+        factor_large(divisor, factors);
+        factor_large(n / divisor, factors);
+    }
+}
+
+/// The Miller–Rabin test using the first twelve primes as bases,
+/// which has no false positives below 3.18 · 10²³, far above the largest `u64`.
+/// Expects an odd `n` greater than 37.
+fn is_prime(n: u64) -> bool {
+    let exponent = (n - 1).trailing_zeros();
+    let odd = (n - 1) >> exponent;
+    [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37]
+        .into_iter()
+        .all(|base| {
+            let mut x = pow_mod(base, odd, n);
+            if x == 1 || x == n - 1 {
+                return true;
+            }
+            (1..exponent).any(|_| {
+                x = mul_mod(x, x, n);
+                x == n - 1
+            })
+        })
+}
+
+/// Finds a non-trivial divisor of a composite `n` without small prime factors.
+fn pollard_rho(n: u64) -> u64 {
+    (1..)
+        .find_map(|increment| {
+            let step =
+                |x: u64| ((u128::from(x) * u128::from(x) + increment) % u128::from(n)) as u64;
+            let (mut slow, mut fast) = (2, 2);
+            loop {
+                slow = step(slow);
+                fast = step(step(fast));
+                match gcd(slow.abs_diff(fast), n) {
+                    1 => continue,
+                    // The sequence cycled without finding a divisor, retry with another increment.
+                    divisor if divisor == n => return None,
+                    divisor => return Some(divisor),
+                }
+            }
+        })
+        .unwrap_or(n)
+}
+
+fn mul_mod(a: u64, b: u64, modulus: u64) -> u64 {
+    // The remainder is less than the modulus, so it always fits.
+    (u128::from(a) * u128::from(b) % u128::from(modulus)) as u64
+}
+
+fn pow_mod(mut base: u64, mut exponent: u64, modulus: u64) -> u64 {
+    let mut result = 1;
+    base %= modulus;
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = mul_mod(result, base, modulus);
+        }
+        base = mul_mod(base, base, modulus);
+        exponent >>= 1;
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn formats_prime_factorization() {
+        for (expected, input) in [
+            ("2³·3²·5", 360_u64),
+            ("2", 2),
+            ("97", 97),
+            ("2¹⁰", 1024),
+            ("3·5·7", 105),
+            ("7²", 49),
+            ("2·4999", 9998),
+            ("641·6700417", 4_294_967_297),
+        ] {
+            assert_eq!(expected, PrimeFactorization(input).to_string());
+        }
+    }
+
+    #[test]
+    fn formats_large_prime_factors() {
+        for (expected, input) in [
+            ("18446744073709551557", 18_446_744_073_709_551_557_u64),
+            ("4294967279·4294967291", 18_446_743_979_220_271_189),
+            ("4294967291²", 4_294_967_291 * 4_294_967_291),
+            ("997·1009", 997 * 1009),
+            ("1009²", 1009 * 1009),
+            ("2·3·1000003·1000033", 6 * 1_000_003 * 1_000_033),
+        ] {
+            assert_eq!(expected, PrimeFactorization(input).to_string());
+        }
+    }
+
+    #[test]
+    fn tests_primality_without_false_positives() {
+        // Strong pseudoprimes to several of the smaller bases.
+        for composite in [
+            2047_u64,
+            1_373_653,
+            25_326_001,
+            3_215_031_751,
+            341_550_071_728_321,
+        ] {
+            assert!(!is_prime(composite));
+        }
+        for prime in [1_000_003_u64, 4_294_967_291, 18_446_744_073_709_551_557] {
+            assert!(is_prime(prime));
+        }
+    }
+
+    #[test]
+    fn matches_trial_division() {
+        fn trial_division_reference(mut n: u64) -> Vec<u64> {
+            let mut factors = Vec::new();
+            let mut divisor = 2;
+            while divisor * divisor <= n {
+                while n % divisor == 0 {
+                    factors.push(divisor);
+                    n /= divisor;
+                }
+                divisor += 1;
+            }
+            if n > 1 {
+                factors.push(n);
+            }
+            factors
+        }
+        for n in (2..20_000).chain((1_000_000_000..1_000_002_000).step_by(7)) {
+            let mut factors = Factors {
+                primes: [0; 64],
+                len: 0,
+            };
+            factor_large(trial_division(n, &mut factors), &mut factors);
+            let primes = &mut factors.primes[..factors.len];
+            primes.sort_unstable();
+            assert_eq!(trial_division_reference(n), primes);
+        }
+    }
+
+    #[test]
+    fn formats_zero_and_one() {
+        assert_eq!("0", PrimeFactorization(0_u8).to_string());
+        assert_eq!("1", PrimeFactorization(1_u8).to_string());
+    }
+
+    #[test]
+    fn formats_extreme_values() {
+        assert_eq!("251", PrimeFactorization(251_u8).to_string());
+        assert_eq!("3·5·17", PrimeFactorization(u8::MAX).to_string());
+        assert_eq!("2⁷", PrimeFactorization(128_u8).to_string());
+        assert_eq!(
+            "3·5·17·257·641·65537·6700417",
+            PrimeFactorization(u64::MAX).to_string()
+        );
+        assert_eq!("2⁶³", PrimeFactorization(1_u64 << 63).to_string());
+        assert_eq!("2²·3·7", PrimeFactorization(84_usize).to_string());
+        assert_eq!(
+            "2⁵·3",
+            PrimeFactorization(core::num::Wrapping(96_usize)).to_string()
+        );
+    }
+}
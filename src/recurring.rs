@@ -0,0 +1,265 @@
+use crate::integer::{gcd, IntegerImpl, Sign};
+use crate::{Integer, VulgarFraction};
+use core::fmt::{self, Write};
+
+/// A fraction formatted as a decimal with its repeating digits marked,
+/// e.g. `0.3̅` for ¹⁄₃ or `0.16̅` for ¹⁄₆, created by [`VulgarFraction::recurring`].
+///
+/// The decimal expansion is computed by long division of the reduced fraction.
+/// The digits before the repeating block (if any) are written as is,
+/// the repeating block (the repetend) is marked according to the [`RecurringMark`]:
+///
+/// | Mark                                 | ¹⁄₃     | ¹⁄₇             |
+/// |--------------------------------------|---------|-----------------|
+/// | [`RecurringMark::Overline`] (default)| `0.3̅`   | `0.1̅4̅2̅8̅5̅7̅`   |
+/// | [`RecurringMark::Dots`]              | `0.3̇`   | `0.1̇42857̇`     |
+///
+/// Fractions with a terminating expansion, such as ¹⁄₄, are written without any marks.
+/// Negative fractions are written with a leading `-`.
+///
+/// ## Period Length
+/// The repetend of ¹⁄ₙ can be up to `n - 1` digits long, which is impractical for large denominators.
+/// At most [`Recurring::MAX_PERIOD`] digits of the repetend are written.
+/// A longer repetend is truncated, written without marks and followed by an ellipsis (`…`).
+///
+/// ```
+/// # use fmtastic::{RecurringMark, VulgarFraction};
+/// assert_eq!("0.3\u{305}", VulgarFraction::new(1, 3).recurring().unwrap().to_string());
+/// assert_eq!("0.16\u{305}", VulgarFraction::new(1, 6).recurring().unwrap().to_string());
+/// assert_eq!(
+///     "0.1\u{307}42857\u{307}",
+///     VulgarFraction::new(1, 7).recurring().unwrap().mark(RecurringMark::Dots).to_string()
+/// );
+/// assert_eq!("-2.25", VulgarFraction::new(-9, 4).recurring().unwrap().to_string());
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct Recurring<T>(pub(crate) VulgarFraction<T>, pub(crate) RecurringMark);
+
+/// The convention used by [`Recurring`] to mark the repeating digits.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+#[non_exhaustive]
+pub enum RecurringMark {
+    /// A combining overline (U+0305) over every repeating digit, e.g. `0.1̅4̅2̅8̅5̅7̅`.
+    #[default]
+    Overline,
+    /// A combining dot above (U+0307) the first and the last repeating digit, e.g. `0.1̇42857̇`.
+    /// A single repeating digit has a single dot, e.g. `0.3̇`.
+    Dots,
+}
+
+impl<T> Recurring<T> {
+    /// The largest number of repeating digits that are written.
+    pub const MAX_PERIOD: usize = 1024;
+
+    /// Sets the convention used to mark the repeating digits.
+    #[must_use]
+    pub fn mark(mut self, mark: RecurringMark) -> Self {
+        self.1 = mark;
+        self
+    }
+
+    /// Returns a reference to the wrapped fraction.
+    #[must_use]
+    pub fn as_inner(&self) -> &VulgarFraction<T> {
+        &self.0
+    }
+}
+
+impl<T> fmt::Display for Recurring<T>
+where
+    T: Integer,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let numerator = self.0.numerator.into_impl();
+        let denominator = self.0.denominator.into_impl();
+        let negative = numerator != T::Impl::ZERO
+            && matches!(numerator.sign() * denominator.sign(), Sign::Negative);
+        if negative {
+            f.write_char('-')?;
+        }
+        fmt_recurring(
+            numerator.unsigned_abs(),
+            denominator.unsigned_abs(),
+            self.1,
+            f,
+        )
+    }
+}
+
+fn fmt_recurring<T: IntegerImpl>(
+    n: T,
+    d: T,
+    mark: RecurringMark,
+    f: &mut fmt::Formatter<'_>,
+) -> fmt::Result {
+    let divisor = gcd(n, d);
+    let (n, d) = (n / divisor, d / divisor);
+    write!(f, "{}", n / d)?;
+    let mut remainder = n % d;
+    if remainder == T::ZERO {
+        return Ok(());
+    }
+
+    f.write_char('.')?;
+    for _ in 0..pre_period(d) {
+        let (digit, rest) = next_digit(remainder, d);
+        write!(f, "{digit}")?;
+        remainder = rest;
+    }
+    if remainder == T::ZERO {
+        return Ok(());
+    }
+
+    match period(remainder, d) {
+        Some(period) => (0..period).try_for_each(|i| {
+            let (digit, rest) = next_digit(remainder, d);
+            remainder = rest;
+            write!(f, "{digit}")?;
+            match mark {
+                RecurringMark::Overline => f.write_char('\u{305}'),
+                RecurringMark::Dots if i == 0 || i == period - 1 => f.write_char('\u{307}'),
+                RecurringMark::Dots => Ok(()),
+            }
+        }),
+        None => {
+            for _ in 0..Recurring::<T>::MAX_PERIOD {
+                let (digit, rest) = next_digit(remainder, d);
+                remainder = rest;
+                write!(f, "{digit}")?;
+            }
+            f.write_char('…')
+        }
+    }
+}
+
+/// The number of digits before the repetend of a reduced fraction with the denominator `d`,
+/// which is the larger of the multiplicities of the prime factors two and five of `d`.
+fn pre_period<T: IntegerImpl>(d: T) -> usize {
+    let two = T::ONE + T::ONE;
+    let multiplicity = |factor: T| {
+        let mut d = d;
+        let mut count = 0;
+        while d % factor == T::ZERO {
+            d = d / factor;
+            count += 1;
+        }
+        count
+    };
+    multiplicity(two).max(multiplicity(T::FIVE))
+}
+
+/// The length of the repetend starting at `remainder`,
+/// found by repeating the long division until the remainder recurs.
+/// Returns `None` if the repetend is longer than [`Recurring::MAX_PERIOD`].
+fn period<T: IntegerImpl>(remainder: T, d: T) -> Option<usize> {
+    let mut current = remainder;
+    (1..=Recurring::<T>::MAX_PERIOD).find(|_| {
+        current = next_digit(current, d).1;
+        current == remainder
+    })
+}
+
+/// Performs one step of the long division, returning the next digit and remainder
+/// of `remainder * 10 / d`. The multiplication is done by repeated addition
+/// modulo `d`, as `remainder * 10` may not fit into `T`.
+fn next_digit<T: IntegerImpl>(remainder: T, d: T) -> (u8, T) {
+    (0..10).fold((0, T::ZERO), |(digit, acc), _| {
+        if acc >= d - remainder {
+            (digit + 1, acc - (d - remainder))
+        } else {
+            (digit, acc + remainder)
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn recurring<T: Integer>(n: T, d: T, mark: RecurringMark) -> String {
+        VulgarFraction::new(n, d)
+            .recurring()
+            .unwrap()
+            .mark(mark)
+            .to_string()
+    }
+
+    #[test]
+    fn marks_repetend_with_overline() {
+        for (expected, (n, d)) in [
+            ("0.3\u{305}", (1, 3)),
+            ("0.6\u{305}", (2, 3)),
+            ("0.1\u{305}4\u{305}2\u{305}8\u{305}5\u{305}7\u{305}", (1, 7)),
+            ("0.16\u{305}", (1, 6)),
+            ("0.083\u{305}", (1, 12)),
+            ("3.6\u{305}", (11, 3)),
+            ("-0.3\u{305}", (1, -3)),
+            ("0.3\u{305}", (-2, -6)),
+        ] {
+            assert_eq!(expected, recurring(n, d, RecurringMark::Overline));
+        }
+    }
+
+    #[test]
+    fn marks_repetend_with_dots() {
+        for (expected, (n, d)) in [
+            ("0.3\u{307}", (1, 3)),
+            ("0.1\u{307}42857\u{307}", (1, 7)),
+            ("0.16\u{307}", (1, 6)),
+            ("0.0\u{307}9\u{307}", (1, 11)),
+        ] {
+            assert_eq!(expected, recurring(n, d, RecurringMark::Dots));
+        }
+    }
+
+    #[test]
+    fn formats_terminating_fractions() {
+        for (expected, (n, d)) in [
+            ("0.25", (1, 4)),
+            ("0.125", (1, 8)),
+            ("2", (4, 2)),
+            ("0", (0, 7)),
+            ("0", (0, -7)),
+            ("-2.25", (-9, 4)),
+            ("0.0016", (1, 625)),
+        ] {
+            assert_eq!(expected, recurring(n, d, RecurringMark::Overline));
+        }
+    }
+
+    #[test]
+    fn formats_extreme_values() {
+        assert_eq!(
+            "-1.0\u{305}0\u{305}7\u{305}8\u{305}7\u{305}4\u{305}",
+            recurring(i8::MIN, 127, RecurringMark::Overline)
+                .chars()
+                .take(15)
+                .collect::<String>()
+        );
+        assert_eq!("-128", recurring(i8::MIN, 1, RecurringMark::Overline));
+        let formatted = recurring(u128::MAX - 1, u128::MAX, RecurringMark::Overline);
+        assert!(formatted.starts_with("0.99999999"));
+        assert!(formatted.ends_with('…'));
+    }
+
+    #[test]
+    fn truncates_long_periods() {
+        // 1/1033 has a period of 1032 digits.
+        let formatted = recurring(1_u16, 1033, RecurringMark::Overline);
+        assert!(formatted.ends_with('…'));
+        assert!(!formatted.contains('\u{305}'));
+        assert_eq!(
+            "0.".len() + Recurring::<u16>::MAX_PERIOD + '…'.len_utf8(),
+            formatted.len()
+        );
+        let formatted = recurring(1_u16, 1019, RecurringMark::Overline);
+        assert!(formatted.ends_with('\u{305}'));
+    }
+
+    #[test]
+    fn computes_next_digit_without_overflow() {
+        assert_eq!((3, 1), next_digit(1_u8, 3));
+        assert_eq!((9, 245), next_digit(254_u8, 255));
+        assert_eq!((9, u128::MAX - 10), next_digit(u128::MAX - 1, u128::MAX));
+    }
+}
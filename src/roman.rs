@@ -1,9 +1,10 @@
 // Adapted from Yann Villessuzanne's roman.rs under the
 // Unlicense, at https://github.com/linfir/roman.rs/
 
-use crate::integer::IntegerImpl;
-use crate::UnsignedInteger;
-use core::fmt;
+use crate::integer::{IntegerImpl, Sign};
+use crate::{OutOfRange, Plain, SignedInteger, UnsignedInteger};
+use core::fmt::{self, Write};
+use core::str::FromStr;
 
 /// Formats unsigned integers as Roman numerals.
 ///
@@ -23,27 +24,211 @@ use core::fmt;
 /// By default uppercase numerals are used.
 /// The alternate flag `#` can be used to switch to lowercase numerals.
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
-pub struct Roman<T>(T, SymbolRepertoire);
+pub struct Roman<T>(T, SymbolRepertoire, Option<char>);
 
 impl<T> Roman<T> {
     /// Uses ASCII symbols instead of the dedicated unciode
     /// symbols for Roman numerals.
+    #[must_use]
     pub fn ascii(mut self) -> Self {
         self.1 = SymbolRepertoire::Ascii;
         self
     }
+
+    /// Uses the dedicated unicode symbols for Roman numerals,
+    /// which is the default. Reverts [`Roman::ascii`].
+    ///
+    /// ```
+    /// # use fmtastic::{Roman, SymbolRepertoire};
+    /// let roman = Roman::new(4_u16).unwrap().ascii();
+    /// assert_eq!(SymbolRepertoire::Ascii, roman.repertoire());
+    /// assert_eq!("ⅠⅤ", roman.unicode().to_string());
+    /// ```
+    #[must_use]
+    pub fn unicode(mut self) -> Self {
+        self.1 = SymbolRepertoire::Unicode;
+        self
+    }
+
+    /// Uses ASCII letters together with the Claudian letter `Ↄ` (U+2183)
+    /// for 500 and 1000. See [`SymbolRepertoire::Claudian`].
+    ///
+    /// ```
+    /// # use fmtastic::Roman;
+    /// assert_eq!("CIↃCIↃIↃCCCCXCIX", Roman::new(2999_u16).unwrap().claudian().to_string());
+    /// assert_eq!("ciↄxxiv", format!("{:#}", Roman::new(1024_u16).unwrap().claudian()));
+    /// ```
+    #[must_use]
+    pub fn claudian(mut self) -> Self {
+        self.1 = SymbolRepertoire::Claudian;
+        self
+    }
+
+    /// Returns the symbols used to format this numeral.
+    #[must_use]
+    pub fn repertoire(&self) -> SymbolRepertoire {
+        self.1
+    }
+
+    /// Separates the symbols with an interpunct (`·`, U+00B7), e.g. `M·M·X·X·IV`.
+    ///
+    /// The interpunct is only inserted between symbols, never within the
+    /// pair of a subtractive notation such as `IV` or `CM`.
+    ///
+    /// ```
+    /// # use fmtastic::Roman;
+    /// assert_eq!("M·M·X·X·IV", Roman::new(2024_u16).unwrap().ascii().interpunct().to_string());
+    /// ```
+    #[must_use]
+    pub fn interpunct(mut self) -> Self {
+        self.2 = Some('\u{00B7}');
+        self
+    }
+
+    /// Limits the numeral to `max_chars` characters, e.g. for narrow columns.
+    /// If the numeral is longer, the first `max_chars` characters are written
+    /// followed by an ellipsis (`…`, U+2026), which is not counted towards the limit.
+    ///
+    /// The limit counts the characters as written, not the bytes:
+    /// Each of the dedicated unicode symbols (e.g. `Ⅿ`) counts as one character,
+    /// as does each letter of the ASCII symbols and each interpunct.
+    ///
+    /// ```
+    /// # use fmtastic::Roman;
+    /// assert_eq!("ⅯⅯⅯⅮ…", Roman::new(3888_u16).unwrap().truncate(4).to_string());
+    /// assert_eq!("ⅩⅠⅠ", Roman::new(12_u16).unwrap().truncate(3).to_string());
+    /// ```
+    #[must_use]
+    pub fn truncate(self, max_chars: usize) -> TruncatedRoman<T> {
+        TruncatedRoman(self, max_chars)
+    }
+
+    /// Returns a reference to the wrapped value.
+    #[must_use]
+    pub fn as_inner(&self) -> &T {
+        &self.0
+    }
+
+    /// Returns the value of the numeral, e.g. for doing arithmetic with it.
+    ///
+    /// ```
+    /// # use fmtastic::Roman;
+    /// let roman = Roman::new(2024_u16).unwrap();
+    /// assert_eq!(2025, roman.value() + 1);
+    /// assert_eq!(2024_u16, u16::from(roman));
+    /// ```
+    #[must_use]
+    pub const fn value(&self) -> T
+    where
+        T: Copy,
+    {
+        self.0
+    }
+
+    /// Formats the wrapped value using its standard formatting,
+    /// ignoring the glyphs of this format. See [`Plain`].
+    #[must_use]
+    pub fn plain(self) -> Plain<T> {
+        Plain(self.0)
+    }
+
+    /// Applies a function to the wrapped value, keeping the symbols used.
+    /// Returns an [`OutOfRange`] error if the new value cannot be
+    /// represented as Roman numeral.
+    ///
+    /// ```
+    /// # use fmtastic::Roman;
+    /// assert_eq!("IV", Roman::new(3_u16).unwrap().ascii().map(|n| n + 1).unwrap().to_string());
+    /// assert!(Roman::new(3999_u16).unwrap().map(|n| n + 1).is_err());
+    /// ```
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> Result<Roman<U>, OutOfRange<U>>
+    where
+        U: UnsignedInteger,
+    {
+        let Roman(value, ..) = Roman::new(f(self.0))?;
+        Ok(Roman(value, self.1, self.2))
+    }
 }
 
+/// The symbols used to format a [`Roman`] numeral.
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
 #[non_exhaustive]
-enum SymbolRepertoire {
+pub enum SymbolRepertoire {
+    /// The dedicated unicode symbols for Roman numerals from the
+    /// [Number Forms](https://www.unicode.org/charts/PDF/U2150.pdf) block, e.g. `ⅯⅯⅩⅩⅠⅤ`.
+    ///
+    /// Every letter of the numeral is written as its own precomposed symbol,
+    /// `ⅠⅤⅩⅬⅭⅮⅯ` (U+2160 to U+216F) or `ⅰⅴⅹⅼⅽⅾⅿ` (U+2170 to U+217F) with the alternate flag `#`,
+    /// so that the numeral has the same number of characters as its ASCII counterpart.
+    /// The symbols for multiple letters such as `Ⅻ` (U+216B) and the historical
+    /// forms such as `ↀ` (U+2180) are never used.
     Unicode,
+    /// The ASCII letters, e.g. `MMXXIV`.
     Ascii,
+    /// The ASCII letters, with 500 and 1000 written in the historical apostrophus form
+    /// using the Claudian letter `Ↄ` (U+2183, lowercase `ↄ` U+2184)
+    /// from the [Number Forms](https://www.unicode.org/charts/PDF/U2150.pdf) block,
+    /// e.g. `CIↃCIↃXXIV`.
+    ///
+    /// | Value | ASCII | Claudian |
+    /// |-------|-------|----------|
+    /// | 400   | `CD`  | `CCCC`   |
+    /// | 500   | `D`   | `IↃ`     |
+    /// | 900   | `CM`  | `IↃCCCC` |
+    /// | 1000  | `M`   | `CIↃ`    |
+    ///
+    /// 400 and 900 are written additively, as the subtractive form of 400 (`CIↃ`)
+    /// cannot be told apart from 1000. All other values use the ASCII symbols.
+    /// The representable range is the same as for the other repertoires, 1 to 3999.
+    Claudian,
+}
+
+impl Roman<u16> {
+    #[doc(hidden)]
+    pub const fn __new_const(value: u16) -> Option<Self> {
+        if 0 < value && value <= 3999 {
+            Some(Roman(value, SymbolRepertoire::Unicode, None))
+        } else {
+            None
+        }
+    }
+}
+
+/// Creates a [`Roman<u16>`](Roman) numeral from a constant,
+/// checking at compile time that it is between 1 and 3999.
+///
+/// The value can be an integer literal or any constant expression of type `u16`.
+///
+/// ```
+/// # use fmtastic::roman;
+/// assert_eq!("ⅯⅯⅩⅩⅠⅤ", roman!(2024).to_string());
+///
+/// const YEAR: u16 = 1999;
+/// assert_eq!("MCMXCIX", roman!(YEAR).ascii().to_string());
+/// ```
+///
+/// Values that are out of range fail to compile:
+///
+/// ```compile_fail
+/// # use fmtastic::roman;
+/// let zero = roman!(0); // error: Roman numerals must be between 1 and 3999
+/// ```
+#[macro_export]
+macro_rules! roman {
+    ($value:expr) => {{
+        const ROMAN: $crate::Roman<u16> = match $crate::Roman::__new_const($value) {
+            ::core::option::Option::Some(roman) => roman,
+            ::core::option::Option::None => {
+                ::core::panic!("Roman numerals must be between 1 and 3999")
+            }
+        };
+        ROMAN
+    }};
 }
 
 impl From<u8> for Roman<u8> {
     fn from(value: u8) -> Self {
-        Roman(value, SymbolRepertoire::Unicode)
+        Roman(value, SymbolRepertoire::Unicode, None)
     }
 }
 
@@ -52,34 +237,455 @@ where
     T: UnsignedInteger,
 {
     /// Creates a new [`Roman`] numeral.
-    /// Returns `None` if the value is not between 1 and 3999.
-    pub fn new(value: T) -> Option<Roman<T>> {
+    /// Returns an [`OutOfRange`] error if the value is not between 1 and 3999.
+    pub fn new(value: T) -> Result<Roman<T>, OutOfRange<T>> {
         if T::Impl::ZERO < value.into_impl() && value.into_impl() <= T::UnsignedImpl::ROMAN_MAX {
-            Some(Roman(value, SymbolRepertoire::Unicode))
+            Ok(Roman(value, SymbolRepertoire::Unicode, None))
         } else {
-            None
+            Err(OutOfRange {
+                value,
+                min: T::from_impl(T::Impl::ONE),
+                max: T::from_impl(T::UnsignedImpl::ROMAN_MAX),
+            })
         }
     }
 }
 
-impl<T> fmt::Display for Roman<T>
+impl<T> Roman<T>
 where
     T: UnsignedInteger,
+{
+    /// Formats the given values as a list of Roman numerals
+    /// separated by `", "`. The separator can be changed using [`RomanList::separator`].
+    ///
+    /// Values that cannot be represented as Roman numeral (see [`Roman::new`]) are skipped.
+    ///
+    /// ```
+    /// # use fmtastic::Roman;
+    /// assert_eq!("I, II, III, IV, V", Roman::list(1..=5_u16).ascii().to_string());
+    /// assert_eq!("Ⅰ ⅠⅠ ⅠⅠⅠ", Roman::list(0..=3_u16).separator(" ").to_string());
+    /// assert_eq!("ⅰ/ⅰⅰ", format!("{:#}", Roman::list([1_u8, 2]).separator("/")));
+    /// ```
+    #[must_use]
+    pub fn list<I>(values: I) -> RomanList<I>
+    where
+        I: IntoIterator<Item = T> + Clone,
+    {
+        RomanList {
+            values,
+            repertoire: SymbolRepertoire::Unicode,
+            separator: ", ",
+        }
+    }
+}
+
+/// A list of Roman numerals, created by [`Roman::list`].
+///
+/// ## Formatting Flags
+/// The formatting flags are applied to every numeral in the list.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct RomanList<I> {
+    values: I,
+    repertoire: SymbolRepertoire,
+    separator: &'static str,
+}
+
+impl<I> RomanList<I> {
+    /// Uses ASCII symbols instead of the dedicated unicode
+    /// symbols for Roman numerals.
+    #[must_use]
+    pub fn ascii(mut self) -> Self {
+        self.repertoire = SymbolRepertoire::Ascii;
+        self
+    }
+
+    /// Sets the separator written between two numerals.
+    #[must_use]
+    pub fn separator(mut self, separator: &'static str) -> Self {
+        self.separator = separator;
+        self
+    }
+}
+
+impl<I, T> fmt::Display for RomanList<I>
+where
+    I: IntoIterator<Item = T> + Clone,
+    T: UnsignedInteger,
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let mut n = self.0.into_impl();
-        for (symbol, value) in roman_pairs::<T>(self.1, f.alternate()) {
-            let value = value.into_impl();
-            while n >= value {
-                n -= value;
-                write!(f, "{symbol}")?;
+        let numerals = self.values.clone().into_iter().filter_map(|value| {
+            let Roman(value, ..) = Roman::new(value).ok()?;
+            Some(Roman(value, self.repertoire, None))
+        });
+        for (index, numeral) in numerals.enumerate() {
+            if index > 0 {
+                f.write_str(self.separator)?;
             }
+            fmt::Display::fmt(&numeral, f)?;
         }
-        debug_assert!(n == T::Impl::ZERO);
         Ok(())
     }
 }
 
+impl<T> fmt::Display for Roman<T>
+where
+    T: UnsignedInteger,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_roman(self, usize::MAX, f)
+    }
+}
+
+/// A Roman numeral limited to a number of characters, created by [`Roman::truncate`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct TruncatedRoman<T>(Roman<T>, usize);
+
+impl<T> fmt::Display for TruncatedRoman<T>
+where
+    T: UnsignedInteger,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_roman(&self.0, self.1, f)
+    }
+}
+
+/// Writes at most `max_chars` characters of the numeral,
+/// followed by an ellipsis if any characters were left out.
+fn fmt_roman<T>(roman: &Roman<T>, max_chars: usize, f: &mut fmt::Formatter<'_>) -> fmt::Result
+where
+    T: UnsignedInteger,
+{
+    let mut remaining = max_chars;
+    // Every value is written as the largest possible multiple of each symbol,
+    // leaving the remainder for the smaller symbols. As the last symbol `I`
+    // has a value of one, nothing remains once all symbols have been written.
+    let mut n = roman.0.into_impl();
+    let mut separator = None;
+    for (symbol, value) in roman_pairs::<T>(roman.1, f.alternate()) {
+        let value = value.into_impl();
+        for _ in T::Impl::range(T::Impl::ZERO, n / value) {
+            for c in separator.into_iter().chain(symbol.chars()) {
+                if remaining == 0 {
+                    return f.write_char('\u{2026}');
+                }
+                remaining -= 1;
+                f.write_char(c)?;
+            }
+            separator = roman.2;
+        }
+        n = n % value;
+    }
+    Ok(())
+}
+
+/// Formats signed integers as Roman numerals, marking negative numbers
+/// in one of the [`NegativeStyle`]s.
+///
+/// The Romans had no negative numbers, so there is no historical convention.
+/// By default, negative numbers are prefixed with a hyphen-minus (`-`).
+/// The magnitude is formatted like a [`Roman`] numeral and zero cannot be represented.
+///
+/// | Style                                 | -5      |
+/// |---------------------------------------|---------|
+/// | [`NegativeStyle::Hyphen`] (default)   | `-Ⅴ`    |
+/// | [`NegativeStyle::Minus`]              | `−Ⅴ`    |
+/// | [`NegativeStyle::Parentheses`]        | `(Ⅴ)`   |
+///
+/// ```
+/// # use fmtastic::{NegativeStyle, SignedRoman};
+/// assert_eq!("-V", SignedRoman::new(-5).unwrap().ascii().to_string());
+/// assert_eq!("(V)", SignedRoman::new(-5).unwrap().ascii().negative_style(NegativeStyle::Parentheses).to_string());
+/// assert_eq!("ⅩⅠⅤ", SignedRoman::new(14).unwrap().to_string());
+/// ```
+///
+/// ## Formatting Flags
+/// ### Alternate `#`
+/// The alternate flag `#` can be used to switch to lowercase numerals, like for [`Roman`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct SignedRoman<T>(T, Roman<u16>, NegativeStyle);
+
+/// The marking of a negative [`SignedRoman`] numeral.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+#[non_exhaustive]
+pub enum NegativeStyle {
+    /// A leading hyphen-minus (`-`, U+002D), e.g. `-V`.
+    #[default]
+    Hyphen,
+    /// A leading minus sign (`−`, U+2212), e.g. `−V`.
+    /// Unlike the hyphen, it has the width of a plus sign and is not used for hyphenation.
+    Minus,
+    /// The numeral enclosed in parentheses, e.g. `(V)`, as used for negative amounts in accounting.
+    Parentheses,
+}
+
+impl<T> SignedRoman<T>
+where
+    T: SignedInteger,
+{
+    /// Creates a new [`SignedRoman`] numeral.
+    ///
+    /// Returns an [`OutOfRange`] error if the value is zero or its magnitude is greater than 3999.
+    /// The error contains the range of values with the same sign as the value, e.g. -3999 to -1
+    /// for a negative value. Zero is rejected with the range of positive values.
+    pub fn new(value: T) -> Result<SignedRoman<T>, OutOfRange<T>> {
+        let n = value.into_impl();
+        let magnitude = n.unsigned_abs();
+        let in_range = <<T::Impl as IntegerImpl>::Magnitude>::try_from(3999)
+            .map_or(true, |max| magnitude <= max);
+        if n != T::Impl::ZERO && in_range {
+            let roman = Roman(magnitude.as_usize() as u16, SymbolRepertoire::Unicode, None);
+            return Ok(SignedRoman(value, roman, NegativeStyle::default()));
+        }
+        let max = signed_roman_max::<T::Impl>();
+        let (min, max) = match n.sign() {
+            Sign::Negative => (T::Impl::ZERO - max, T::Impl::ZERO - T::Impl::ONE),
+            Sign::PositiveOrZero => (T::Impl::ONE, max),
+        };
+        Err(OutOfRange {
+            value,
+            min: T::from_impl(min),
+            max: T::from_impl(max),
+        })
+    }
+}
+
+/// Returns the largest value of a signed integer type that can be formatted as Roman numeral.
+fn signed_roman_max<T: IntegerImpl>() -> T {
+    // Only `i8` cannot represent 3999, and all of its positive values are in range.
+    match (T::try_from(3999), T::try_from(i8::MAX as u16)) {
+        (Ok(max), _) | (_, Ok(max)) => max,
+        _ => unreachable!("all signed integer types can represent i8::MAX"),
+    }
+}
+
+impl<T> SignedRoman<T> {
+    /// Sets how negative numbers are marked.
+    #[must_use]
+    pub fn negative_style(mut self, style: NegativeStyle) -> Self {
+        self.2 = style;
+        self
+    }
+
+    /// Uses ASCII symbols instead of the dedicated unicode
+    /// symbols for Roman numerals. See [`Roman::ascii`].
+    #[must_use]
+    pub fn ascii(mut self) -> Self {
+        self.1 = self.1.ascii();
+        self
+    }
+
+    /// Returns a reference to the wrapped value.
+    #[must_use]
+    pub fn as_inner(&self) -> &T {
+        &self.0
+    }
+
+    /// Formats the wrapped value using its standard formatting,
+    /// ignoring the glyphs of this format. See [`Plain`].
+    #[must_use]
+    pub fn plain(self) -> Plain<T> {
+        Plain(self.0)
+    }
+}
+
+impl<T> fmt::Display for SignedRoman<T>
+where
+    T: SignedInteger,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let negative = matches!(self.0.into_impl().sign(), Sign::Negative);
+        match (negative, self.2) {
+            (false, _) => fmt::Display::fmt(&self.1, f),
+            (true, NegativeStyle::Hyphen) => {
+                f.write_char('-')?;
+                fmt::Display::fmt(&self.1, f)
+            }
+            (true, NegativeStyle::Minus) => {
+                f.write_char('\u{2212}')?;
+                fmt::Display::fmt(&self.1, f)
+            }
+            (true, NegativeStyle::Parentheses) => {
+                f.write_char('(')?;
+                fmt::Display::fmt(&self.1, f)?;
+                f.write_char(')')
+            }
+        }
+    }
+}
+
+/// Parses a Roman numeral.
+///
+/// Both uppercase and lowercase symbols are accepted.
+/// The symbol repertoire is detected from the input: A numeral written
+/// using the dedicated unicode symbols is formatted using unicode symbols again,
+/// while a numeral written in ASCII is formatted as ASCII.
+/// Mixing both repertoires in one numeral is an error.
+///
+/// Only numerals in their canonical form are accepted (e.g. `IV` but not `IIII`).
+///
+/// ```
+/// # use fmtastic::{ParseRomanError, Roman};
+/// let roman = Roman::<u16>::try_from("MMXXIV").unwrap();
+/// assert_eq!(2024, *roman.as_inner());
+/// assert_eq!("MMXXIV", roman.to_string());
+/// assert_eq!("ⅯⅯⅩⅩⅠⅤ", Roman::<u16>::try_from("ⅯⅯⅩⅩⅠⅤ").unwrap().to_string());
+/// assert_eq!(Err(ParseRomanError::MixedRepertoire), Roman::<u16>::try_from("ⅯMXXIV"));
+/// ```
+impl<T> TryFrom<&str> for Roman<T>
+where
+    T: UnsignedInteger,
+{
+    type Error = ParseRomanError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let repertoire = detect_repertoire(value)?;
+        let value =
+            T::Impl::try_from(parse_value(value)?).map_err(|_| ParseRomanError::OutOfRange)?;
+        let Roman(value, ..) =
+            Roman::new(T::from_impl(value)).map_err(|_| ParseRomanError::OutOfRange)?;
+        Ok(Roman(value, repertoire, None))
+    }
+}
+
+/// Serializes the value of the numeral, without the symbols used to format it.
+#[cfg(feature = "serde")]
+impl<T> serde::Serialize for Roman<T>
+where
+    T: serde::Serialize,
+{
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize(serializer)
+    }
+}
+
+/// Deserializes the value of the numeral, failing if it
+/// is not between 1 and 3999 (see [`Roman::new`]).
+#[cfg(feature = "serde")]
+impl<'de, T> serde::Deserialize<'de> for Roman<T>
+where
+    T: UnsignedInteger + serde::Deserialize<'de> + fmt::Display,
+{
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Roman::new(T::deserialize(deserializer)?).map_err(serde::de::Error::custom)
+    }
+}
+
+/// Parses a Roman numeral in the same way as `Roman::try_from`,
+/// so that [`str::parse`] can be used.
+///
+/// ```
+/// # use fmtastic::Roman;
+/// let roman: Roman<u16> = "ⅯⅯⅩⅩⅠⅤ".parse().unwrap();
+/// assert_eq!(2024, *roman.as_inner());
+/// assert!("VX".parse::<Roman<u16>>().is_err());
+/// ```
+impl<T> FromStr for Roman<T>
+where
+    T: UnsignedInteger,
+{
+    type Err = ParseRomanError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Roman::try_from(s)
+    }
+}
+
+/// An error which can be returned when parsing a [`Roman`] numeral.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum ParseRomanError {
+    /// The input is empty.
+    Empty,
+    /// The input contains a character that is not a Roman numeral symbol.
+    InvalidSymbol,
+    /// The input mixes ASCII and unicode symbols.
+    MixedRepertoire,
+    /// The symbols are not in their canonical form, e.g. `IIII` instead of `IV`.
+    NotCanonical,
+    /// The value cannot be represented as Roman numeral of the target integer type.
+    OutOfRange,
+}
+
+impl fmt::Display for ParseRomanError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let message = match self {
+            ParseRomanError::Empty => "cannot parse Roman numeral from empty string",
+            ParseRomanError::InvalidSymbol => "invalid symbol found in Roman numeral",
+            ParseRomanError::MixedRepertoire => "Roman numeral mixes ASCII and unicode symbols",
+            ParseRomanError::NotCanonical => "Roman numeral is not in canonical form",
+            ParseRomanError::OutOfRange => "Roman numeral is out of range for the target type",
+        };
+        f.write_str(message)
+    }
+}
+
+fn detect_repertoire(input: &str) -> Result<SymbolRepertoire, ParseRomanError> {
+    let mut repertoire = None;
+    for c in input.chars() {
+        let (_, symbol_repertoire) = parse_symbol(c).ok_or(ParseRomanError::InvalidSymbol)?;
+        match repertoire {
+            Some(r) if r != symbol_repertoire => return Err(ParseRomanError::MixedRepertoire),
+            _ => repertoire = Some(symbol_repertoire),
+        }
+    }
+    repertoire.ok_or(ParseRomanError::Empty)
+}
+
+fn parse_value(input: &str) -> Result<u16, ParseRomanError> {
+    let symbols = || input.chars().filter_map(|c| Some(parse_symbol(c)?.0));
+
+    let mut value = 0_u16;
+    let mut rest = symbols();
+    for (_, _, symbol, _, symbol_value) in ROMAN_PAIRS.iter().copied() {
+        loop {
+            let mut candidate = rest.clone();
+            if !symbol.chars().all(|c| candidate.next() == Some(c)) {
+                break;
+            }
+            value = value
+                .checked_add(symbol_value)
+                .ok_or(ParseRomanError::OutOfRange)?;
+            rest = candidate;
+        }
+    }
+
+    if rest.next().is_none() && canonical_ascii_symbols(value).eq(symbols()) {
+        Ok(value)
+    } else {
+        Err(ParseRomanError::NotCanonical)
+    }
+}
+
+fn canonical_ascii_symbols(value: u16) -> impl Iterator<Item = char> {
+    ROMAN_PAIRS
+        .iter()
+        .scan(value, |n, (_, _, symbol, _, symbol_value)| {
+            let count = *n / symbol_value;
+            *n %= symbol_value;
+            Some(core::iter::repeat(*symbol).take(count.into()))
+        })
+        .flatten()
+        .flat_map(str::chars)
+}
+
+/// Maps a symbol to its uppercase ASCII equivalent.
+fn parse_symbol(c: char) -> Option<(char, SymbolRepertoire)> {
+    match c {
+        'I' | 'V' | 'X' | 'L' | 'C' | 'D' | 'M' => Some((c, SymbolRepertoire::Ascii)),
+        'i' | 'v' | 'x' | 'l' | 'c' | 'd' | 'm' => {
+            Some((c.to_ascii_uppercase(), SymbolRepertoire::Ascii))
+        }
+        'Ⅰ' | 'ⅰ' => Some(('I', SymbolRepertoire::Unicode)),
+        'Ⅴ' | 'ⅴ' => Some(('V', SymbolRepertoire::Unicode)),
+        'Ⅹ' | 'ⅹ' => Some(('X', SymbolRepertoire::Unicode)),
+        'Ⅼ' | 'ⅼ' => Some(('L', SymbolRepertoire::Unicode)),
+        'Ⅽ' | 'ⅽ' => Some(('C', SymbolRepertoire::Unicode)),
+        'Ⅾ' | 'ⅾ' => Some(('D', SymbolRepertoire::Unicode)),
+        'Ⅿ' | 'ⅿ' => Some(('M', SymbolRepertoire::Unicode)),
+        _ => None,
+    }
+}
+
 fn roman_pairs<T>(
     repertoire: SymbolRepertoire,
     lowercase: bool,
@@ -94,8 +700,17 @@ where
                 (SymbolRepertoire::Unicode, true) => lower_unicode,
                 (SymbolRepertoire::Ascii, false) => upper_ascii,
                 (SymbolRepertoire::Ascii, true) => lower_ascii,
+                (SymbolRepertoire::Claudian, _) => match (value, lowercase) {
+                    (900 | 400, _) => return None,
+                    (1000, false) => "CI\u{2183}",
+                    (1000, true) => "ci\u{2184}",
+                    (500, false) => "I\u{2183}",
+                    (500, true) => "i\u{2184}",
+                    (_, false) => upper_ascii,
+                    (_, true) => lower_ascii,
+                },
             };
-            Some((symbol, T::Impl::try_from(value).ok()?.into_public()))
+            Some((symbol, T::from_impl(T::Impl::try_from(value).ok()?)))
         },
     )
 }
@@ -137,10 +752,56 @@ macro_rules! impl_roman_integer {
 
 impl_roman_integer!(u16, u32, u64, u128, usize);
 
+macro_rules! impl_from_roman {
+    ($($ty:ty),*) => {
+        $(
+            impl From<Roman<$ty>> for $ty {
+                fn from(roman: Roman<$ty>) -> Self {
+                    roman.0
+                }
+            }
+        )*
+    }
+}
+
+impl_from_roman!(u8, u16, u32, u64, u128, usize);
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn truncates_to_max_chars() {
+        let roman = Roman::new(3888_u16).unwrap();
+        for (expected, max_chars) in [
+            ("…", 0),
+            ("Ⅿ…", 1),
+            ("ⅯⅯⅯⅮ…", 4),
+            ("ⅯⅯⅯⅮⅭⅭⅭⅬⅩⅩⅩⅤⅠⅠ…", 14),
+            ("ⅯⅯⅯⅮⅭⅭⅭⅬⅩⅩⅩⅤⅠⅠⅠ", 15),
+            ("ⅯⅯⅯⅮⅭⅭⅭⅬⅩⅩⅩⅤⅠⅠⅠ", usize::MAX),
+        ] {
+            assert_eq!(expected, roman.truncate(max_chars).to_string());
+        }
+        assert_eq!("mmmd…", format!("{:#}", roman.ascii().truncate(4)));
+        assert_eq!(
+            "Ⅿ·Ⅿ·…",
+            Roman::new(2024_u16)
+                .unwrap()
+                .interpunct()
+                .truncate(4)
+                .to_string()
+        );
+        assert_eq!(
+            "CIↃ…",
+            Roman::new(1500_u16)
+                .unwrap()
+                .claudian()
+                .truncate(3)
+                .to_string()
+        );
+    }
+
     #[test]
     fn test_to_roman() {
         let roman =
@@ -159,4 +820,340 @@ mod tests {
             "CDXLVIII"
         );
     }
+
+    #[test]
+    fn formats_all_numerals_like_reference() {
+        fn reference(n: u16) -> String {
+            const THOUSANDS: [&str; 4] = ["", "M", "MM", "MMM"];
+            const HUNDREDS: [&str; 10] =
+                ["", "C", "CC", "CCC", "CD", "D", "DC", "DCC", "DCCC", "CM"];
+            const TENS: [&str; 10] = ["", "X", "XX", "XXX", "XL", "L", "LX", "LXX", "LXXX", "XC"];
+            const ONES: [&str; 10] = ["", "I", "II", "III", "IV", "V", "VI", "VII", "VIII", "IX"];
+            let n = usize::from(n);
+            [
+                THOUSANDS[n / 1000],
+                HUNDREDS[n / 100 % 10],
+                TENS[n / 10 % 10],
+                ONES[n % 10],
+            ]
+            .concat()
+        }
+        fn to_unicode(c: char) -> char {
+            let index = "IVXLCDM".find(c).unwrap();
+            "ⅠⅤⅩⅬⅭⅮⅯ".chars().nth(index).unwrap()
+        }
+        for n in 1..=3999_u16 {
+            let expected = reference(n);
+            let roman = Roman::new(n).unwrap();
+            assert_eq!(expected, roman.ascii().to_string());
+            assert_eq!(expected.to_lowercase(), format!("{:#}", roman.ascii()));
+            assert_eq!(
+                expected.chars().map(to_unicode).collect::<String>(),
+                roman.to_string()
+            );
+        }
+    }
+
+    #[test]
+    fn uses_single_precomposed_symbols() {
+        for n in 1..=3999_u16 {
+            let roman = Roman::new(n).unwrap();
+            let upper = roman.to_string();
+            let lower = format!("{roman:#}");
+            assert!(upper.chars().all(|c| "ⅠⅤⅩⅬⅭⅮⅯ".contains(c)), "{upper}");
+            assert!(lower.chars().all(|c| "ⅰⅴⅹⅼⅽⅾⅿ".contains(c)), "{lower}");
+            assert_eq!(roman.ascii().to_string().len(), upper.chars().count());
+            assert_eq!(roman.ascii().to_string().len(), lower.chars().count());
+        }
+        assert_eq!("ⅯⅮⅭ", Roman::new(1600_u16).unwrap().to_string());
+        assert_eq!("ⅿⅾⅽ", format!("{:#}", Roman::new(1600_u16).unwrap()));
+    }
+
+    #[test]
+    fn formats_all_magnitudes_with_single_symbols() {
+        let roman = Roman::new(3888_u16).unwrap();
+        assert_eq!("ⅯⅯⅯⅮⅭⅭⅭⅬⅩⅩⅩⅤⅠⅠⅠ", roman.to_string());
+        assert_eq!("ⅿⅿⅿⅾⅽⅽⅽⅼⅹⅹⅹⅴⅰⅰⅰ", format!("{roman:#}"));
+        assert_eq!("ⅯⅮⅭⅬⅩⅤⅠ", Roman::new(1666_u16).unwrap().to_string());
+    }
+
+    #[test]
+    fn separates_symbols_with_interpunct() {
+        let roman = Roman::new(2024_u16).unwrap();
+        assert_eq!("ⅯⅯⅩⅩⅠⅤ", roman.to_string());
+        assert_eq!("Ⅿ·Ⅿ·Ⅹ·Ⅹ·ⅠⅤ", roman.interpunct().to_string());
+        assert_eq!("MMXXIV", roman.ascii().to_string());
+        assert_eq!("m·m·x·x·iv", format!("{:#}", roman.ascii().interpunct()));
+        assert_eq!("I", Roman::from(1).ascii().interpunct().to_string());
+    }
+
+    #[test]
+    fn maps_value_and_keeps_repertoire() {
+        let roman = Roman::new(9_u32).unwrap().ascii();
+        assert_eq!(&9, roman.as_inner());
+        assert_eq!("X", roman.map(|n| n + 1).unwrap().to_string());
+        assert_eq!(
+            Err(OutOfRange {
+                value: 0,
+                min: 1,
+                max: 3999
+            }),
+            roman.map(|n| n - 9)
+        );
+    }
+
+    #[test]
+    fn round_trips_parsed_numerals() {
+        for input in ["MMXXIV", "ⅯⅯⅩⅩⅠⅤ", "MMMCMXCIX", "I", "ⅭⅮⅩⅬⅤⅠⅠⅠ"]
+        {
+            assert_eq!(input, Roman::<u16>::try_from(input).unwrap().to_string());
+        }
+        assert_eq!(
+            "mmxxiv",
+            format!("{:#}", Roman::<u16>::try_from("mmxxiv").unwrap())
+        );
+    }
+
+    #[test]
+    fn parses_formatted_numerals_from_str() {
+        for n in 1..=3999_u16 {
+            let roman = Roman::new(n).unwrap();
+            for formatted in [
+                roman.to_string(),
+                format!("{roman:#}"),
+                roman.ascii().to_string(),
+                format!("{:#}", roman.ascii()),
+            ] {
+                assert_eq!(Ok(n), formatted.parse::<Roman<u16>>().map(|r| r.0));
+            }
+        }
+        for n in 1..=u8::MAX {
+            assert_eq!(
+                Ok(n),
+                Roman::from(n).to_string().parse().map(|r: Roman<u8>| r.0)
+            );
+        }
+        assert_eq!(
+            Err(ParseRomanError::NotCanonical),
+            "VX".parse::<Roman<u32>>()
+        );
+        assert_eq!(
+            Err(ParseRomanError::OutOfRange),
+            "ⅯⅯⅯⅯ".parse::<Roman<u64>>()
+        );
+    }
+
+    #[test]
+    fn returns_value_of_numeral() {
+        const ROMAN: Roman<u16> = roman!(1999);
+        const VALUE: u16 = ROMAN.value();
+        assert_eq!(1999, VALUE);
+        assert_eq!(255_u8, u8::from(Roman::from(u8::MAX)));
+        assert_eq!(3999_u128, Roman::new(3999_u128).unwrap().value());
+        assert_eq!(
+            4_usize,
+            usize::from(Roman::<usize>::try_from("IV").unwrap())
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_numerals() {
+        for (expected, input) in [
+            (ParseRomanError::Empty, ""),
+            (ParseRomanError::InvalidSymbol, "XIIZ"),
+            (ParseRomanError::InvalidSymbol, " XII"),
+            (ParseRomanError::MixedRepertoire, "ⅩII"),
+            (ParseRomanError::NotCanonical, "IIII"),
+            (ParseRomanError::NotCanonical, "IC"),
+            (ParseRomanError::NotCanonical, "VV"),
+            (ParseRomanError::OutOfRange, "MMMM"),
+        ] {
+            assert_eq!(Err(expected), Roman::<u16>::try_from(input));
+        }
+        assert_eq!(
+            Err(ParseRomanError::OutOfRange),
+            Roman::<u8>::try_from("CCLVI")
+        );
+        assert_eq!(255, *Roman::<u8>::try_from("CCLV").unwrap().as_inner());
+        assert_eq!(
+            Err(ParseRomanError::OutOfRange),
+            "M".repeat(100).parse::<Roman<u16>>()
+        );
+        assert_eq!(
+            Err(ParseRomanError::OutOfRange),
+            "ⅿ".repeat(70).parse::<Roman<u16>>()
+        );
+    }
+
+    #[test]
+    fn creates_numerals_from_constants() {
+        assert_eq!(Roman::new(1_u16), Ok(roman!(1)));
+        assert_eq!(Roman::new(3999_u16), Ok(roman!(3999)));
+        assert_eq!("MMXXIV", roman!(2000 + 24).ascii().to_string());
+    }
+
+    #[test]
+    fn formats_claudian_letters() {
+        for (expected, input) in [
+            ("CCCC", 400_u16),
+            ("IↃ", 500),
+            ("IↃCCCC", 900),
+            ("CIↃ", 1000),
+            ("CIↃCCCCXLIV", 1444),
+            ("CIↃIↃCCCCXCIX", 1999),
+            ("CIↃCIↃCIↃIↃCCCCXCIX", 3999),
+            ("XIV", 14),
+        ] {
+            let roman = Roman::new(input).unwrap().claudian();
+            assert_eq!(expected, roman.to_string());
+            assert_eq!(expected.to_lowercase(), format!("{roman:#}"));
+        }
+        assert_eq!(
+            "CIↃ·IↃ·C·C·C·C·XC·IX",
+            Roman::new(1999_u16)
+                .unwrap()
+                .claudian()
+                .interpunct()
+                .to_string()
+        );
+        let roman = Roman::new(1000_u16).unwrap().claudian();
+        assert_eq!(SymbolRepertoire::Claudian, roman.repertoire());
+        assert_eq!("CIↃI", roman.map(|n| n + 1).unwrap().to_string());
+    }
+
+    #[test]
+    fn formats_negative_numbers_in_each_style() {
+        for (style, expected) in [
+            (NegativeStyle::Hyphen, "-V"),
+            (NegativeStyle::Minus, "\u{2212}V"),
+            (NegativeStyle::Parentheses, "(V)"),
+        ] {
+            let roman = SignedRoman::new(-5).unwrap().negative_style(style);
+            assert_eq!(expected, roman.ascii().to_string());
+            assert_eq!(expected.replace('V', "Ⅴ"), roman.to_string());
+            assert_eq!(expected.replace('V', "v"), format!("{:#}", roman.ascii()));
+            assert_eq!(
+                "V",
+                SignedRoman::new(5)
+                    .unwrap()
+                    .ascii()
+                    .negative_style(style)
+                    .to_string()
+            );
+        }
+    }
+
+    #[test]
+    fn rejects_signed_values_out_of_range() {
+        assert_eq!(
+            Err(OutOfRange {
+                value: 0,
+                min: 1,
+                max: 3999
+            }),
+            SignedRoman::new(0_i32)
+        );
+        assert_eq!(
+            Err(OutOfRange {
+                value: -4000,
+                min: -3999,
+                max: -1
+            }),
+            SignedRoman::new(-4000_i16)
+        );
+        assert_eq!(
+            Err(OutOfRange {
+                value: 4000,
+                min: 1,
+                max: 3999
+            }),
+            SignedRoman::new(4000_i64)
+        );
+        assert_eq!(
+            Err(OutOfRange {
+                value: 0,
+                min: 1,
+                max: i8::MAX
+            }),
+            SignedRoman::new(0_i8)
+        );
+        assert_eq!(
+            "-CXXVIII",
+            SignedRoman::new(i8::MIN).unwrap().ascii().to_string()
+        );
+        assert_eq!(
+            "-MMMCMXCIX",
+            SignedRoman::new(-3999_i128).unwrap().ascii().to_string()
+        );
+        assert!(SignedRoman::new(i128::MIN).is_err());
+    }
+
+    #[test]
+    fn toggles_repertoire() {
+        let roman = Roman::new(14_u16).unwrap();
+        assert_eq!(SymbolRepertoire::Unicode, roman.repertoire());
+        let roman = roman.ascii();
+        assert_eq!(SymbolRepertoire::Ascii, roman.repertoire());
+        assert_eq!("XIV", roman.to_string());
+        let roman = roman.unicode();
+        assert_eq!(SymbolRepertoire::Unicode, roman.repertoire());
+        assert_eq!("ⅩⅠⅤ", roman.to_string());
+        let roman = roman.ascii();
+        assert_eq!(SymbolRepertoire::Ascii, roman.repertoire());
+        assert_eq!("XIV", roman.to_string());
+        assert_eq!(
+            SymbolRepertoire::Ascii,
+            Roman::<u16>::try_from("xiv").unwrap().repertoire()
+        );
+    }
+
+    #[test]
+    fn rejects_values_out_of_range() {
+        let out_of_range = |value| OutOfRange {
+            value,
+            min: 1,
+            max: 3999,
+        };
+        assert_eq!(Err(out_of_range(0_u32)), Roman::new(0_u32));
+        assert!(Roman::new(1_u32).is_ok());
+        assert!(Roman::new(3999_u32).is_ok());
+        assert_eq!(Err(out_of_range(4000_u32)), Roman::new(4000_u32));
+        assert_eq!(
+            Err(OutOfRange {
+                value: 0,
+                min: 1,
+                max: u8::MAX
+            }),
+            Roman::new(0_u8)
+        );
+        assert!(Roman::new(u8::MAX).is_ok());
+    }
+
+    // `u16` stands in for `usize` on 16-bit targets.
+    #[test]
+    fn formats_bounds_of_16_bit_integers() {
+        assert_eq!(
+            "MMMCMXCIX",
+            Roman::new(3999_u16).unwrap().ascii().to_string()
+        );
+        assert_eq!(
+            Err(OutOfRange {
+                value: u16::MAX,
+                min: 1,
+                max: 3999
+            }),
+            Roman::new(u16::MAX)
+        );
+        assert_eq!(
+            3999,
+            *Roman::<u16>::try_from("MMMCMXCIX").unwrap().as_inner()
+        );
+    }
+
+    #[test]
+    #[cfg(target_pointer_width = "16")]
+    fn formats_bounds_of_usize_on_16_bit_targets() {
+        assert!(Roman::new(3999_usize).is_ok());
+        assert!(Roman::new(usize::MAX).is_err());
+    }
 }
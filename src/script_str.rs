@@ -0,0 +1,232 @@
+use crate::{subscript_digit, superscript_digit};
+use core::fmt::{self, Write};
+
+/// Formats a string as superscript, e.g. for exponents such as `xⁿ`.
+///
+/// Each character is mapped using [`superscript_char`]: ASCII digits,
+/// the signs `+`, `-` and `=`, parentheses and most ASCII letters have a superscript form.
+/// All other characters, including spaces, are written unchanged.
+///
+/// The superscript letters are mostly modifier letters from the [Spacing Modifier Letters]
+/// and [Phonetic Extensions] blocks, so their size and baseline vary between fonts.
+/// There is no superscript form of `q`, `C`, `F`, `Q`, `S`, `X`, `Y` and `Z`.
+/// Unicode 14 added modifier letters for `q`, `C`, `F` and `Q`, but as hardly any fonts support them yet,
+/// these are not used.
+///
+/// [Spacing Modifier Letters]: https://www.unicode.org/charts/PDF/U02B0.pdf
+/// [Phonetic Extensions]: https://www.unicode.org/charts/PDF/U1D00.pdf
+///
+/// ```
+/// # use fmtastic::SuperscriptStr;
+/// assert_eq!("xⁿ", format!("x{}", SuperscriptStr("n")));
+/// assert_eq!("eⁱᵖⁱ", format!("e{}", SuperscriptStr("ipi")));
+/// assert_eq!("ᵀᴹ", SuperscriptStr("TM").to_string());
+/// assert_eq!("⁽ⁿ⁻¹⁾", SuperscriptStr("(n-1)").to_string());
+/// assert_eq!("ʸᵉˢ/ⁿᵒ", SuperscriptStr("yes/no").to_string()); // `/` is passed through
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct SuperscriptStr<'a>(pub &'a str);
+
+impl<'a> SuperscriptStr<'a> {
+    /// Returns the wrapped string.
+    #[must_use]
+    pub fn as_inner(&self) -> &'a str {
+        self.0
+    }
+
+    /// Returns `true` if every character has a superscript form,
+    /// i.e. no character is passed through unchanged.
+    ///
+    /// ```
+    /// # use fmtastic::SuperscriptStr;
+    /// assert!(SuperscriptStr("n+1").is_fully_mapped());
+    /// assert!(!SuperscriptStr("XY").is_fully_mapped());
+    /// ```
+    #[must_use]
+    pub fn is_fully_mapped(&self) -> bool {
+        self.0.chars().all(|c| superscript_char(c).is_some())
+    }
+}
+
+impl fmt::Display for SuperscriptStr<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_script_str(self.0, superscript_char, f)
+    }
+}
+
+/// Formats a string as subscript, e.g. for indices such as `xᵢ`.
+///
+/// Each character is mapped using [`subscript_char`]: ASCII digits,
+/// the signs `+`, `-` and `=`, parentheses and some lowercase ASCII letters have a subscript form.
+/// All other characters, including spaces, are written unchanged.
+///
+/// Subscript letters only exist for `a`, `e`, `h`, `i`, `j`, `k`, `l`, `m`, `n`, `o`, `p`,
+/// `r`, `s`, `t`, `u`, `v` and `x`. There is no subscript form of the other lowercase letters
+/// (`b`, `c`, `d`, `f`, `g`, `q`, `w`, `y` and `z`) and of any uppercase letter.
+///
+/// ```
+/// # use fmtastic::SubscriptStr;
+/// assert_eq!("xᵢ", format!("x{}", SubscriptStr("i")));
+/// assert_eq!("aₙ₊₁", format!("a{}", SubscriptStr("n+1")));
+/// assert_eq!("Vₘₐₓ", format!("V{}", SubscriptStr("max")));
+/// assert_eq!("ₐB", SubscriptStr("aB").to_string()); // `B` is passed through
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct SubscriptStr<'a>(pub &'a str);
+
+impl<'a> SubscriptStr<'a> {
+    /// Returns the wrapped string.
+    #[must_use]
+    pub fn as_inner(&self) -> &'a str {
+        self.0
+    }
+
+    /// Returns `true` if every character has a subscript form,
+    /// i.e. no character is passed through unchanged.
+    ///
+    /// ```
+    /// # use fmtastic::SubscriptStr;
+    /// assert!(SubscriptStr("i+j").is_fully_mapped());
+    /// assert!(!SubscriptStr("y").is_fully_mapped());
+    /// ```
+    #[must_use]
+    pub fn is_fully_mapped(&self) -> bool {
+        self.0.chars().all(|c| subscript_char(c).is_some())
+    }
+}
+
+impl fmt::Display for SubscriptStr<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_script_str(self.0, subscript_char, f)
+    }
+}
+
+fn fmt_script_str(
+    s: &str,
+    map: fn(char) -> Option<char>,
+    f: &mut fmt::Formatter<'_>,
+) -> fmt::Result {
+    s.chars()
+        .try_for_each(|c| f.write_char(map(c).unwrap_or(c)))
+}
+
+/// Maps an ASCII character to its superscript form, e.g. `'n'` to `'ⁿ'`.
+/// Returns [`None`] for characters without a superscript form. See [`SuperscriptStr`].
+///
+/// ```
+/// # use fmtastic::superscript_char;
+/// assert_eq!(Some('ⁿ'), superscript_char('n'));
+/// assert_eq!(Some('²'), superscript_char('2'));
+/// assert_eq!(Some('⁻'), superscript_char('-'));
+/// assert_eq!(None, superscript_char('Q'));
+/// ```
+#[must_use]
+pub fn superscript_char(c: char) -> Option<char> {
+    let glyph = match c {
+        '0'..='9' => return superscript_digit(c),
+        'a'..='z' => SUPERSCRIPT_LOWERCASE[c as usize - 'a' as usize],
+        'A'..='Z' => SUPERSCRIPT_UPPERCASE[c as usize - 'A' as usize],
+        '+' => '⁺',
+        '-' => '⁻',
+        '=' => '⁼',
+        '(' => '⁽',
+        ')' => '⁾',
+        _ => NONE,
+    };
+    (glyph != NONE).then_some(glyph)
+}
+
+/// Maps an ASCII character to its subscript form, e.g. `'i'` to `'ᵢ'`.
+/// Returns [`None`] for characters without a subscript form. See [`SubscriptStr`].
+///
+/// ```
+/// # use fmtastic::subscript_char;
+/// assert_eq!(Some('ᵢ'), subscript_char('i'));
+/// assert_eq!(Some('₂'), subscript_char('2'));
+/// assert_eq!(Some('₊'), subscript_char('+'));
+/// assert_eq!(None, subscript_char('A'));
+/// ```
+#[must_use]
+pub fn subscript_char(c: char) -> Option<char> {
+    let glyph = match c {
+        '0'..='9' => return subscript_digit(c),
+        'a'..='z' => SUBSCRIPT_LOWERCASE[c as usize - 'a' as usize],
+        '+' => '₊',
+        '-' => '₋',
+        '=' => '₌',
+        '(' => '₍',
+        ')' => '₎',
+        _ => NONE,
+    };
+    (glyph != NONE).then_some(glyph)
+}
+
+/// Marks letters without a form in the tables below.
+const NONE: char = '\0';
+
+const SUPERSCRIPT_LOWERCASE: [char; 26] = [
+    'ᵃ', 'ᵇ', 'ᶜ', 'ᵈ', 'ᵉ', 'ᶠ', 'ᵍ', 'ʰ', 'ⁱ', 'ʲ', 'ᵏ', 'ˡ', 'ᵐ', 'ⁿ', 'ᵒ', 'ᵖ', NONE, 'ʳ', 'ˢ',
+    'ᵗ', 'ᵘ', 'ᵛ', 'ʷ', 'ˣ', 'ʸ', 'ᶻ',
+];
+
+const SUPERSCRIPT_UPPERCASE: [char; 26] = [
+    'ᴬ', 'ᴮ', NONE, 'ᴰ', 'ᴱ', NONE, 'ᴳ', 'ᴴ', 'ᴵ', 'ᴶ', 'ᴷ', 'ᴸ', 'ᴹ', 'ᴺ', 'ᴼ', 'ᴾ', NONE, 'ᴿ',
+    NONE, 'ᵀ', 'ᵁ', 'ⱽ', 'ᵂ', NONE, NONE, NONE,
+];
+
+const SUBSCRIPT_LOWERCASE: [char; 26] = [
+    'ₐ', NONE, NONE, NONE, 'ₑ', NONE, NONE, 'ₕ', 'ᵢ', 'ⱼ', 'ₖ', 'ₗ', 'ₘ', 'ₙ', 'ₒ', 'ₚ', NONE, 'ᵣ',
+    'ₛ', 'ₜ', 'ᵤ', 'ᵥ', NONE, 'ₓ', NONE, NONE,
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn documents_unmapped_letters() {
+        let unmapped = |map: fn(char) -> Option<char>| {
+            ('a'..='z')
+                .chain('A'..='Z')
+                .filter(|c| map(*c).is_none())
+                .collect::<String>()
+        };
+        assert_eq!("qCFQSXYZ", unmapped(superscript_char));
+        assert_eq!(
+            "bcdfgqwyzABCDEFGHIJKLMNOPQRSTUVWXYZ",
+            unmapped(subscript_char)
+        );
+    }
+
+    #[test]
+    fn maps_each_char_to_a_distinct_glyph() {
+        for map in [superscript_char, subscript_char] {
+            let glyphs: Vec<_> = (' '..='~').filter_map(map).collect();
+            for (index, glyph) in glyphs.iter().enumerate() {
+                assert!(!glyphs[..index].contains(glyph), "{glyph}");
+                assert!(!glyph.is_ascii());
+            }
+        }
+    }
+
+    #[test]
+    fn passes_through_unmapped_chars() {
+        assert_eq!("", SuperscriptStr("").to_string());
+        assert_eq!("ᵃ ᵇ", SuperscriptStr("a b").to_string());
+        assert_eq!("ˣ·ʸ", SuperscriptStr("x·y").to_string());
+        assert_eq!("Xₘₐₓ!", SubscriptStr("Xmax!").to_string());
+        assert_eq!("₍ᵢ,ⱼ₎", SubscriptStr("(i,j)").to_string());
+    }
+
+    #[test]
+    fn matches_integer_formatting() {
+        assert_eq!(
+            crate::Superscript(-120).to_string(),
+            SuperscriptStr("-120").to_string()
+        );
+        assert_eq!(
+            format!("{:+}", crate::Subscript(42)),
+            SubscriptStr("+42").to_string()
+        );
+    }
+}
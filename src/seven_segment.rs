@@ -1,7 +1,7 @@
-use crate::digits::iter_digits;
+use crate::digits::{digit_from_char, fmt_with, iter_digits, max_digits};
 use crate::integer::{Base, IntegerImpl};
-use crate::UnsignedInteger;
-use core::fmt;
+use crate::{Enclosed, Enclosure, ParseDigitError, Plain, UnsignedInteger};
+use core::fmt::{self, Write};
 
 /// Formats an unsigned integer using seven-segment digits
 /// from the [Legacy Computing] block.
@@ -34,9 +34,184 @@ use core::fmt;
 /// assert_eq!("🯰", format!("{:b}", Segmented(0_u8)));
 /// assert_eq!("🯱🯰🯱🯰🯱🯰", format!("{:+b}", Segmented(0b101010_u8)));
 /// ```
+///
+/// ## Layout
+/// `Segmented<T>` is `#[repr(transparent)]`, so it has the same size, alignment and ABI as `T`.
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+#[repr(transparent)]
 pub struct Segmented<T>(pub T);
 
+impl<T> Segmented<T> {
+    /// Returns a reference to the wrapped value.
+    #[must_use]
+    pub fn as_inner(&self) -> &T {
+        &self.0
+    }
+
+    /// Applies a function to the wrapped value.
+    ///
+    /// ```
+    /// # use fmtastic::Segmented;
+    /// assert_eq!(Segmented(4), Segmented(3_u8).map(|n| n + 1));
+    /// ```
+    #[must_use]
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> Segmented<U> {
+        Segmented(f(self.0))
+    }
+
+    /// Formats the wrapped value using its standard formatting,
+    /// ignoring the glyphs of this format. See [`Plain`].
+    #[must_use]
+    pub fn plain(self) -> Plain<T> {
+        Plain(self.0)
+    }
+
+    /// Appends a combining enclosing mark after each digit, see [`Enclosed`].
+    #[must_use]
+    pub fn enclosed(self, enclosure: Enclosure) -> Enclosed<Self> {
+        Enclosed(self, enclosure)
+    }
+
+    /// Sets the glyphs used to draw the digits, see [`SegmentStyle`].
+    ///
+    /// ```
+    /// use fmtastic::{SegmentStyle, Segmented};
+    ///
+    /// assert_eq!("🯲", Segmented(2_u8).style(SegmentStyle::LegacyComputing).to_string());
+    /// assert_eq!("╶─┐\n┌─┘\n└─╴", Segmented(2_u8).style(SegmentStyle::BoxDrawing).to_string());
+    /// ```
+    #[must_use]
+    pub fn style(self, style: SegmentStyle) -> StyledSegmented<T> {
+        StyledSegmented(self.0, style)
+    }
+}
+
+/// The glyphs used to draw [`Segmented`] digits, see [`Segmented::style`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+#[non_exhaustive]
+pub enum SegmentStyle {
+    /// The dedicated seven-segment digits from the [Legacy Computing] block, e.g. `🯲`.
+    /// Each digit is a single character, so numbers can be used within a line of text,
+    /// but few fonts support these digits.
+    ///
+    /// [Legacy Computing]: https://www.unicode.org/charts/PDF/U1FB00.pdf
+    #[default]
+    LegacyComputing,
+    /// Digits drawn from characters of the [Box Drawing] block over three lines,
+    /// three columns per digit with a space between digits. The lines are separated by `\n`.
+    /// Box drawing characters are supported by most monospace fonts,
+    /// but the output spans multiple lines and only lines up in a monospace font.
+    /// The digits are best-effort approximations of seven segments, e.g. for 2:
+    ///
+    /// ```text
+    /// ╶─┐
+    /// ┌─┘
+    /// └─╴
+    /// ```
+    ///
+    /// [Box Drawing]: https://www.unicode.org/charts/PDF/U2500.pdf
+    BoxDrawing,
+}
+
+/// A [`Segmented`] number drawn in a [`SegmentStyle`], created by [`Segmented::style`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct StyledSegmented<T>(T, SegmentStyle);
+
+impl<T> StyledSegmented<T> {
+    /// Returns the style used to draw the digits.
+    #[must_use]
+    pub fn segment_style(&self) -> SegmentStyle {
+        self.1
+    }
+
+    /// Returns a reference to the wrapped value.
+    #[must_use]
+    pub fn as_inner(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T> fmt::Binary for StyledSegmented<T>
+where
+    T: UnsignedInteger,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_styled::<_, <T::Impl as IntegerImpl>::BaseTwo>(self.0.into_impl(), self.1, f)
+    }
+}
+
+impl<T> fmt::Display for StyledSegmented<T>
+where
+    T: UnsignedInteger,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_styled::<_, <T::Impl as IntegerImpl>::BaseTen>(self.0.into_impl(), self.1, f)
+    }
+}
+
+fn fmt_styled<T: IntegerImpl, B: Base<T::Magnitude>>(
+    n: T,
+    style: SegmentStyle,
+    f: &mut fmt::Formatter<'_>,
+) -> fmt::Result {
+    match style {
+        SegmentStyle::LegacyComputing => fmt_seven_segment::<T, B>(n, f),
+        SegmentStyle::BoxDrawing => fmt_box_drawing::<T, B>(n, f),
+    }
+}
+
+fn fmt_box_drawing<T: IntegerImpl, B: Base<T::Magnitude>>(
+    n: T,
+    f: &mut fmt::Formatter<'_>,
+) -> fmt::Result {
+    for row in 0..3 {
+        if row > 0 {
+            f.write_char('\n')?;
+        }
+        for (index, digit) in iter_digits::<_, B>(n).enumerate() {
+            if index > 0 {
+                f.write_char(' ')?;
+            }
+            box_drawing_row(SEGMENTS[digit], row)
+                .into_iter()
+                .try_for_each(|c| f.write_char(c))?;
+        }
+    }
+    Ok(())
+}
+
+/// Draws one of the three rows of a digit. Each row consists of a joint on the left,
+/// a horizontal segment in the middle and a joint on the right.
+/// The joints connect the horizontal segment of the row with
+/// the vertical segments above and below.
+fn box_drawing_row(segments: u8, row: usize) -> [char; 3] {
+    let lit = |segment: u8| segments & (1 << segment) != 0;
+    let (a, b, c, d, e, f, g) = (lit(0), lit(1), lit(2), lit(3), lit(4), lit(5), lit(6));
+    let (horizontal, up_left, down_left, up_right, down_right) = match row {
+        0 => (a, false, f, false, b),
+        1 => (g, f, e, b, c),
+        _ => (d, e, false, c, false),
+    };
+    [
+        joint(up_left, down_left, false, horizontal),
+        if horizontal { '─' } else { ' ' },
+        joint(up_right, down_right, horizontal, false),
+    ]
+}
+
+/// Returns the box drawing character connecting the given directions.
+fn joint(up: bool, down: bool, left: bool, right: bool) -> char {
+    const JOINTS: [char; 16] = [
+        ' ', '╵', '╷', '│', '╴', '┘', '┐', '┤', '╶', '└', '┌', '├', '─', '┴', '┬', '┼',
+    ];
+    JOINTS[usize::from(up)
+        | usize::from(down) << 1
+        | usize::from(left) << 2
+        | usize::from(right) << 3]
+}
+
 impl<T> From<T> for Segmented<T>
 where
     T: UnsignedInteger,
@@ -46,6 +221,73 @@ where
     }
 }
 
+/// Converts an ASCII digit (`'0'` to `'9'`) to a single digit.
+///
+/// ## Errors
+/// Returns a [`ParseDigitError`] if the character is not an ASCII digit.
+///
+/// ```
+/// # use fmtastic::Segmented;
+/// assert_eq!("🯷", Segmented::<u8>::try_from('7').unwrap().to_string());
+/// assert!(Segmented::<u8>::try_from('x').is_err());
+/// ```
+impl<T> TryFrom<char> for Segmented<T>
+where
+    T: UnsignedInteger,
+{
+    type Error = ParseDigitError;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        digit_from_char(value).map(Segmented)
+    }
+}
+
+impl<T> Segmented<T>
+where
+    T: UnsignedInteger,
+{
+    /// The largest number of bytes written when formatting a value of type `T`
+    /// using any of the formatting traits, for sizing buffers.
+    ///
+    /// The longest output is the binary representation of the largest value,
+    /// with one digit per bit of `T`. Each digit is encoded in four bytes.
+    ///
+    /// ```
+    /// # use fmtastic::Segmented;
+    /// assert_eq!(32 * 4, Segmented::<u32>::MAX_LEN);
+    /// assert_eq!(Segmented::<u32>::MAX_LEN, format!("{:b}", Segmented(u32::MAX)).len());
+    /// ```
+    pub const MAX_LEN: usize = max_digits(<T::Impl as IntegerImpl>::BITS, 2) * 4;
+
+    /// Returns the seven-segment pattern of each decimal digit,
+    /// starting with the most significant digit.
+    ///
+    /// Each pattern is a bit mask where a set bit means that the segment is lit.
+    /// The segments are labelled `a` through `g` in the usual way
+    /// (`a` is the top segment, then clockwise with `g` in the middle)
+    /// and are stored in the lower seven bits, `a` in bit 0 up to `g` in bit 6.
+    /// The most significant bit is always unset.
+    ///
+    /// ```text
+    ///  ─a─
+    /// f   b
+    ///  ─g─
+    /// e   c
+    ///  ─d─
+    /// ```
+    ///
+    /// ```
+    /// use fmtastic::Segmented;
+    ///
+    /// let segments: Vec<u8> = Segmented(628_u32).segments().collect();
+    /// assert_eq!(vec![0b111_1101, 0b101_1011, 0b111_1111], segments);
+    /// ```
+    pub fn segments(&self) -> impl Iterator<Item = u8> {
+        iter_digits::<_, <T::Impl as IntegerImpl>::BaseTen>(self.0.into_impl())
+            .map(|digit| SEGMENTS[digit])
+    }
+}
+
 impl<T> fmt::Binary for Segmented<T>
 where
     T: UnsignedInteger,
@@ -64,8 +306,68 @@ where
     }
 }
 
-fn fmt_seven_segment<T: IntegerImpl, B: Base<T>>(n: T, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-    iter_digits::<_, B>(n).try_for_each(|digit| write!(f, "{}", DIGITS[digit]))
+pub(crate) fn fmt_seven_segment<T: IntegerImpl, B: Base<T::Magnitude>>(
+    n: T,
+    f: &mut fmt::Formatter<'_>,
+) -> fmt::Result {
+    fmt_with(n.into_public(), B::VALUE.as_usize() as u32, &DIGITS, f)
+}
+
+/// Formats a time of day as `HH:MM` using seven-segment digits for hours and minutes,
+/// e.g. `🯱🯲:🯳🯴`.
+///
+/// Hours and minutes are padded with a leading zero to two digits.
+/// The values are not validated, so e.g. `25:61` is formatted as is.
+///
+/// The colon is an ordinary colon (`:`), as there is no seven-segment colon.
+/// Depending on the font, the colon can look cramped next to the digits.
+/// A spacer can be written on both sides of the colon using [`SegmentedClock::spacer`],
+/// such as a thin space (U+2009). By default, no spacer is written.
+///
+/// ```
+/// # use fmtastic::SegmentedClock;
+/// assert_eq!("🯱🯲:🯳🯴", SegmentedClock::new(12, 34).to_string());
+/// assert_eq!("🯰🯷\u{2009}:\u{2009}🯰🯵", SegmentedClock::new(7, 5).spacer("\u{2009}").to_string());
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct SegmentedClock {
+    hours: u8,
+    minutes: u8,
+    spacer: &'static str,
+}
+
+impl SegmentedClock {
+    /// Creates a clock showing the given hours and minutes.
+    #[must_use]
+    pub fn new(hours: u8, minutes: u8) -> Self {
+        Self {
+            hours,
+            minutes,
+            spacer: "",
+        }
+    }
+
+    /// Sets the spacer written before and after the colon.
+    /// Use an empty string to disable the spacing.
+    #[must_use]
+    pub fn spacer(mut self, spacer: &'static str) -> Self {
+        self.spacer = spacer;
+        self
+    }
+}
+
+impl fmt::Display for SegmentedClock {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.hours < 10 {
+            f.write_str(DIGITS[0])?;
+        }
+        write!(f, "{}", Segmented(self.hours))?;
+        write!(f, "{spacer}:{spacer}", spacer = self.spacer)?;
+        if self.minutes < 10 {
+            f.write_str(DIGITS[0])?;
+        }
+        write!(f, "{}", Segmented(self.minutes))
+    }
 }
 
 const DIGITS: [&str; 10] = [
@@ -80,3 +382,132 @@ const DIGITS: [&str; 10] = [
     "\u{1FBF8}",
     "\u{1FBF9}",
 ];
+
+// Segment masks for the digits 0-9, segment a is bit 0, g is bit 6.
+const SEGMENTS: [u8; 10] = [
+    0b011_1111, 0b000_0110, 0b101_1011, 0b100_1111, 0b110_0110, 0b110_1101, 0b111_1101, 0b000_0111,
+    0b111_1111, 0b110_1111,
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn output_never_exceeds_max_len() {
+        fn max_len<F: fmt::Display + fmt::Binary>(f: F) -> usize {
+            format!("{f}").len().max(format!("{f:b}").len())
+        }
+        assert!((0..=u8::MAX).all(|n| max_len(Segmented(n)) <= Segmented::<u8>::MAX_LEN));
+        assert!((0..=u16::MAX).all(|n| max_len(Segmented(n)) <= Segmented::<u16>::MAX_LEN));
+        for n in [0, 1, 9, 10, u32::MAX / 2, u32::MAX - 1, u32::MAX] {
+            assert!(max_len(Segmented(n)) <= Segmented::<u32>::MAX_LEN);
+        }
+        assert_eq!(Segmented::<u128>::MAX_LEN, max_len(Segmented(u128::MAX)));
+    }
+
+    #[test]
+    fn switches_segment_style_for_single_digit() {
+        assert_eq!(
+            "🯸",
+            Segmented(8_u8)
+                .style(SegmentStyle::LegacyComputing)
+                .to_string()
+        );
+        assert_eq!(
+            "┌─┐\n├─┤\n└─┘",
+            Segmented(8_u8).style(SegmentStyle::BoxDrawing).to_string()
+        );
+        assert_eq!(
+            "  ╷\n  │\n  ╵",
+            Segmented(1_u8).style(SegmentStyle::BoxDrawing).to_string()
+        );
+        assert_eq!(
+            "┌─┐\n│ │\n└─┘",
+            Segmented(0_u8).style(SegmentStyle::BoxDrawing).to_string()
+        );
+        assert_eq!(
+            SegmentStyle::BoxDrawing,
+            Segmented(0_u8)
+                .style(SegmentStyle::BoxDrawing)
+                .segment_style()
+        );
+    }
+
+    #[test]
+    fn draws_all_digits_with_box_drawing() {
+        let expected = [
+            "  ╷ ╶─┐ ╶─┐ ╷ ╷ ┌─╴ ┌─╴ ╶─┐ ┌─┐ ┌─┐ ┌─┐",
+            "  │ ┌─┘ ╶─┤ └─┤ └─┐ ├─┐   │ ├─┤ └─┤ │ │",
+            "  ╵ └─╴ ╶─┘   ╵ ╶─┘ └─┘   ╵ └─┘ ╶─┘ └─┘",
+        ];
+        let drawn = Segmented(1234567890_u32).style(SegmentStyle::BoxDrawing);
+        assert_eq!(expected.join("\n"), drawn.to_string());
+        assert_eq!(
+            "  ╷ ┌─┐\n  │ │ │\n  ╵ └─┘",
+            format!("{:b}", Segmented(2_u8).style(SegmentStyle::BoxDrawing))
+        );
+    }
+
+    #[test]
+    fn converts_ascii_digits() {
+        for (c, expected) in ('0'..='9').zip(0_u8..) {
+            assert_eq!(Ok(Segmented(expected)), Segmented::try_from(c));
+        }
+        for c in ['a', 'x', ' ', '٣', '-'] {
+            assert!(Segmented::<u32>::try_from(c).is_err());
+        }
+    }
+
+    #[test]
+    fn formats_precomputed_digits() {
+        let mut output = String::new();
+        crate::fmt_digits([6, 2, 8, 0], &DIGITS, &mut output).unwrap();
+        assert_eq!("🯶🯲🯸🯰", output);
+        assert_eq!(Segmented(6280_u32).to_string(), output);
+    }
+
+    #[test]
+    fn formats_clock_with_and_without_spacer() {
+        assert_eq!("🯰🯰:🯰🯰", SegmentedClock::new(0, 0).to_string());
+        assert_eq!("🯲🯳:🯵🯹", SegmentedClock::new(23, 59).to_string());
+        assert_eq!(
+            "🯱🯲\u{2009}:\u{2009}🯳🯴",
+            SegmentedClock::new(12, 34).spacer("\u{2009}").to_string()
+        );
+        assert_eq!(
+            "🯰🯹:🯰🯸",
+            SegmentedClock::new(9, 8).spacer(" ").spacer("").to_string()
+        );
+        assert_eq!("🯱🯰🯰:🯱🯲🯳", SegmentedClock::new(100, 123).to_string());
+    }
+
+    #[test]
+    fn returns_standard_segment_masks_for_digits() {
+        for (digit, expected) in [
+            (0_u8, 0x3F),
+            (1, 0x06),
+            (2, 0x5B),
+            (3, 0x4F),
+            (4, 0x66),
+            (5, 0x6D),
+            (6, 0x7D),
+            (7, 0x07),
+            (8, 0x7F),
+            (9, 0x6F),
+        ] {
+            assert_eq!(
+                vec![expected],
+                Segmented(digit).segments().collect::<Vec<_>>()
+            );
+        }
+    }
+
+    #[test]
+    fn returns_segment_masks_for_each_digit() {
+        assert_eq!(
+            vec![0x06, 0x3F, 0x3F],
+            Segmented(100_u64).segments().collect::<Vec<_>>()
+        );
+    }
+}
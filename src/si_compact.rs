@@ -0,0 +1,224 @@
+use crate::integer::{IntegerImpl, Sign};
+use crate::{Integer, Plain};
+use core::fmt::{self, Write};
+
+/// Formats an integer compactly using an [SI prefix], e.g. `1.5k` or `3.4M`.
+///
+/// The value is divided by the largest power of 1000 that is not greater than it
+/// and written with at most one fractional digit, followed by the prefix.
+/// The fractional digit is omitted when it is zero, e.g. `12k`.
+/// Values below 1000 are written as is, without a prefix.
+///
+/// The mantissa is rounded to the nearest tenth (halfway cases are rounded away from zero).
+/// When rounding reaches the next power of 1000, the next prefix is used,
+/// so `999_960` is formatted as `1M` rather than `1000k`.
+/// Values beyond the largest prefix (quetta) keep using it, e.g. `340282366.9Q` for [`u128::MAX`].
+///
+/// Negative numbers are prefixed with `-`.
+///
+/// | Power  | Prefix | Superscript |
+/// |--------|--------|-------------|
+/// | 1000¹  | `k`    | `ᵏ`         |
+/// | 1000²  | `M`    | `ᴹ`         |
+/// | 1000³  | `G`    | `ᴳ`         |
+/// | 1000⁴  | `T`    | `ᵀ`         |
+/// | 1000⁵  | `P`    | `ᴾ`         |
+/// | 1000⁶  | `E`    | `ᴱ`         |
+/// | 1000⁷  | `Z`    | `Z`         |
+/// | 1000⁸  | `Y`    | `Y`         |
+/// | 1000⁹  | `R`    | `ᴿ`         |
+/// | 1000¹⁰ | `Q`    | `Q`         |
+///
+/// See [`SiCompact::superscript`] for writing the prefix as superscript.
+///
+/// ```
+/// # use fmtastic::SiCompact;
+/// assert_eq!("1.5k", SiCompact(1500).to_string());
+/// assert_eq!("3.4M", SiCompact(3_400_000).to_string());
+/// assert_eq!("12k", SiCompact(12_000).to_string());
+/// assert_eq!("999", SiCompact(999).to_string());
+/// assert_eq!("-2.5G", SiCompact(-2_500_000_000_i64).to_string());
+/// ```
+///
+/// [SI prefix]: https://en.wikipedia.org/wiki/Metric_prefix
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct SiCompact<T>(pub T);
+
+impl<T> SiCompact<T> {
+    /// Writes the prefix as superscript, e.g. `1.5ᵏ`.
+    ///
+    /// There are no superscript forms of the capital letters `Z`, `Y` and `Q`,
+    /// so these prefixes are written as regular letters.
+    ///
+    /// ```
+    /// # use fmtastic::SiCompact;
+    /// assert_eq!("1.5ᵏ", SiCompact(1500).superscript().to_string());
+    /// assert_eq!("3.4ᴹ", SiCompact(3_400_000).superscript().to_string());
+    /// ```
+    #[must_use]
+    pub fn superscript(self) -> SuperscriptSiCompact<T> {
+        SuperscriptSiCompact(self.0)
+    }
+
+    /// Returns a reference to the wrapped value.
+    #[must_use]
+    pub fn as_inner(&self) -> &T {
+        &self.0
+    }
+
+    /// Applies a function to the wrapped value.
+    #[must_use]
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> SiCompact<U> {
+        SiCompact(f(self.0))
+    }
+
+    /// Formats the wrapped value using its standard formatting,
+    /// ignoring the glyphs of this format. See [`Plain`].
+    #[must_use]
+    pub fn plain(self) -> Plain<T> {
+        Plain(self.0)
+    }
+}
+
+impl<T> From<T> for SiCompact<T>
+where
+    T: Integer,
+{
+    fn from(value: T) -> Self {
+        SiCompact(value)
+    }
+}
+
+impl<T> fmt::Display for SiCompact<T>
+where
+    T: Integer,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_si_compact(self.0.into_impl(), &PREFIXES, f)
+    }
+}
+
+/// An [`SiCompact`] number with the prefix written as superscript,
+/// created by [`SiCompact::superscript`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct SuperscriptSiCompact<T>(pub T);
+
+impl<T> fmt::Display for SuperscriptSiCompact<T>
+where
+    T: Integer,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_si_compact(self.0.into_impl(), &SUPERSCRIPT_PREFIXES, f)
+    }
+}
+
+fn fmt_si_compact<T: IntegerImpl>(
+    n: T,
+    prefixes: &[&str; 10],
+    f: &mut fmt::Formatter<'_>,
+) -> fmt::Result {
+    if let Sign::Negative = n.sign() {
+        f.write_char('-')?;
+    }
+    let magnitude = n.unsigned_abs();
+    match scale_to_prefix(magnitude) {
+        None => write!(f, "{magnitude}"),
+        Some((tenths, prefix)) => {
+            let Ok(ten) = <T::Magnitude>::try_from(10) else {
+                unreachable!("all integer types can represent ten")
+            };
+            write!(f, "{}", tenths / ten)?;
+            let fraction = tenths % ten;
+            if fraction != <T::Magnitude>::ZERO {
+                write!(f, ".{fraction}")?;
+            }
+            f.write_str(prefixes[prefix])
+        }
+    }
+}
+
+/// Returns the magnitude in tenths of the chosen prefix and the index of the prefix,
+/// or [`None`] if the magnitude is below 1000.
+fn scale_to_prefix<T: IntegerImpl>(magnitude: T) -> Option<(T, usize)> {
+    let thousand = T::try_from(1000).ok().filter(|t| magnitude >= *t)?;
+    let (Ok(hundred), Ok(ten_thousand)) = (T::try_from(100), T::try_from(10_000)) else {
+        unreachable!("types that can represent 1000 can represent 100 and 10 000")
+    };
+    let mut divisor = hundred;
+    let mut prefix = 0;
+    loop {
+        let tenths = div_round(magnitude, divisor);
+        if tenths < ten_thousand || prefix == PREFIXES.len() - 1 {
+            return Some((tenths, prefix));
+        }
+        // The rounded mantissa is at least 1000, so the magnitude is at least
+        // 999.95 times the current power of 1000 and the next power fits.
+        divisor = divisor * thousand;
+        prefix += 1;
+    }
+}
+
+/// Divides `n` by `d`, rounding halfway cases up.
+fn div_round<T: IntegerImpl>(n: T, d: T) -> T {
+    let remainder = n % d;
+    if remainder >= d - remainder {
+        n / d + T::ONE
+    } else {
+        n / d
+    }
+}
+
+const PREFIXES: [&str; 10] = ["k", "M", "G", "T", "P", "E", "Z", "Y", "R", "Q"];
+
+const SUPERSCRIPT_PREFIXES: [&str; 10] = [
+    "\u{1D4F}", "\u{1D39}", "\u{1D33}", "\u{1D40}", "\u{1D3E}", "\u{1D31}", "Z", "Y", "\u{1D3F}",
+    "Q",
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn formats_with_si_prefix() {
+        for (expected, input) in [
+            ("0", 0_i64),
+            ("999", 999),
+            ("1k", 1000),
+            ("1.5k", 1500),
+            ("12k", 12_000),
+            ("123.5k", 123_456),
+            ("999.9k", 999_949),
+            ("1M", 999_950),
+            ("3.4M", 3_400_000),
+            ("-1.5k", -1500),
+            ("-999", -999),
+            ("9.2E", i64::MAX),
+            ("-9.2E", i64::MIN),
+        ] {
+            assert_eq!(expected, SiCompact(input).to_string());
+        }
+    }
+
+    #[test]
+    fn formats_extreme_values() {
+        assert_eq!("255", SiCompact(u8::MAX).to_string());
+        assert_eq!("-128", SiCompact(i8::MIN).to_string());
+        assert_eq!("65.5k", SiCompact(u16::MAX).to_string());
+        assert_eq!("18.4E", SiCompact(u64::MAX).to_string());
+        assert_eq!("1Q", SiCompact(10_u128.pow(30)).to_string());
+        assert_eq!("340282366.9Q", SiCompact(u128::MAX).to_string());
+        assert_eq!("-170141183.5Q", SiCompact(i128::MIN).to_string());
+    }
+
+    #[test]
+    fn formats_superscript_prefix() {
+        assert_eq!("1.5ᵏ", SiCompact(1500).superscript().to_string());
+        assert_eq!("42", SiCompact(42).superscript().to_string());
+        assert_eq!("18.4ᴱ", SiCompact(u64::MAX).superscript().to_string());
+        assert_eq!("1Z", SiCompact(10_u128.pow(21)).superscript().to_string());
+        assert_eq!("1ᴿ", SiCompact(10_u128.pow(27)).superscript().to_string());
+    }
+}
@@ -0,0 +1,131 @@
+use crate::integer::IntegerImpl;
+use crate::Integer;
+use core::fmt;
+
+/// Formats a value within a range as one of the eight block elements `▁▂▃▄▅▆▇█`,
+/// e.g. for drawing inline sparklines. See also [`sparkline`].
+///
+/// The tuple contains the value, the minimum and the maximum in that order.
+/// The block is chosen by linear interpolation: the range from minimum to maximum
+/// is mapped onto the eight blocks, rounding to the nearest block
+/// (halfway cases are rounded up).
+/// The minimum is always formatted as `▁` and the maximum as `█`.
+///
+/// Values outside of the range are clamped to the nearest bound.
+/// If the range is empty (i.e. the maximum is not greater than the minimum),
+/// the lowest block `▁` is formatted.
+///
+/// ```
+/// # use fmtastic::Spark;
+/// assert_eq!("▁", Spark(0, 0, 100).to_string());
+/// assert_eq!("▅", Spark(50, 0, 100).to_string());
+/// assert_eq!("█", Spark(100, 0, 100).to_string());
+/// assert_eq!("█", Spark(200, 0, 100).to_string()); // clamped
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct Spark<T>(pub T, pub T, pub T);
+
+impl<T> fmt::Display for Spark<T>
+where
+    T: Integer,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let level = spark_level(self.0.into_impl(), self.1.into_impl(), self.2.into_impl());
+        f.write_str(BLOCKS[level])
+    }
+}
+
+fn spark_level<T: IntegerImpl>(value: T, min: T, max: T) -> usize {
+    if max <= min || value <= min {
+        0
+    } else if value >= max {
+        BLOCKS.len() - 1
+    } else {
+        crate::integer::scale_ratio(value.abs_diff(min), max.abs_diff(min), BLOCKS.len() - 1)
+    }
+}
+
+/// Formats a slice of integers as a sparkline using [`Spark`], e.g. `▁▃▅█`.
+///
+/// The range of the sparkline spans from the smallest to the largest value of the slice.
+/// A slice where all values are equal is formatted using the lowest block `▁`.
+///
+/// ```
+/// # use fmtastic::sparkline;
+/// assert_eq!("▁▃▅█▅", sparkline(&[1, 3, 5, 8, 5]).to_string());
+/// assert_eq!("▁▁▁", sparkline(&[7, 7, 7]).to_string());
+/// assert_eq!("", sparkline::<u8>(&[]).to_string());
+/// ```
+#[must_use]
+pub fn sparkline<T: Integer>(values: &[T]) -> Sparkline<'_, T> {
+    Sparkline(values)
+}
+
+/// A sparkline over a slice of integers, created by [`sparkline`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct Sparkline<'a, T>(&'a [T]);
+
+impl<T> fmt::Display for Sparkline<'_, T>
+where
+    T: Integer,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut values = self.0.iter().map(|v| v.into_impl());
+        let Some(first) = values.next() else {
+            return Ok(());
+        };
+        let (min, max) = values.fold((first, first), |(min, max), v| {
+            (if v < min { v } else { min }, if v > max { v } else { max })
+        });
+        self.0
+            .iter()
+            .try_for_each(|v| f.write_str(BLOCKS[spark_level(v.into_impl(), min, max)]))
+    }
+}
+
+const BLOCKS: [&str; 8] = ["▁", "▂", "▃", "▄", "▅", "▆", "▇", "█"];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn formats_min_max_and_midpoint() {
+        assert_eq!("▁", Spark(0_u8, 0, 70).to_string());
+        assert_eq!("█", Spark(70_u8, 0, 70).to_string());
+        assert_eq!("▄", Spark(30_u8, 0, 70).to_string());
+        assert_eq!("▅", Spark(35_u8, 0, 70).to_string());
+        assert_eq!("▅", Spark(0_i32, -10, 10).to_string());
+    }
+
+    #[test]
+    fn formats_each_block() {
+        let blocks: String = (0..=7).map(|v| Spark(v, 0, 7).to_string()).collect();
+        assert_eq!("▁▂▃▄▅▆▇█", blocks);
+    }
+
+    #[test]
+    fn clamps_values_outside_of_range() {
+        assert_eq!("▁", Spark(-5, 0, 10).to_string());
+        assert_eq!("█", Spark(11, 0, 10).to_string());
+        assert_eq!("▁", Spark(5, 10, 10).to_string());
+        assert_eq!("▁", Spark(5, 10, 0).to_string());
+    }
+
+    #[test]
+    fn formats_extreme_ranges() {
+        assert_eq!("▁", Spark(i64::MIN, i64::MIN, i64::MAX).to_string());
+        assert_eq!("▅", Spark(0, i64::MIN, i64::MAX).to_string());
+        assert_eq!("█", Spark(u128::MAX, 0, u128::MAX).to_string());
+    }
+
+    #[test]
+    fn formats_sparkline() {
+        assert_eq!(
+            "▁▂▃▄▅▆▇█",
+            sparkline(&[-7, -6, -5, -4, -3, -2, -1, 0]).to_string()
+        );
+        assert_eq!("█▁", sparkline(&[u64::MAX, 0]).to_string());
+        assert_eq!("▁", sparkline(&[42]).to_string());
+    }
+}
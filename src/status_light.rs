@@ -0,0 +1,116 @@
+use core::fmt;
+
+/// Formats a status as a traffic light, e.g. for health checks on dashboards.
+///
+/// By default the status is drawn as a colored circle emoji from the
+/// [Geometric Shapes Extended] block (and the red circle from [Miscellaneous Symbols and Pictographs]).
+/// The alternate flag `#` switches to the monochrome circles from the [Geometric Shapes] block,
+/// which are supported by most fonts and don't rely on color to be told apart:
+///
+/// | Status                             | Default         | Alternate `#`   |
+/// |------------------------------------|-----------------|-----------------|
+/// | [`StatusLight::Ok`]                | `🟢` (U+1F7E2)  | `●` (U+25CF)    |
+/// | [`StatusLight::Warn`]              | `🟡` (U+1F7E1)  | `◐` (U+25D0)    |
+/// | [`StatusLight::Error`]             | `🔴` (U+1F534)  | `○` (U+25CB)    |
+/// | [`StatusLight::Unknown`] (default) | `⚪` (U+26AA)   | `◌` (U+25CC)    |
+///
+/// Booleans can be converted using [`From`], mapping `true` to [`StatusLight::Ok`]
+/// and `false` to [`StatusLight::Error`].
+///
+/// ```
+/// # use fmtastic::StatusLight;
+/// assert_eq!("🟢 api", format!("{} api", StatusLight::from(true)));
+/// assert_eq!("🔴 db", format!("{} db", StatusLight::from(false)));
+/// ```
+///
+/// [Geometric Shapes Extended]: https://www.unicode.org/charts/PDF/U1F780.pdf
+/// [Miscellaneous Symbols and Pictographs]: https://www.unicode.org/charts/PDF/U1F300.pdf
+/// [Geometric Shapes]: https://www.unicode.org/charts/PDF/U25A0.pdf
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Default)]
+#[non_exhaustive]
+pub enum StatusLight {
+    /// Everything is fine.
+    ///
+    /// ```
+    /// # use fmtastic::StatusLight;
+    /// assert_eq!("🟢", StatusLight::Ok.to_string());
+    /// assert_eq!("●", format!("{:#}", StatusLight::Ok));
+    /// ```
+    Ok,
+    /// Something needs attention.
+    ///
+    /// ```
+    /// # use fmtastic::StatusLight;
+    /// assert_eq!("🟡", StatusLight::Warn.to_string());
+    /// assert_eq!("◐", format!("{:#}", StatusLight::Warn));
+    /// ```
+    Warn,
+    /// Something is broken.
+    ///
+    /// ```
+    /// # use fmtastic::StatusLight;
+    /// assert_eq!("🔴", StatusLight::Error.to_string());
+    /// assert_eq!("○", format!("{:#}", StatusLight::Error));
+    /// ```
+    Error,
+    /// The status is not known, e.g. because a check hasn't run yet.
+    ///
+    /// ```
+    /// # use fmtastic::StatusLight;
+    /// assert_eq!("⚪", StatusLight::Unknown.to_string());
+    /// assert_eq!("◌", format!("{:#}", StatusLight::Unknown));
+    /// ```
+    #[default]
+    Unknown,
+}
+
+impl fmt::Display for StatusLight {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let glyph = match (self, f.alternate()) {
+            (StatusLight::Ok, false) => "\u{1F7E2}",
+            (StatusLight::Warn, false) => "\u{1F7E1}",
+            (StatusLight::Error, false) => "\u{1F534}",
+            (StatusLight::Unknown, false) => "\u{26AA}",
+            (StatusLight::Ok, true) => "\u{25CF}",
+            (StatusLight::Warn, true) => "\u{25D0}",
+            (StatusLight::Error, true) => "\u{25CB}",
+            (StatusLight::Unknown, true) => "\u{25CC}",
+        };
+        f.write_str(glyph)
+    }
+}
+
+impl From<bool> for StatusLight {
+    fn from(value: bool) -> Self {
+        if value {
+            StatusLight::Ok
+        } else {
+            StatusLight::Error
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn formats_each_status() {
+        for (expected, alternate, status) in [
+            ("\u{1F7E2}", "\u{25CF}", StatusLight::Ok),
+            ("\u{1F7E1}", "\u{25D0}", StatusLight::Warn),
+            ("\u{1F534}", "\u{25CB}", StatusLight::Error),
+            ("\u{26AA}", "\u{25CC}", StatusLight::Unknown),
+        ] {
+            assert_eq!(expected, status.to_string());
+            assert_eq!(alternate, format!("{status:#}"));
+        }
+        assert_eq!(StatusLight::Unknown, StatusLight::default());
+    }
+
+    #[test]
+    fn converts_booleans() {
+        assert_eq!(StatusLight::Ok, StatusLight::from(true));
+        assert_eq!(StatusLight::Error, StatusLight::from(false));
+    }
+}
@@ -1,7 +1,7 @@
-use crate::digits::iter_digits;
-use crate::integer::{Base, IntegerImpl, Sign};
-use crate::Integer;
-use core::fmt::{self, Write};
+use crate::digits::{fmt_with, max_digits};
+use crate::integer::{Base, IntegerImpl};
+use crate::{Integer, Plain, SignGlyphs, WithSign};
+use core::fmt;
 
 /// A number that can be formatted as superscript using the [`Display`][`core::fmt::Display`] trait.
 ///
@@ -11,6 +11,11 @@ use core::fmt::{self, Write};
 /// ### Sign: `+`
 /// Use the `+` flag to always include the + sign for positive numbers.
 ///
+/// ### Alternate `#`
+/// By default the superscript signs (`⁺` U+207A, `⁻` U+207B) are used.
+/// The alternate flag `#` can be used to switch to the modifier letter signs
+/// (`˖` U+02D6, `˗` U+02D7) instead.
+///
 /// ## Examples
 /// ```
 /// # use fmtastic::Superscript;
@@ -23,10 +28,52 @@ use core::fmt::{self, Write};
 /// assert_eq!("¹⁰¹⁰¹⁰", format!("{:b}", Superscript(0b101010)));
 /// assert_eq!("⁺¹⁰¹⁰¹⁰", format!("{:+b}", Superscript(0b101010)));
 /// assert_eq!("⁻¹⁰¹⁰¹⁰", format!("{:b}", Superscript(-0b101010)));
+///
+/// // Modifier letter signs
+/// assert_eq!("˗¹²³", format!("{:#}", Superscript(-123)));
 /// ```
+///
+/// ## Layout
+/// `Superscript<T>` is `#[repr(transparent)]`, so it has the same size, alignment and ABI as `T`.
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+#[repr(transparent)]
 pub struct Superscript<T>(pub T);
 
+impl<T> Superscript<T> {
+    /// Returns a reference to the wrapped value.
+    #[must_use]
+    pub fn as_inner(&self) -> &T {
+        &self.0
+    }
+
+    /// Applies a function to the wrapped value.
+    ///
+    /// ```
+    /// # use fmtastic::Superscript;
+    /// assert_eq!(Superscript(4), Superscript(3).map(|n| n + 1));
+    /// ```
+    #[must_use]
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> Superscript<U> {
+        Superscript(f(self.0))
+    }
+
+    /// Formats the wrapped value using its standard formatting,
+    /// ignoring the glyphs of this format. See [`Plain`].
+    #[must_use]
+    pub fn plain(self) -> Plain<T> {
+        Plain(self.0)
+    }
+
+    /// Sets whether zero gets a plus sign when formatted with the `+` flag.
+    /// Zero is signed by default. See [`SignZero`].
+    #[must_use]
+    pub fn sign_zero(self, sign_zero: bool) -> SignZero<Self> {
+        SignZero(self, sign_zero)
+    }
+}
+
 impl<T> From<T> for Superscript<T>
 where
     T: Integer,
@@ -36,6 +83,42 @@ where
     }
 }
 
+impl<T> Superscript<T>
+where
+    T: Integer,
+{
+    /// The largest number of bytes written when formatting a value of type `T`
+    /// using any of the formatting traits, for sizing buffers.
+    ///
+    /// The longest output is a sign (e.g. `⁻`) followed by the binary representation
+    /// of the largest magnitude, with one digit per bit of `T`.
+    /// The sign and each digit are encoded in at most three bytes.
+    ///
+    /// ```
+    /// # use fmtastic::Superscript;
+    /// assert_eq!(3 + 8 * 3, Superscript::<i8>::MAX_LEN);
+    /// ```
+    pub const MAX_LEN: usize = 3 + max_digits(<T::Impl as IntegerImpl>::BITS, 2) * 3;
+}
+
+#[cfg(feature = "alloc")]
+impl<T> Superscript<T>
+where
+    T: Integer,
+{
+    /// Formats the number as LaTeX superscript, e.g. `^{123}` or `^{-123}`.
+    ///
+    /// ```
+    /// # use fmtastic::Superscript;
+    /// assert_eq!("x^{123}", format!("x{}", Superscript(123).to_latex()));
+    /// assert_eq!("^{-123}", Superscript(-123).to_latex());
+    /// ```
+    #[must_use]
+    pub fn to_latex(&self) -> alloc::string::String {
+        alloc::format!("^{{{}}}", self.0.into_impl())
+    }
+}
+
 impl<T> fmt::Display for Superscript<T>
 where
     T: Integer,
@@ -44,9 +127,8 @@ where
         fmt_number_with_base_and_digits::<_, <T::Impl as IntegerImpl>::BaseTen>(
             f,
             self.0.into_impl(),
-            '⁺',
-            '⁻',
-            &['⁰', '¹', '²', '³', '⁴', '⁵', '⁶', '⁷', '⁸', '⁹'],
+            &SUPERSCRIPT,
+            true,
         )
     }
 }
@@ -59,9 +141,8 @@ where
         fmt_number_with_base_and_digits::<_, <T::Impl as IntegerImpl>::BaseTwo>(
             f,
             self.0.into_impl(),
-            '⁺',
-            '⁻',
-            &['⁰', '¹'],
+            &SUPERSCRIPT,
+            true,
         )
     }
 }
@@ -73,6 +154,7 @@ where
 /// ## Formatting Flags
 /// ### Sign: `+`
 /// Use the `+` flag to always include the + sign for positive numbers.
+/// The subscript signs (`₊` U+208A, `₋` U+208B) are used in all bases.
 ///
 /// ## Examples
 /// ```
@@ -87,9 +169,48 @@ where
 /// assert_eq!("₊₁₀₁₀₁₀", format!("{:+b}", Subscript(0b101010)));
 /// assert_eq!("₋₁₀₁₀₁₀", format!("{:b}", Subscript(-0b101010)));
 /// ```
+///
+/// ## Layout
+/// `Subscript<T>` is `#[repr(transparent)]`, so it has the same size, alignment and ABI as `T`.
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+#[repr(transparent)]
 pub struct Subscript<T>(pub T);
 
+impl<T> Subscript<T> {
+    /// Returns a reference to the wrapped value.
+    #[must_use]
+    pub fn as_inner(&self) -> &T {
+        &self.0
+    }
+
+    /// Applies a function to the wrapped value.
+    ///
+    /// ```
+    /// # use fmtastic::Subscript;
+    /// assert_eq!(Subscript(4), Subscript(3).map(|n| n + 1));
+    /// ```
+    #[must_use]
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> Subscript<U> {
+        Subscript(f(self.0))
+    }
+
+    /// Formats the wrapped value using its standard formatting,
+    /// ignoring the glyphs of this format. See [`Plain`].
+    #[must_use]
+    pub fn plain(self) -> Plain<T> {
+        Plain(self.0)
+    }
+
+    /// Sets whether zero gets a plus sign when formatted with the `+` flag.
+    /// Zero is signed by default. See [`SignZero`].
+    #[must_use]
+    pub fn sign_zero(self, sign_zero: bool) -> SignZero<Self> {
+        SignZero(self, sign_zero)
+    }
+}
+
 impl<T> From<T> for Subscript<T>
 where
     T: Integer,
@@ -99,6 +220,42 @@ where
     }
 }
 
+impl<T> Subscript<T>
+where
+    T: Integer,
+{
+    /// The largest number of bytes written when formatting a value of type `T`
+    /// using any of the formatting traits, for sizing buffers.
+    ///
+    /// The longest output is a sign (e.g. `₋`) followed by the binary representation
+    /// of the largest magnitude, with one digit per bit of `T`.
+    /// The sign and each digit are encoded in at most three bytes.
+    ///
+    /// ```
+    /// # use fmtastic::Subscript;
+    /// assert_eq!(3 + 8 * 3, Subscript::<i8>::MAX_LEN);
+    /// ```
+    pub const MAX_LEN: usize = 3 + max_digits(<T::Impl as IntegerImpl>::BITS, 2) * 3;
+}
+
+#[cfg(feature = "alloc")]
+impl<T> Subscript<T>
+where
+    T: Integer,
+{
+    /// Formats the number as LaTeX subscript, e.g. `_{123}` or `_{-123}`.
+    ///
+    /// ```
+    /// # use fmtastic::Subscript;
+    /// assert_eq!("x_{123}", format!("x{}", Subscript(123).to_latex()));
+    /// assert_eq!("_{-123}", Subscript(-123).to_latex());
+    /// ```
+    #[must_use]
+    pub fn to_latex(&self) -> alloc::string::String {
+        alloc::format!("_{{{}}}", self.0.into_impl())
+    }
+}
+
 impl<T> fmt::Display for Subscript<T>
 where
     T: Integer,
@@ -107,9 +264,8 @@ where
         fmt_number_with_base_and_digits::<_, <T::Impl as IntegerImpl>::BaseTen>(
             f,
             self.0.into_impl(),
-            '₊',
-            '₋',
-            &['₀', '₁', '₂', '₃', '₄', '₅', '₆', '₇', '₈', '₉'],
+            &SUBSCRIPT,
+            true,
         )
     }
 }
@@ -122,35 +278,191 @@ where
         fmt_number_with_base_and_digits::<_, <T::Impl as IntegerImpl>::BaseTwo>(
             f,
             self.0.into_impl(),
-            '₊',
-            '₋',
-            &['₀', '₁'],
+            &SUBSCRIPT,
+            true,
         )
     }
 }
 
-fn fmt_number_with_base_and_digits<T: IntegerImpl, B: Base<T>>(
+/// A format that controls whether zero gets a plus sign when formatted with the `+` flag,
+/// created by `sign_zero` on [`Superscript`], [`Subscript`] and [`VulgarFraction`](crate::VulgarFraction).
+///
+/// By default, zero is treated as positive and gets a plus sign, e.g. `⁺⁰`.
+/// With `sign_zero(false)` zero is never signed, while all other numbers are formatted as usual.
+/// A fraction counts as zero if its numerator is zero.
+///
+/// ```
+/// # use fmtastic::{Subscript, Superscript, VulgarFraction};
+/// assert_eq!("⁺⁰", format!("{:+}", Superscript(0).sign_zero(true)));
+/// assert_eq!("⁰", format!("{:+}", Superscript(0).sign_zero(false)));
+/// assert_eq!("₊₁", format!("{:+}", Subscript(1).sign_zero(false)));
+/// assert_eq!("⁰⁄₅", format!("{:+}", VulgarFraction::new(0, -5).sign_zero(false)));
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct SignZero<F>(pub(crate) F, pub(crate) bool);
+
+macro_rules! impl_sign_zero {
+    ($wrapper:ident, $script:expr) => {
+        impl<T> fmt::Display for SignZero<$wrapper<T>>
+        where
+            T: Integer,
+        {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt_number_with_base_and_digits::<_, <T::Impl as IntegerImpl>::BaseTen>(
+                    f,
+                    self.0 .0.into_impl(),
+                    &$script,
+                    self.1,
+                )
+            }
+        }
+
+        impl<T> fmt::Binary for SignZero<$wrapper<T>>
+        where
+            T: Integer,
+        {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt_number_with_base_and_digits::<_, <T::Impl as IntegerImpl>::BaseTwo>(
+                    f,
+                    self.0 .0.into_impl(),
+                    &$script,
+                    self.1,
+                )
+            }
+        }
+    };
+}
+
+impl_sign_zero!(Superscript, SUPERSCRIPT);
+impl_sign_zero!(Subscript, SUBSCRIPT);
+
+/// Maps an ASCII digit (`'0'` to `'9'`) to its superscript digit, e.g. `'7'` to `'⁷'`.
+/// Returns [`None`] for all other characters.
+///
+/// ```
+/// # use fmtastic::superscript_digit;
+/// let n: String = "x2".chars().map(|c| superscript_digit(c).unwrap_or(c)).collect();
+/// assert_eq!("x²", n);
+/// assert_eq!(None, superscript_digit('a'));
+/// ```
+#[must_use]
+pub fn superscript_digit(c: char) -> Option<char> {
+    script_digit(c, &SUPERSCRIPT)
+}
+
+/// Maps an ASCII digit (`'0'` to `'9'`) to its subscript digit, e.g. `'7'` to `'₇'`.
+/// Returns [`None`] for all other characters.
+///
+/// ```
+/// # use fmtastic::subscript_digit;
+/// let n: String = "H2O".chars().map(|c| subscript_digit(c).unwrap_or(c)).collect();
+/// assert_eq!("H₂O", n);
+/// assert_eq!(None, subscript_digit('a'));
+/// ```
+#[must_use]
+pub fn subscript_digit(c: char) -> Option<char> {
+    script_digit(c, &SUBSCRIPT)
+}
+
+fn script_digit(c: char, script: &Script) -> Option<char> {
+    let digit = c.to_digit(10)?;
+    Some(script.digits[digit as usize])
+}
+
+/// The glyphs of a script, shared by all bases so that
+/// the sign always matches the digits.
+struct Script {
+    sign: SignGlyphs,
+    digits: [char; 10],
+}
+
+const SUPERSCRIPT: Script = Script {
+    sign: SignGlyphs::SUPERSCRIPT,
+    digits: ['⁰', '¹', '²', '³', '⁴', '⁵', '⁶', '⁷', '⁸', '⁹'],
+};
+
+const SUBSCRIPT: Script = Script {
+    sign: SignGlyphs::SUBSCRIPT,
+    digits: ['₀', '₁', '₂', '₃', '₄', '₅', '₆', '₇', '₈', '₉'],
+};
+
+fn fmt_number_with_base_and_digits<T: IntegerImpl, B: Base<T::Magnitude>>(
     f: &mut fmt::Formatter<'_>,
     n: T,
-    plus: char,
-    minus: char,
-    digits: &[char],
+    script: &Script,
+    sign_zero: bool,
 ) -> fmt::Result {
-    match n.sign() {
-        Sign::PositiveOrZero if f.sign_plus() => f.write_char(plus)?,
-        Sign::Negative => f.write_char(minus)?,
-        _ => {}
+    let digits = ScriptDigits {
+        n,
+        radix: B::VALUE.as_usize() as u32,
+        digits: &script.digits,
     };
+    let with_sign = WithSign::from_impl(n, digits)
+        .glyphs(script.sign)
+        .sign_zero(sign_zero);
+    fmt::Display::fmt(&with_sign, f)
+}
 
-    iter_digits::<T, B>(n)
-        .map(|digit| digits[digit])
-        .try_for_each(|digit| f.write_char(digit))
+/// The digits of the magnitude of `n`, without a sign.
+struct ScriptDigits<'a, T> {
+    n: T,
+    radix: u32,
+    digits: &'a [char; 10],
+}
+
+impl<T: IntegerImpl> fmt::Display for ScriptDigits<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_with(self.n.into_public(), self.radix, self.digits, f)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn maps_digit_chars() {
+        for (digit, (superscript, subscript)) in
+            ('0'..='9').zip("⁰¹²³⁴⁵⁶⁷⁸⁹".chars().zip("₀₁₂₃₄₅₆₇₈₉".chars()))
+        {
+            assert_eq!(Some(superscript), superscript_digit(digit));
+            assert_eq!(Some(subscript), subscript_digit(digit));
+        }
+        for c in ['a', ' ', '-', '⁷', '٣'] {
+            assert_eq!(None, superscript_digit(c));
+            assert_eq!(None, subscript_digit(c));
+        }
+    }
+
+    #[test]
+    fn output_never_exceeds_max_len() {
+        fn max_len<F: fmt::Display + fmt::Binary>(f: F) -> usize {
+            [
+                format!("{f}"),
+                format!("{f:+}"),
+                format!("{f:#}"),
+                format!("{f:b}"),
+                format!("{f:+b}"),
+            ]
+            .iter()
+            .map(String::len)
+            .max()
+            .unwrap()
+        }
+        for n in i8::MIN..=i8::MAX {
+            assert!(max_len(Superscript(n)) <= Superscript::<i8>::MAX_LEN);
+            assert!(max_len(Subscript(n)) <= Subscript::<i8>::MAX_LEN);
+        }
+        for n in 0..=u16::MAX {
+            assert!(max_len(Superscript(n)) <= Superscript::<u16>::MAX_LEN);
+            assert!(max_len(Subscript(n)) <= Subscript::<u16>::MAX_LEN);
+        }
+        for n in [i32::MIN, -1, 0, 1, i32::MAX] {
+            assert!(max_len(Superscript(n)) <= Superscript::<i32>::MAX_LEN);
+            assert!(max_len(Subscript(n).sign_zero(true)) <= Subscript::<i32>::MAX_LEN);
+        }
+    }
+
     #[test]
     fn formats_as_superscript() {
         for (expected, input) in [
@@ -181,6 +493,18 @@ mod tests {
         assert_eq!("⁻¹²³⁴⁵⁶⁷⁸⁹⁰", format!("{:+}", Superscript(-1234567890)));
     }
 
+    #[test]
+    fn signs_zero_only_if_enabled() {
+        assert_eq!("⁺⁰", format!("{:+}", Superscript(0).sign_zero(true)));
+        assert_eq!("⁰", format!("{:+}", Superscript(0).sign_zero(false)));
+        assert_eq!("⁰", format!("{:+b}", Superscript(0_u8).sign_zero(false)));
+        assert_eq!("⁺¹", format!("{:+}", Superscript(1).sign_zero(false)));
+        assert_eq!("⁻¹", format!("{:+}", Superscript(-1).sign_zero(false)));
+        assert_eq!("₊₀", format!("{:+}", Subscript(0).sign_zero(true)));
+        assert_eq!("₀", format!("{:+}", Subscript(0).sign_zero(false)));
+        assert_eq!("₀", Subscript(0).sign_zero(true).to_string());
+    }
+
     #[test]
     fn formats_as_subscript() {
         for (expected, input) in [
@@ -202,6 +526,94 @@ mod tests {
         }
     }
 
+    #[test]
+    fn formats_min_values() {
+        assert_eq!("⁻¹²⁸", Superscript(i8::MIN).to_string());
+        assert_eq!("⁻²¹⁴⁷⁴⁸³⁶⁴⁸", Superscript(i32::MIN).to_string());
+        assert_eq!(
+            "₋₁₇₀₁₄₁₁₈₃₄₆₀₄₆₉₂₃₁₇₃₁₆₈₇₃₀₃₇₁₅₈₈₄₁₀₅₇₂₈",
+            Subscript(i128::MIN).to_string()
+        );
+        assert_eq!("⁻¹⁰⁰⁰⁰⁰⁰⁰", format!("{:b}", Superscript(i8::MIN)));
+        assert_eq!("₊₁₂₇", format!("{:+}", Subscript(i8::MAX)));
+    }
+
+    #[test]
+    fn formats_u128_in_binary_like_standard_formatting() {
+        let to_superscript = |c: char| SUPERSCRIPT.digits[c.to_digit(2).unwrap() as usize];
+        let values = (0..128).flat_map(|shift| {
+            let power = 1_u128 << shift;
+            [power - 1, power, power | 1, u128::MAX >> shift]
+        });
+        for n in values {
+            let expected: String = format!("{n:b}").chars().map(to_superscript).collect();
+            assert_eq!(expected, format!("{:b}", Superscript(n)));
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn formats_as_latex() {
+        assert_eq!("^{0}", Superscript(0).to_latex());
+        assert_eq!("^{-128}", Superscript(i8::MIN).to_latex());
+        assert_eq!("_{42}", Subscript(42_u8).to_latex());
+        assert_eq!("_{-7}", Subscript(-7).to_latex());
+    }
+
+    #[test]
+    fn uses_matching_sign_glyphs_in_all_modes() {
+        for (expected, actual) in [
+            ("\u{207A}", format!("{:+}", Superscript(1)).replace('¹', "")),
+            ("\u{207B}", format!("{}", Superscript(-1)).replace('¹', "")),
+            (
+                "\u{207A}",
+                format!("{:+b}", Superscript(1)).replace('¹', ""),
+            ),
+            (
+                "\u{207B}",
+                format!("{:b}", Superscript(-1)).replace('¹', ""),
+            ),
+            (
+                "\u{02D6}",
+                format!("{:+#}", Superscript(1)).replace('¹', ""),
+            ),
+            (
+                "\u{02D7}",
+                format!("{:#}", Superscript(-1)).replace('¹', ""),
+            ),
+            (
+                "\u{02D6}",
+                format!("{:+#b}", Superscript(1)).replace('¹', ""),
+            ),
+            (
+                "\u{02D7}",
+                format!("{:#b}", Superscript(-1)).replace('¹', ""),
+            ),
+            ("\u{208A}", format!("{:+}", Subscript(1)).replace('₁', "")),
+            ("\u{208B}", format!("{}", Subscript(-1)).replace('₁', "")),
+            ("\u{208A}", format!("{:+b}", Subscript(1)).replace('₁', "")),
+            ("\u{208B}", format!("{:b}", Subscript(-1)).replace('₁', "")),
+            ("\u{208A}", format!("{:+#}", Subscript(1)).replace('₁', "")),
+            ("\u{208B}", format!("{:#b}", Subscript(-1)).replace('₁', "")),
+        ] {
+            assert_eq!(expected, actual);
+        }
+    }
+
+    #[test]
+    fn formats_plain_value() {
+        assert_eq!("42", Superscript(42).plain().to_string());
+        assert_eq!("-42", Subscript(-42).plain().to_string());
+        assert_eq!("101010", format!("{:b}", Superscript(42).plain()));
+    }
+
+    #[test]
+    fn maps_wrapped_value() {
+        assert_eq!(Superscript(4), Superscript(3).map(|n| n + 1));
+        assert_eq!("₋₁", Subscript(1_u8).map(|n| -i16::from(n)).to_string());
+        assert_eq!(&3, Superscript(3).as_inner());
+    }
+
     #[test]
     fn adds_subscript_plus_sign_to_positive_numbers() {
         assert_eq!("₊₀", format!("{:+}", Subscript(0)));
@@ -1,5 +1,6 @@
+use crate::digits::iter_digits;
 use crate::integer::IntegerImpl;
-use crate::UnsignedInteger;
+use crate::{Plain, UnsignedInteger};
 use core::fmt::{self, Write};
 
 /// Formats an unsigned integer as tally marks.
@@ -21,7 +22,13 @@ use core::fmt::{self, Write};
 /// assert_eq!("𝍸𝍷", TallyMarks(6_u32).to_string());
 /// assert_eq!("𝍸𝍸𝍸𝍷𝍷", TallyMarks(17_u32).to_string());
 /// ```
+///
+/// ## Layout
+/// `TallyMarks<T>` is `#[repr(transparent)]`, so it has the same size, alignment and ABI as `T`.
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+#[repr(transparent)]
 pub struct TallyMarks<T>(pub T);
 
 impl<T> From<T> for TallyMarks<T>
@@ -33,6 +40,109 @@ where
     }
 }
 
+impl<T> TallyMarks<T> {
+    /// Returns a reference to the wrapped value.
+    #[must_use]
+    pub fn as_inner(&self) -> &T {
+        &self.0
+    }
+
+    /// Applies a function to the wrapped value.
+    ///
+    /// ```
+    /// # use fmtastic::TallyMarks;
+    /// assert_eq!(TallyMarks(4), TallyMarks(3_u8).map(|n| n + 1));
+    /// ```
+    #[must_use]
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> TallyMarks<U> {
+        TallyMarks(f(self.0))
+    }
+
+    /// Formats the wrapped value using its standard formatting,
+    /// ignoring the glyphs of this format. See [`Plain`].
+    #[must_use]
+    pub fn plain(self) -> Plain<T> {
+        Plain(self.0)
+    }
+
+    /// Appends the count as decimal number in parentheses, e.g. `𝍸𝍸𝍷𝍷 (12)`.
+    /// This helps readability where tally marks are not well supported by fonts.
+    /// Zero is formatted as `(0)` without any tally marks.
+    ///
+    /// ```
+    /// use fmtastic::TallyMarks;
+    ///
+    /// assert_eq!("𝍸𝍸𝍷𝍷 (12)", TallyMarks(12_u32).annotated().to_string());
+    /// ```
+    #[must_use]
+    pub fn annotated(self) -> AnnotatedTallyMarks<T> {
+        AnnotatedTallyMarks(self.0)
+    }
+
+    /// Separates the groups of five and the trailing ones by a thin space (U+2009),
+    /// e.g. `𝍸 𝍸 𝍸 𝍷𝍷`. This makes long runs of tally marks easier to count.
+    /// Without it, all marks are written without any separator.
+    ///
+    /// ```
+    /// use fmtastic::TallyMarks;
+    ///
+    /// assert_eq!("𝍸\u{2009}𝍸\u{2009}𝍸\u{2009}𝍷𝍷", TallyMarks(17_u32).spaced().to_string());
+    /// ```
+    #[must_use]
+    pub fn spaced(self) -> SpacedTallyMarks<T> {
+        SpacedTallyMarks(self.0)
+    }
+}
+
+/// Tally marks with their groups separated by a thin space, created by [`TallyMarks::spaced`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct SpacedTallyMarks<T>(pub T);
+
+impl<T> fmt::Display for SpacedTallyMarks<T>
+where
+    T: UnsignedInteger,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        const THIN_SPACE: char = '\u{2009}';
+        let n = self.0.into_impl();
+        let (fives, ones) = (n / T::Impl::FIVE, n % T::Impl::FIVE);
+        let mut separator = None;
+        for _ in T::Impl::range(T::Impl::ZERO, fives) {
+            separator.into_iter().try_for_each(|c| f.write_char(c))?;
+            fmt_fives_and_ones(T::Impl::ONE, T::Impl::ZERO, f)?;
+            separator = Some(THIN_SPACE);
+        }
+        if ones != T::Impl::ZERO {
+            separator.into_iter().try_for_each(|c| f.write_char(c))?;
+            fmt_fives_and_ones(T::Impl::ZERO, ones, f)?;
+        }
+        Ok(())
+    }
+}
+
+/// Tally marks followed by their count, created by [`TallyMarks::annotated`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct AnnotatedTallyMarks<T>(pub T);
+
+impl<T> fmt::Display for AnnotatedTallyMarks<T>
+where
+    T: UnsignedInteger,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let n = self.0.into_impl();
+        if n != T::Impl::ZERO {
+            fmt_tally_marks(n, f)?;
+            f.write_char(' ')?;
+        }
+        f.write_char('(')?;
+        iter_digits::<_, <T::Impl as IntegerImpl>::BaseTen>(n)
+            .try_for_each(|digit| write!(f, "{digit}"))?;
+        f.write_char(')')
+    }
+}
+
 impl<T> fmt::Display for TallyMarks<T>
 where
     T: UnsignedInteger,
@@ -42,11 +152,195 @@ where
     }
 }
 
-fn fmt_tally_marks<T: IntegerImpl>(n: T, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+pub(crate) fn fmt_tally_marks<T: IntegerImpl>(n: T, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    fmt_fives_and_ones(n / T::FIVE, n % T::FIVE, f)
+}
+
+fn fmt_fives_and_ones<T: IntegerImpl>(
+    fives: T,
+    ones: T,
+    f: &mut fmt::Formatter<'_>,
+) -> fmt::Result {
     const TALLY_MARK_ONE: char = '\u{1D377}';
     const TALLY_MARK_FIVE: char = '\u{1D378}';
-    let (fives, ones) = (n / T::FIVE, n % T::FIVE);
     T::range(T::ZERO, fives).try_for_each(|_| f.write_char(TALLY_MARK_FIVE))?;
     T::range(T::ZERO, ones).try_for_each(|_| f.write_char(TALLY_MARK_ONE))?;
     Ok(())
 }
+
+/// Keeps track of a running count rendered as [`TallyMarks`], so that
+/// only the marks that changed need to be written when the count changes.
+///
+/// The only state kept is the current count, which starts at zero.
+/// Tally marks are written as a run of five-marks (`𝍸`) followed by up to four one-marks (`𝍷`).
+/// When the count changes, [`TallyWriter::update`] returns a [`TallyUpdate`] that
+/// tells how many marks at the end to erase and which marks to append afterwards.
+/// When the ones roll over into a new five-mark, the one-marks are erased
+/// and replaced by the five-mark.
+///
+/// ```
+/// use fmtastic::TallyWriter;
+///
+/// let mut tally = TallyWriter::new();
+/// let update = tally.update(4_u32);
+/// assert_eq!((0, "𝍷𝍷𝍷𝍷".to_string()), (update.erase(), update.to_string()));
+/// let update = tally.update(5_u32);
+/// assert_eq!((4, "𝍸".to_string()), (update.erase(), update.to_string()));
+/// let update = tally.update(6_u32);
+/// assert_eq!((0, "𝍷".to_string()), (update.erase(), update.to_string()));
+/// ```
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct TallyWriter<T> {
+    count: T,
+}
+
+impl<T> TallyWriter<T>
+where
+    T: UnsignedInteger,
+{
+    /// Creates a writer with a count of zero, i.e. no marks written yet.
+    #[must_use]
+    pub fn new() -> Self {
+        TallyWriter {
+            count: T::from_impl(T::Impl::ZERO),
+        }
+    }
+
+    /// Returns the current count.
+    #[must_use]
+    pub fn count(&self) -> T {
+        self.count
+    }
+
+    /// Sets the count and returns the changes needed to update
+    /// the marks of the previous count to the marks of the new one.
+    /// The count may also decrease, in which case marks are only erased
+    /// or replaced by fewer marks.
+    pub fn update(&mut self, count: T) -> TallyUpdate<T> {
+        let (old, new) = (self.count.into_impl(), count.into_impl());
+        self.count = count;
+        let (old_fives, old_ones) = (old / T::Impl::FIVE, old % T::Impl::FIVE);
+        let (new_fives, new_ones) = (new / T::Impl::FIVE, new % T::Impl::FIVE);
+        let (erase, fives, ones) = if new_fives == old_fives {
+            let common_ones = if old_ones < new_ones {
+                old_ones
+            } else {
+                new_ones
+            };
+            (
+                old_ones - common_ones,
+                T::Impl::ZERO,
+                new_ones - common_ones,
+            )
+        } else if new_fives > old_fives {
+            (old_ones, new_fives - old_fives, new_ones)
+        } else {
+            (old_fives - new_fives + old_ones, T::Impl::ZERO, new_ones)
+        };
+        TallyUpdate {
+            erase: T::from_impl(erase),
+            fives: T::from_impl(fives),
+            ones: T::from_impl(ones),
+        }
+    }
+}
+
+/// The changes to the marks of a [`TallyWriter`] after its count has been updated.
+///
+/// First, [`TallyUpdate::erase`] marks have to be removed from the end of the previous marks.
+/// Afterwards, the marks formatted by [`Display`](fmt::Display) are appended.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[must_use]
+pub struct TallyUpdate<T> {
+    erase: T,
+    fives: T,
+    ones: T,
+}
+
+impl<T> TallyUpdate<T>
+where
+    T: UnsignedInteger,
+{
+    /// Returns the number of marks to erase from the end before appending the new marks.
+    #[must_use]
+    pub fn erase(&self) -> T {
+        self.erase
+    }
+}
+
+impl<T> fmt::Display for TallyUpdate<T>
+where
+    T: UnsignedInteger,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_fives_and_ones(self.fives.into_impl(), self.ones.into_impl(), f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn formats_annotated_tally_marks() {
+        for (expected, input) in [
+            ("(0)", 0_u32),
+            ("𝍷𝍷𝍷𝍷 (4)", 4),
+            ("𝍸 (5)", 5),
+            ("𝍸𝍸𝍷𝍷 (12)", 12),
+        ] {
+            assert_eq!(expected, TallyMarks(input).annotated().to_string());
+        }
+    }
+
+    #[test]
+    fn formats_spaced_tally_marks() {
+        for (expected, input) in [
+            ("", 0_u32),
+            ("𝍷𝍷𝍷", 3),
+            ("𝍸", 5),
+            ("𝍸\u{2009}𝍷", 6),
+            ("𝍸\u{2009}𝍸", 10),
+            ("𝍸\u{2009}𝍸\u{2009}𝍸\u{2009}𝍷𝍷", 17),
+        ] {
+            assert_eq!(expected, TallyMarks(input).spaced().to_string());
+        }
+    }
+
+    #[test]
+    fn updates_incrementally() {
+        let mut tally = TallyWriter::new();
+        for (count, expected_erase, expected_marks) in [
+            (3_u32, 0, "𝍷𝍷𝍷"),
+            (4, 0, "𝍷"),
+            (5, 4, "𝍸"),
+            (9, 0, "𝍷𝍷𝍷𝍷"),
+            (10, 4, "𝍸"),
+            (10, 0, ""),
+            (17, 0, "𝍸𝍷𝍷"),
+            (16, 1, ""),
+            (3, 4, "𝍷𝍷𝍷"),
+            (0, 3, ""),
+        ] {
+            let update = tally.update(count);
+            assert_eq!(expected_erase, update.erase());
+            assert_eq!(expected_marks, update.to_string());
+            assert_eq!(count, tally.count());
+        }
+    }
+
+    #[test]
+    fn incremental_updates_match_full_rendering() {
+        let mut tally = TallyWriter::new();
+        let mut marks: Vec<char> = Vec::new();
+        for count in [1_u8, 4, 5, 9, 10, 11, 24, 25, 7, 255, 0, 13] {
+            let update = tally.update(count);
+            marks.truncate(marks.len() - usize::from(update.erase()));
+            marks.extend(update.to_string().chars());
+            assert_eq!(
+                TallyMarks(count).to_string(),
+                marks.iter().collect::<String>()
+            );
+        }
+    }
+}
@@ -0,0 +1,64 @@
+use core::fmt;
+
+/// Formats a boolean as a toggle switch, e.g. for settings.
+///
+/// By default the switch is drawn using a track of two box drawing lines
+/// (`─`, U+2500) with the knob on the right when on and on the left when off.
+/// The knob is a fisheye (`◉`, U+25C9) when on and a white circle (`○`, U+25CB) when off.
+/// These characters are part of the [Box Drawing] and [Geometric Shapes] blocks,
+/// which are supported by most fonts.
+///
+/// ```
+/// # use fmtastic::Toggle;
+/// assert_eq!("──◉ Wi-Fi", format!("{} Wi-Fi", Toggle(true)));
+/// assert_eq!("○── Bluetooth", format!("{} Bluetooth", Toggle(false)));
+/// assert_eq!("⏽", format!("{:#}", Toggle(true)));
+/// assert_eq!("⭘", format!("{:#}", Toggle(false)));
+/// ```
+///
+/// ## Formatting Flags
+/// ### Alternate `#`
+/// The alternate flag `#` can be used to use the single character IEC power symbols instead:
+/// power on (`⏽`, U+23FD) and power off (`⭘`, U+2B58).
+/// These symbols are only supported by few fonts such as [Noto Sans Symbols 2].
+///
+/// [Box Drawing]: https://www.unicode.org/charts/PDF/U2500.pdf
+/// [Geometric Shapes]: https://www.unicode.org/charts/PDF/U25A0.pdf
+/// [Noto Sans Symbols 2]: https://fonts.google.com/noto/specimen/Noto+Sans+Symbols+2
+///
+/// ## Layout
+/// `Toggle` is `#[repr(transparent)]`, so it has the same size, alignment and ABI as `bool`.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct Toggle(pub bool);
+
+impl fmt::Display for Toggle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let glyph = match (self.0, f.alternate()) {
+            (true, false) => "\u{2500}\u{2500}\u{25C9}",
+            (false, false) => "\u{25CB}\u{2500}\u{2500}",
+            (true, true) => "\u{23FD}",
+            (false, true) => "\u{2B58}",
+        };
+        f.write_str(glyph)
+    }
+}
+
+impl From<bool> for Toggle {
+    fn from(value: bool) -> Self {
+        Toggle(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn formats_on_and_off() {
+        assert_eq!("──◉", Toggle(true).to_string());
+        assert_eq!("○──", Toggle(false).to_string());
+        assert_eq!("\u{23FD}", format!("{:#}", Toggle::from(true)));
+        assert_eq!("\u{2B58}", format!("{:#}", Toggle::from(false)));
+    }
+}
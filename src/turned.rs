@@ -0,0 +1,140 @@
+use crate::digits::{iter_digits_rev, max_digits};
+use crate::integer::{Base, IntegerImpl};
+use crate::{Plain, UnsignedInteger};
+use core::fmt;
+
+/// Formats an unsigned integer as if it was turned upside-down,
+/// like reading a calculator display from the other side.
+///
+/// The digits are written in reverse order and each digit is replaced
+/// by a lookalike of the digit rotated by 180°.
+/// Only `0`, `8`, `6` and `9` have exact counterparts, as do `2` and `3`
+/// with the dedicated turned digits (`↊`, `↋`) from the [Number Forms] block.
+/// The remaining digits are best-effort approximations:
+///
+/// | Digit | Turned |
+/// |-------|--------|
+/// | `0`   | `0`    |
+/// | `1`   | `1`    |
+/// | `2`   | `↊`    |
+/// | `3`   | `↋`    |
+/// | `4`   | `ㄣ`   |
+/// | `5`   | `ϛ`    |
+/// | `6`   | `9`    |
+/// | `7`   | `ㄥ`   |
+/// | `8`   | `8`    |
+/// | `9`   | `6`    |
+///
+/// [Number Forms]: https://www.unicode.org/charts/PDF/U2150.pdf
+///
+/// ```
+/// use fmtastic::Turned;
+///
+/// assert_eq!("619", Turned(619_u32).to_string());
+/// assert_eq!("9ϛㄣ↋↊1", Turned(123456_u32).to_string());
+/// assert_eq!("0", Turned(0_u32).to_string());
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct Turned<T>(pub T);
+
+impl<T> Turned<T> {
+    /// Returns a reference to the wrapped value.
+    #[must_use]
+    pub fn as_inner(&self) -> &T {
+        &self.0
+    }
+
+    /// Applies a function to the wrapped value.
+    ///
+    /// ```
+    /// # use fmtastic::Turned;
+    /// assert_eq!(Turned(4), Turned(3_u8).map(|n| n + 1));
+    /// ```
+    #[must_use]
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> Turned<U> {
+        Turned(f(self.0))
+    }
+
+    /// Formats the wrapped value using its standard formatting,
+    /// ignoring the glyphs of this format. See [`Plain`].
+    #[must_use]
+    pub fn plain(self) -> Plain<T> {
+        Plain(self.0)
+    }
+}
+
+impl<T> Turned<T>
+where
+    T: UnsignedInteger,
+{
+    /// The largest number of bytes written when formatting a value of type `T`,
+    /// for sizing buffers.
+    ///
+    /// This is the number of decimal digits of the largest value of `T`
+    /// times three bytes, the longest encoding of a turned digit (e.g. `ㄣ`).
+    ///
+    /// ```
+    /// # use fmtastic::Turned;
+    /// assert_eq!(10 * 3, Turned::<u32>::MAX_LEN);
+    /// ```
+    pub const MAX_LEN: usize = max_digits(<T::Impl as IntegerImpl>::BITS, 10) * 3;
+}
+
+impl<T> From<T> for Turned<T>
+where
+    T: UnsignedInteger,
+{
+    fn from(value: T) -> Self {
+        Turned(value)
+    }
+}
+
+impl<T> fmt::Display for Turned<T>
+where
+    T: UnsignedInteger,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_turned::<_, <T::Impl as IntegerImpl>::BaseTen>(self.0.into_impl(), f)
+    }
+}
+
+pub(crate) fn fmt_turned<T: IntegerImpl, B: Base<T::Magnitude>>(
+    n: T,
+    f: &mut fmt::Formatter<'_>,
+) -> fmt::Result {
+    iter_digits_rev::<_, B>(n).try_for_each(|digit| write!(f, "{}", DIGITS[digit]))
+}
+
+const DIGITS: [&str; 10] = [
+    "0", "1", "\u{218A}", "\u{218B}", "\u{3123}", "\u{03DB}", "9", "\u{3125}", "8", "6",
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn output_never_exceeds_max_len() {
+        assert!((0..=u8::MAX).all(|n| Turned(n).to_string().len() <= Turned::<u8>::MAX_LEN));
+        assert!((0..=u16::MAX).all(|n| Turned(n).to_string().len() <= Turned::<u16>::MAX_LEN));
+        for n in [0, 4_044_444_444, u32::MAX] {
+            assert!(Turned(n).to_string().len() <= Turned::<u32>::MAX_LEN);
+        }
+    }
+
+    #[test]
+    fn formats_turned_digits() {
+        for (expected, input) in [
+            ("0", 0_u32),
+            ("619", 619),
+            ("9", 6),
+            ("6", 9),
+            ("01", 10),
+            ("ㄥ8", 87),
+            ("9ϛㄣ↋↊1", 123456),
+        ] {
+            assert_eq!(expected, Turned(input).to_string())
+        }
+    }
+}
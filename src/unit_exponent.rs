@@ -0,0 +1,52 @@
+use crate::integer::IntegerImpl;
+use crate::{Integer, Superscript};
+use core::fmt;
+
+/// Formats a unit followed by its exponent as [`Superscript`], e.g. `m³` or `s⁻¹`.
+///
+/// The exponent is omitted when it is one, so `m¹` is formatted as `m`.
+/// Negative exponents use the superscript minus (`⁻`).
+///
+/// ## Formatting Flags
+/// ### Sign: `+`
+/// Use the `+` flag to always include the + sign for positive exponents.
+///
+/// ## Examples
+/// ```
+/// # use fmtastic::UnitExponent;
+/// assert_eq!("m³", UnitExponent("m", 3).to_string());
+/// assert_eq!("s⁻¹", UnitExponent("s", -1).to_string());
+/// assert_eq!("m", UnitExponent("m", 1).to_string());
+/// assert_eq!("kg·m²·s⁻²", format!("{}·{}·{}", UnitExponent("kg", 1), UnitExponent("m", 2), UnitExponent("s", -2)));
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct UnitExponent<'a, T>(pub &'a str, pub T);
+
+impl<T> fmt::Display for UnitExponent<'_, T>
+where
+    T: Integer,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.0)?;
+        if self.1.into_impl() == T::Impl::ONE {
+            Ok(())
+        } else {
+            fmt::Display::fmt(&Superscript(self.1), f)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn formats_unit_with_exponent() {
+        assert_eq!("m³", UnitExponent("m", 3).to_string());
+        assert_eq!("s⁻¹", UnitExponent("s", -1).to_string());
+        assert_eq!("m", UnitExponent("m", 1).to_string());
+        assert_eq!("m", UnitExponent("m", 1_u8).to_string());
+        assert_eq!("m⁰", UnitExponent("m", 0).to_string());
+        assert_eq!("m⁺²", format!("{:+}", UnitExponent("m", 2)));
+    }
+}
@@ -0,0 +1,264 @@
+use crate::integer::{IntegerImpl, Sign};
+use crate::Integer;
+use core::fmt::{self, Write};
+
+/// Writes the sign of a value followed by a formatter for its magnitude,
+/// e.g. to give an unsigned format such as [`Turned`](crate::Turned) a sign.
+///
+/// The sign is taken from the value passed to [`WithSign::new`],
+/// the wrapped formatter is expected to format the magnitude of that value.
+/// [`Superscript`](crate::Superscript), [`Subscript`](crate::Subscript)
+/// and [`VulgarFraction`](crate::VulgarFraction) write their signs this way.
+///
+/// The glyphs used for the signs are configured with [`WithSign::glyphs`]
+/// and default to the ASCII signs `+` and `-`. See [`SignGlyphs`].
+///
+/// ## Formatting Flags
+/// ### Sign: `+`
+/// Use the `+` flag to always include the plus sign for positive numbers.
+/// Zero is signed by default, this can be changed with [`WithSign::sign_zero`].
+///
+/// ### Alternate `#`
+/// The alternate flag `#` switches to the alternate glyphs of the [`SignGlyphs`]
+/// and is passed on to the wrapped formatter.
+///
+/// ## Examples
+/// ```
+/// # use fmtastic::{SignGlyphs, Superscript, Turned, WithSign};
+/// assert_eq!("-9ϛ", WithSign::new(-56, Turned(56_u32)).to_string());
+/// assert_eq!("+9ϛ", format!("{:+}", WithSign::new(56, Turned(56_u32))));
+/// assert_eq!("0", format!("{:+}", WithSign::new(0, Turned(0_u32)).sign_zero(false)));
+///
+/// let superscript = WithSign::new(-12, Superscript(12_u8)).glyphs(SignGlyphs::SUPERSCRIPT);
+/// assert_eq!(Superscript(-12).to_string(), superscript.to_string());
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct WithSign<W> {
+    magnitude: W,
+    negative: bool,
+    zero: bool,
+    sign_zero: bool,
+    glyphs: SignGlyphs,
+}
+
+/// The glyphs written by [`WithSign`] for the sign of a value.
+///
+/// The alternate glyphs are used when formatting with the alternate flag `#`.
+///
+/// | Glyphs                              | Plus | Minus | Alternate plus | Alternate minus |
+/// |-------------------------------------|------|-------|----------------|-----------------|
+/// | [`SignGlyphs::ASCII`] (default)     | `+`  | `-`   | `+`            | `-`             |
+/// | [`SignGlyphs::SUPERSCRIPT`]         | `⁺`  | `⁻`   | `˖`            | `˗`             |
+/// | [`SignGlyphs::SUBSCRIPT`]           | `₊`  | `₋`   | `₊`            | `₋`             |
+/// | [`SignGlyphs::FULLWIDTH`]           | `＋` | `－`  | `＋`           | `－`            |
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct SignGlyphs {
+    /// The glyph for positive values.
+    pub plus: char,
+    /// The glyph for negative values.
+    pub minus: char,
+    /// The glyph for positive values when formatting with `#`.
+    pub alternate_plus: char,
+    /// The glyph for negative values when formatting with `#`.
+    pub alternate_minus: char,
+}
+
+impl SignGlyphs {
+    /// The ASCII signs `+` and `-`.
+    pub const ASCII: SignGlyphs = SignGlyphs::new('+', '-');
+
+    /// The superscript signs `⁺` (U+207A) and `⁻` (U+207B),
+    /// with the modifier letter signs `˖` (U+02D6) and `˗` (U+02D7) as alternates.
+    pub const SUPERSCRIPT: SignGlyphs = SignGlyphs {
+        plus: '\u{207A}',
+        minus: '\u{207B}',
+        alternate_plus: '\u{02D6}',
+        alternate_minus: '\u{02D7}',
+    };
+
+    // There are no subscript modifier letters for the signs,
+    // so the alternate signs are the same as the regular ones.
+    /// The subscript signs `₊` (U+208A) and `₋` (U+208B).
+    pub const SUBSCRIPT: SignGlyphs = SignGlyphs::new('\u{208A}', '\u{208B}');
+
+    /// The fullwidth signs `＋` (U+FF0B) and `－` (U+FF0D).
+    pub const FULLWIDTH: SignGlyphs = SignGlyphs::new('\u{FF0B}', '\u{FF0D}');
+
+    /// Creates glyphs that use the same signs with and without `#`.
+    #[must_use]
+    pub const fn new(plus: char, minus: char) -> Self {
+        SignGlyphs {
+            plus,
+            minus,
+            alternate_plus: plus,
+            alternate_minus: minus,
+        }
+    }
+}
+
+impl Default for SignGlyphs {
+    fn default() -> Self {
+        SignGlyphs::ASCII
+    }
+}
+
+impl<W> WithSign<W> {
+    /// Creates a new [`WithSign`] with the sign of `value`
+    /// and a formatter for the magnitude of `value`.
+    #[must_use]
+    pub fn new<T: Integer>(value: T, magnitude: W) -> Self {
+        Self::from_impl(value.into_impl(), magnitude)
+    }
+
+    pub(crate) fn from_impl<T: IntegerImpl>(value: T, magnitude: W) -> Self {
+        let negative = matches!(value.sign(), Sign::Negative);
+        Self::from_parts(negative, value == T::ZERO, magnitude)
+    }
+
+    pub(crate) fn from_parts(negative: bool, zero: bool, magnitude: W) -> Self {
+        WithSign {
+            magnitude,
+            negative,
+            zero,
+            sign_zero: true,
+            glyphs: SignGlyphs::default(),
+        }
+    }
+
+    /// Sets the glyphs used for the sign.
+    #[must_use]
+    pub fn glyphs(mut self, glyphs: SignGlyphs) -> Self {
+        self.glyphs = glyphs;
+        self
+    }
+
+    /// Controls whether zero is signed when formatting with the `+` flag.
+    /// Zero is signed by default.
+    #[must_use]
+    pub fn sign_zero(mut self, sign_zero: bool) -> Self {
+        self.sign_zero = sign_zero;
+        self
+    }
+
+    /// Returns a reference to the wrapped formatter.
+    #[must_use]
+    pub fn as_inner(&self) -> &W {
+        &self.magnitude
+    }
+
+    pub(crate) fn fmt_sign(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (plus, minus) = if f.alternate() {
+            (self.glyphs.alternate_plus, self.glyphs.alternate_minus)
+        } else {
+            (self.glyphs.plus, self.glyphs.minus)
+        };
+        if self.zero && !self.sign_zero {
+            Ok(())
+        } else if self.negative {
+            f.write_char(minus)
+        } else if f.sign_plus() {
+            f.write_char(plus)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+// The magnitude is written without the `+` flag,
+// so that the wrapped formatter doesn't write a sign of its own.
+macro_rules! impl_fmt {
+    ($($trait:ident => $plain:literal, $alternate:literal),*) => {
+        $(
+            impl<W> fmt::$trait for WithSign<W>
+            where
+                W: fmt::$trait,
+            {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    self.fmt_sign(f)?;
+                    if f.alternate() {
+                        write!(f, $alternate, self.magnitude)
+                    } else {
+                        write!(f, $plain, self.magnitude)
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_fmt!(Display => "{}", "{:#}", Binary => "{:b}", "{:#b}");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Subscript, Superscript, VulgarFraction};
+
+    #[test]
+    fn matches_superscript_and_subscript() {
+        for n in i8::MIN..=i8::MAX {
+            let magnitude = n.unsigned_abs();
+            let superscript =
+                WithSign::new(n, Superscript(magnitude)).glyphs(SignGlyphs::SUPERSCRIPT);
+            let subscript = WithSign::new(n, Subscript(magnitude)).glyphs(SignGlyphs::SUBSCRIPT);
+            assert_eq!(Superscript(n).to_string(), superscript.to_string());
+            assert_eq!(
+                format!("{:+#}", Superscript(n)),
+                format!("{superscript:+#}")
+            );
+            assert_eq!(format!("{:+b}", Subscript(n)), format!("{subscript:+b}"));
+            assert_eq!(
+                format!("{:+}", Subscript(n).sign_zero(false)),
+                format!("{:+}", subscript.sign_zero(false))
+            );
+        }
+        let superscript = WithSign::new(i128::MIN, Superscript(i128::MIN.unsigned_abs()));
+        assert_eq!(
+            format!("{:#}", Superscript(i128::MIN)),
+            format!("{:#}", superscript.glyphs(SignGlyphs::SUPERSCRIPT))
+        );
+    }
+
+    #[test]
+    fn signs_zero_unless_disabled() {
+        assert_eq!("+0", format!("{:+}", WithSign::new(0, 0_u8)));
+        assert_eq!(
+            "0",
+            format!("{:+}", WithSign::new(0, 0_u8).sign_zero(false))
+        );
+        assert_eq!("0", WithSign::new(0_i64, 0_u8).to_string());
+    }
+
+    #[test]
+    fn uses_alternate_glyphs() {
+        let glyphs = SignGlyphs {
+            alternate_minus: '−',
+            ..SignGlyphs::ASCII
+        };
+        assert_eq!("-5", WithSign::new(-5, 5_u8).glyphs(glyphs).to_string());
+        assert_eq!(
+            "−5",
+            format!("{:#}", WithSign::new(-5, 5_u8).glyphs(glyphs))
+        );
+        assert_eq!(
+            "−0b101",
+            format!("{:#b}", WithSign::new(-5, 5_u8).glyphs(glyphs))
+        );
+    }
+
+    #[test]
+    fn fractions_keep_their_signs() {
+        for (expected, fraction) in [
+            ("-¹⁄₁₁", VulgarFraction::new(-1, 11)),
+            ("-¹⁄₁₁", VulgarFraction::new(1, -11)),
+            ("+¹⁄₁₁", VulgarFraction::new(-1, -11)),
+            ("+⁰⁄₁₁", VulgarFraction::new(0, 11)),
+            ("-⁰⁄₁₁", VulgarFraction::new(0, -11)),
+        ] {
+            assert_eq!(expected, format!("{fraction:+}"));
+        }
+        assert_eq!(
+            "⁰⁄₁₁",
+            format!("{:+}", VulgarFraction::new(0, -11).sign_zero(false))
+        );
+    }
+}
@@ -0,0 +1,261 @@
+use crate::digits::iter_digits;
+use crate::integer::{IntegerImpl, Sign};
+use crate::{Integer, Plain};
+use core::fmt;
+
+/// Formats an integer as English words, e.g. `one hundred twenty-three`.
+///
+/// All integer types are supported, with scale words up to undecillion (10³⁶).
+/// Negative numbers are prefixed with `negative`.
+/// The short scale is used (i.e. a billion is 10⁹) and no `and` is
+/// inserted after hundreds.
+///
+/// ## Formatting Flags
+/// ### Alternate `#`
+/// The alternate flag `#` can be used to format the number as ordinal
+/// (e.g. `one hundred twenty-third`) instead of cardinal.
+///
+/// ## Examples
+/// ```
+/// # use fmtastic::Words;
+/// assert_eq!("one hundred twenty-three", Words(123).to_string());
+/// assert_eq!("one hundred twenty-third", format!("{:#}", Words(123)));
+/// assert_eq!("zero", Words(0).to_string());
+/// assert_eq!("negative forty-two", Words(-42).to_string());
+/// assert_eq!("one million two thousand", Words(1_002_000).to_string());
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct Words<T>(pub T);
+
+impl<T> Words<T> {
+    /// Formats the wrapped value using its standard formatting,
+    /// ignoring the glyphs of this format. See [`Plain`].
+    #[must_use]
+    pub fn plain(self) -> Plain<T> {
+        Plain(self.0)
+    }
+}
+
+impl<T> From<T> for Words<T>
+where
+    T: Integer,
+{
+    fn from(value: T) -> Self {
+        Words(value)
+    }
+}
+
+impl<T> fmt::Display for Words<T>
+where
+    T: Integer,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let ordinal = f.alternate();
+        let mut writer = WordWriter {
+            f,
+            pending: None,
+            ordinal,
+        };
+        fmt_words(self.0.into_impl(), &mut writer)?;
+        writer.finish()
+    }
+}
+
+fn fmt_words<T: IntegerImpl>(n: T, writer: &mut WordWriter<'_, '_>) -> fmt::Result {
+    if let Sign::Negative = n.sign() {
+        writer.push(" ", "negative")?;
+    }
+
+    let digit_count = iter_digits::<_, T::BaseTen>(n).count();
+    let group_count = digit_count.div_ceil(3);
+    let mut digits = iter_digits::<_, T::BaseTen>(n);
+    let mut group_size = digit_count - 3 * (group_count - 1);
+    let mut is_zero = true;
+
+    for scale in (0..group_count).rev() {
+        let group = (&mut digits)
+            .take(group_size)
+            .fold(0, |group, digit| group * 10 + digit);
+        group_size = 3;
+        if group != 0 {
+            is_zero = false;
+            fmt_hundreds(group, writer)?;
+            if scale > 0 {
+                writer.push(" ", SCALES[scale - 1])?;
+            }
+        }
+    }
+
+    if is_zero {
+        writer.push(" ", "zero")?;
+    }
+
+    Ok(())
+}
+
+fn fmt_hundreds(n: usize, writer: &mut WordWriter<'_, '_>) -> fmt::Result {
+    let (hundreds, rest) = (n / 100, n % 100);
+    if hundreds > 0 {
+        writer.push(" ", ONES[hundreds])?;
+        writer.push(" ", "hundred")?;
+    }
+    if rest >= 20 {
+        writer.push(" ", TENS[rest / 10])?;
+        if rest % 10 > 0 {
+            writer.push("-", ONES[rest % 10])?;
+        }
+    } else if rest > 0 {
+        writer.push(" ", ONES[rest])?;
+    }
+    Ok(())
+}
+
+/// Writes words while holding back the last word,
+/// so that it can be turned into an ordinal.
+struct WordWriter<'a, 'b> {
+    f: &'a mut fmt::Formatter<'b>,
+    pending: Option<(&'static str, &'static str)>,
+    ordinal: bool,
+}
+
+impl WordWriter<'_, '_> {
+    fn push(&mut self, separator: &'static str, word: &'static str) -> fmt::Result {
+        let separator = if self.pending.is_some() {
+            separator
+        } else {
+            ""
+        };
+        if let Some((separator, word)) = self.pending.replace((separator, word)) {
+            self.f.write_str(separator)?;
+            self.f.write_str(word)?;
+        }
+        Ok(())
+    }
+
+    fn finish(self) -> fmt::Result {
+        let Some((separator, word)) = self.pending else {
+            return Ok(());
+        };
+        self.f.write_str(separator)?;
+        if !self.ordinal {
+            return self.f.write_str(word);
+        }
+        match word {
+            "one" => self.f.write_str("first"),
+            "two" => self.f.write_str("second"),
+            "three" => self.f.write_str("third"),
+            "five" => self.f.write_str("fifth"),
+            "eight" => self.f.write_str("eighth"),
+            "nine" => self.f.write_str("ninth"),
+            "twelve" => self.f.write_str("twelfth"),
+            _ => match word.strip_suffix('y') {
+                Some(stem) => write!(self.f, "{stem}ieth"),
+                None => write!(self.f, "{word}th"),
+            },
+        }
+    }
+}
+
+const ONES: [&str; 20] = [
+    "zero",
+    "one",
+    "two",
+    "three",
+    "four",
+    "five",
+    "six",
+    "seven",
+    "eight",
+    "nine",
+    "ten",
+    "eleven",
+    "twelve",
+    "thirteen",
+    "fourteen",
+    "fifteen",
+    "sixteen",
+    "seventeen",
+    "eighteen",
+    "nineteen",
+];
+
+const TENS: [&str; 10] = [
+    "", "", "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety",
+];
+
+pub(crate) const SCALES: [&str; 12] = [
+    "thousand",
+    "million",
+    "billion",
+    "trillion",
+    "quadrillion",
+    "quintillion",
+    "sextillion",
+    "septillion",
+    "octillion",
+    "nonillion",
+    "decillion",
+    "undecillion",
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn formats_cardinals() {
+        for (expected, input) in [
+            ("zero", 0),
+            ("seven", 7),
+            ("thirteen", 13),
+            ("twenty", 20),
+            ("ninety-nine", 99),
+            ("one hundred", 100),
+            ("one hundred one", 101),
+            ("one hundred twenty-three", 123),
+            ("one thousand", 1_000),
+            ("twelve thousand three hundred forty-five", 12_345),
+            ("one million", 1_000_000),
+            ("one billion one", 1_000_000_001),
+            ("negative one", -1),
+        ] {
+            assert_eq!(expected, Words(input).to_string());
+        }
+    }
+
+    #[test]
+    fn formats_ordinals() {
+        for (expected, input) in [
+            ("zeroth", 0),
+            ("first", 1),
+            ("second", 2),
+            ("third", 3),
+            ("fourth", 4),
+            ("fifth", 5),
+            ("eighth", 8),
+            ("ninth", 9),
+            ("twelfth", 12),
+            ("twentieth", 20),
+            ("twenty-first", 21),
+            ("one hundredth", 100),
+            ("one hundred twenty-third", 123),
+            ("one thousandth", 1_000),
+            ("one million second", 1_000_002),
+        ] {
+            assert_eq!(expected, format!("{:#}", Words(input)));
+        }
+    }
+
+    #[test]
+    fn formats_extreme_values() {
+        assert_eq!(
+            "negative nine quintillion two hundred twenty-three quadrillion three hundred seventy-two trillion thirty-six billion eight hundred fifty-four million seven hundred seventy-five thousand eight hundred eight",
+            Words(i64::MIN).to_string()
+        );
+        assert_eq!(
+            "three hundred forty undecillion two hundred eighty-two decillion three hundred sixty-six nonillion nine hundred twenty octillion nine hundred thirty-eight septillion four hundred sixty-three sextillion four hundred sixty-three quintillion three hundred seventy-four quadrillion six hundred seven trillion four hundred thirty-one billion seven hundred sixty-eight million two hundred eleven thousand four hundred fifty-five",
+            Words(u128::MAX).to_string()
+        );
+    }
+}
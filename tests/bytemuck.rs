@@ -0,0 +1,28 @@
+//! Checks that the formats wrapping a single value can be cast to and from their wrapped value.
+#![cfg(feature = "bytemuck")]
+
+use fmtastic::*;
+
+#[test]
+fn casts_superscript_from_and_to_wrapped_value() {
+    let superscript: Superscript<u32> = bytemuck::cast(628_u32);
+    assert_eq!(Superscript(628), superscript);
+    assert_eq!("⁶²⁸", superscript.to_string());
+    assert_eq!(42_u32, bytemuck::cast(Superscript(42_u32)));
+}
+
+#[test]
+fn casts_slices_of_wrapped_values() {
+    let values = [1_u16, 2, 3];
+    let subscripts: &[Subscript<u16>] = bytemuck::cast_slice(&values);
+    assert_eq!([Subscript(1), Subscript(2), Subscript(3)], subscripts);
+    let bytes = bytemuck::bytes_of(&Segmented(0x0102_u16));
+    assert_eq!(&0x0102_u16.to_ne_bytes(), bytes);
+}
+
+#[test]
+fn creates_zeroed_values() {
+    assert_eq!(TallyMarks(0_u64), bytemuck::Zeroable::zeroed());
+    assert_eq!(BallotBox(false), bytemuck::Zeroable::zeroed());
+    assert_eq!(Toggle(false), bytemuck::Zeroable::zeroed());
+}
@@ -0,0 +1,54 @@
+//! Checks that all formats can be turned into a `String` from a `no_std` crate
+//! using only `alloc`, via the blanket implementation of [`ToString`].
+//! This works without any features, only the formats that own their items
+//! and the methods returning a `String` need the `alloc` feature.
+#![no_std]
+
+extern crate alloc;
+
+use alloc::string::ToString;
+use fmtastic::*;
+
+#[test]
+fn formats_to_string_without_std() {
+    for (expected, actual) in [
+        ("¹⁰⁄₃", VulgarFraction::new(10, 3).to_string()),
+        ("₁", Subscript(1).to_string()),
+        ("²", Superscript(2).to_string()),
+        ("ⅯⅯⅩⅩⅠⅤ", Roman::new(2024_u16).unwrap().to_string()),
+        ("𝍸𝍷", TallyMarks(6_u32).to_string()),
+        ("🯶🯲🯸", Segmented(628_u32).to_string()),
+        ("\u{1CCF1}\u{1CCF2}", Outlined(12_u8).to_string()),
+        ("9ϛㄣ↋↊1", Turned(123456_u32).to_string()),
+        ("☑", BallotBox(true).to_string()),
+        ("m³", UnitExponent("m", 3).to_string()),
+        ("negative forty-two", Words(-42).to_string()),
+        ("42", Superscript(42).plain().to_string()),
+        ("▅", Spark(50, 0, 100).to_string()),
+        ("1TT", BalancedTernary(5).to_string()),
+        ("❸", Dingbat::new(3_u8).unwrap().to_string()),
+        (
+            "Na⁺",
+            ChemicalFormula::new(&[("Na", 1)]).charge(1).to_string(),
+        ),
+        (
+            "a₁₁ a₁₂\na₂₁ a₂₂",
+            SubscriptMatrix::new("a", 2, 2).to_string(),
+        ),
+    ] {
+        assert_eq!(expected, actual);
+    }
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn formats_alloc_only_types_without_std() {
+    let checklist: Checklist = [true, false].into_iter().collect();
+    for (expected, actual) in [
+        ("☑☐", checklist.to_string()),
+        ("^{2}", Superscript(2).to_latex()),
+        ("\\frac{10}{3}", VulgarFraction::new(10, 3).to_latex()),
+    ] {
+        assert_eq!(expected, actual);
+    }
+}
@@ -0,0 +1,72 @@
+//! Checks that the formats serialize their numeric payload and round-trip through JSON.
+#![cfg(feature = "serde")]
+
+use fmtastic::*;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::fmt::Debug;
+
+fn assert_round_trips<T>(expected_json: &str, value: T)
+where
+    T: Serialize + DeserializeOwned + PartialEq + Debug,
+{
+    let json = serde_json::to_string(&value).unwrap();
+    assert_eq!(expected_json, json);
+    assert_eq!(value, serde_json::from_str::<T>(&json).unwrap());
+}
+
+#[test]
+fn serializes_vulgar_fraction_as_map() {
+    assert_round_trips(
+        r#"{"numerator":-10,"denominator":3}"#,
+        VulgarFraction::new(-10, 3),
+    );
+    assert_round_trips(
+        r#"{"numerator":0,"denominator":0}"#,
+        VulgarFraction::new(0_u8, 0),
+    );
+}
+
+#[test]
+fn serializes_wrapped_integers() {
+    assert_round_trips("-12", Superscript(-12));
+    assert_round_trips(
+        "340282366920938463463374607431768211455",
+        Subscript(u128::MAX),
+    );
+    assert_round_trips("17", TallyMarks(17_u32));
+    assert_round_trips("628", Segmented(628_u64));
+    assert_round_trips("12", Outlined(12_u8));
+}
+
+#[test]
+fn serializes_ballot_box_as_bool() {
+    assert_round_trips("true", BallotBox(true));
+    assert_round_trips("false", BallotBox(false));
+}
+
+#[test]
+fn serializes_roman_numeral_as_value() {
+    assert_round_trips("2024", Roman::new(2024_u16).unwrap());
+    assert_round_trips("255", Roman::from(u8::MAX));
+    assert_eq!(
+        "2024",
+        serde_json::to_string(&Roman::new(2024_u16).unwrap().ascii()).unwrap()
+    );
+}
+
+#[test]
+fn rejects_roman_numeral_out_of_range() {
+    for json in ["0", "4000"] {
+        let error = serde_json::from_str::<Roman<u16>>(json).unwrap_err();
+        assert!(error.to_string().contains("out of range"), "{error}");
+    }
+    assert!(serde_json::from_str::<Roman<u8>>("0").is_err());
+    assert!(serde_json::from_str::<Roman<u16>>("-1").is_err());
+}
+
+#[test]
+fn rejects_values_out_of_range_of_wrapped_type() {
+    assert!(serde_json::from_str::<Superscript<u8>>("256").is_err());
+    assert!(serde_json::from_str::<TallyMarks<u32>>("-1").is_err());
+}